@@ -192,67 +192,69 @@ where
 }
 
 /// Writes the provided message to `writer`. Does not call `flush()`.
-pub async fn write_message<W, M>(mut writer: W, message: M) -> Result<()>
+///
+/// The segment table and all of the segments are written with `write_vectored()`, so that a
+/// multi-segment message can go out in a single system call on writers that support it.
+pub async fn write_message<W, M>(writer: W, message: M) -> Result<()>
 where
     W: AsyncWrite + Unpin,
     M: AsOutputSegments,
 {
     let segments = message.as_output_segments();
-    write_segment_table(&mut writer, &segments[..]).await?;
-    write_segments(writer, &segments[..]).await?;
-    Ok(())
+    let table = construct_segment_table(&segments[..]);
+    let mut bufs = Vec::with_capacity(segments.len() + 1);
+    bufs.push(&table[..]);
+    bufs.extend_from_slice(&segments[..]);
+    write_all_vectored(writer, &mut bufs).await
 }
 
-async fn write_segment_table<W>(mut write: W, segments: &[&[u8]]) -> ::std::io::Result<()>
-where
-    W: AsyncWrite + Unpin,
-{
-    let mut buf: [u8; 8] = [0; 8];
+fn construct_segment_table(segments: &[&[u8]]) -> Vec<u8> {
     let segment_count = segments.len();
+    let mut buf = vec![0; (segment_count / 2 + 1) * 8];
 
-    // write the first Word, which contains segment_count and the 1st segment length
+    // the first Word contains segment_count and the 1st segment length
     buf[0..4].copy_from_slice(&(segment_count as u32 - 1).to_le_bytes());
-    buf[4..8].copy_from_slice(&((segments[0].len() / 8) as u32).to_le_bytes());
-    write.write_all(&buf).await?;
-
-    if segment_count > 1 {
-        if segment_count < 4 {
-            for idx in 1..segment_count {
-                buf[(idx - 1) * 4..idx * 4]
-                    .copy_from_slice(&((segments[idx].len() / 8) as u32).to_le_bytes());
-            }
-            if segment_count == 2 {
-                for value in &mut buf[4..8] {
-                    *value = 0;
-                }
-            }
-            write.write_all(&buf).await?;
-        } else {
-            let mut buf = vec![0; (segment_count & !1) * 4];
-            for idx in 1..segment_count {
-                buf[(idx - 1) * 4..idx * 4]
-                    .copy_from_slice(&((segments[idx].len() / 8) as u32).to_le_bytes());
-            }
-            if segment_count % 2 == 0 {
-                for idx in (buf.len() - 4)..(buf.len()) {
-                    buf[idx] = 0
-                }
-            }
-            write.write_all(&buf).await?;
-        }
+    for (idx, segment) in segments.iter().enumerate() {
+        buf[(idx + 1) * 4..(idx + 2) * 4]
+            .copy_from_slice(&((segment.len() / 8) as u32).to_le_bytes());
     }
-    Ok(())
+    buf
 }
 
-/// Writes segments to `write`.
-async fn write_segments<W>(mut write: W, segments: &[&[u8]]) -> Result<()>
+/// Calls `write_vectored()` until all of `bufs` has been written.
+async fn write_all_vectored<W>(mut write: W, bufs: &mut [&[u8]]) -> Result<()>
 where
     W: AsyncWrite + Unpin,
 {
-    for segment in segments {
-        write.write_all(segment).await?;
+    let mut start = 0;
+    loop {
+        while start < bufs.len() && bufs[start].is_empty() {
+            start += 1;
+        }
+        if start == bufs.len() {
+            return Ok(());
+        }
+        let io_slices: Vec<_> = bufs[start..]
+            .iter()
+            .map(|buf| std::io::IoSlice::new(buf))
+            .collect();
+        let mut n = match write.write_vectored(&io_slices).await {
+            Ok(0) => return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into()),
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        while n > 0 {
+            let len = bufs[start].len();
+            if n >= len {
+                n -= len;
+                start += 1;
+            } else {
+                bufs[start] = &bufs[start][n..];
+                n = 0;
+            }
+        }
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -270,7 +272,10 @@ pub mod test {
     use capnp::message::ReaderSegments;
     use capnp::{message, OutputSegments};
 
-    use super::{read_segment_table, try_read_message, write_message, AsOutputSegments};
+    use super::{
+        construct_segment_table, read_segment_table, try_read_message, write_message,
+        AsOutputSegments,
+    };
 
     #[test]
     fn test_read_segment_table() {
@@ -423,14 +428,6 @@ pub mod test {
         buf.clear();
     }
 
-    fn construct_segment_table(segments: &[&[u8]]) -> Vec<u8> {
-        let mut exec = futures::executor::LocalPool::new();
-        let mut buf = vec![];
-        exec.run_until(super::write_segment_table(&mut buf, segments))
-            .unwrap();
-        buf
-    }
-
     #[test]
     fn test_construct_segment_table() {
         let segment_0: [u8; 0] = [];
@@ -640,6 +637,68 @@ pub mod test {
         }
     }
 
+    /// Records the calls to `poll_write_vectored()`.
+    struct VectoredWrite {
+        bytes: Vec<u8>,
+        calls: usize,
+    }
+
+    impl AsyncWrite for VectoredWrite {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.poll_write_vectored(cx, &[io::IoSlice::new(buf)])
+        }
+
+        fn poll_write_vectored(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context,
+            bufs: &[io::IoSlice],
+        ) -> Poll<io::Result<usize>> {
+            self.calls += 1;
+            let start = self.bytes.len();
+            for buf in bufs {
+                self.bytes.extend_from_slice(buf);
+            }
+            Poll::Ready(Ok(self.bytes.len() - start))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn write_message_vectored() {
+        let segments = vec![
+            vec![capnp::word(1, 0, 0, 0, 0, 0, 0, 0)],
+            vec![capnp::word(2, 0, 0, 0, 0, 0, 0, 0); 3],
+            vec![],
+        ];
+        let mut writer = VectoredWrite {
+            bytes: Vec::new(),
+            calls: 0,
+        };
+        futures::executor::block_on(write_message(&mut writer, &segments)).unwrap();
+        assert_eq!(writer.calls, 1);
+        let segments: Vec<&[u8]> = segments
+            .iter()
+            .map(|segment| capnp::Word::words_to_bytes(segment))
+            .collect();
+        assert_eq!(
+            writer.bytes,
+            capnp::serialize::write_message_segments_to_words(&message::SegmentArray::new(
+                &segments
+            ))
+        );
+    }
+
     #[cfg_attr(miri, ignore)] // Miri takes a long time with quickcheck
     #[test]
     fn check_round_trip_async() {
@@ -51,7 +51,7 @@ impl PipelineHook for Pipeline {
 
 pub struct Request {
     error: Error,
-    message: ::capnp::message::Builder<::capnp::message::HeapAllocator>,
+    message: ::capnp::message::Builder<crate::MessageAllocator>,
     cap_table: Vec<Option<Box<dyn ClientHook>>>,
 }
 
@@ -59,7 +59,7 @@ impl Request {
     pub fn new(error: Error, _size_hint: Option<::capnp::MessageSize>) -> Self {
         Self {
             error,
-            message: ::capnp::message::Builder::new_default(),
+            message: crate::new_message_builder(),
             cap_table: Vec::new(),
         }
     }
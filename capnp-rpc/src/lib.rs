@@ -108,6 +108,13 @@ mod split;
 mod task_set;
 pub mod twoparty;
 
+/// The allocator used by the messages that the RPC system sends.
+pub type MessageAllocator = Box<dyn ::capnp::message::Allocator>;
+
+pub(crate) fn new_message_builder() -> ::capnp::message::Builder<MessageAllocator> {
+    ::capnp::message::Builder::new(Box::new(::capnp::message::HeapAllocator::new()))
+}
+
 pub trait OutgoingMessage {
     fn get_body(&mut self) -> ::capnp::Result<::capnp::any_pointer::Builder>;
     fn get_body_as_reader(&self) -> ::capnp::Result<::capnp::any_pointer::Reader>;
@@ -117,11 +124,11 @@ pub trait OutgoingMessage {
     fn send(
         self: Box<Self>,
     ) -> (
-        Promise<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>, ::capnp::Error>,
-        Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+        Promise<Rc<::capnp::message::Builder<crate::MessageAllocator>>, ::capnp::Error>,
+        Rc<::capnp::message::Builder<crate::MessageAllocator>>,
     );
 
-    fn take(self: Box<Self>) -> ::capnp::message::Builder<::capnp::message::HeapAllocator>;
+    fn take(self: Box<Self>) -> ::capnp::message::Builder<crate::MessageAllocator>;
 }
 
 pub trait IncomingMessage {
@@ -60,13 +60,13 @@ impl ResponseHook for Response {
 }
 
 struct Params {
-    request: message::Builder<message::HeapAllocator>,
+    request: message::Builder<crate::MessageAllocator>,
     cap_table: Vec<Option<Box<dyn ClientHook>>>,
 }
 
 impl Params {
     fn new(
-        request: message::Builder<message::HeapAllocator>,
+        request: message::Builder<crate::MessageAllocator>,
         cap_table: Vec<Option<Box<dyn ClientHook>>>,
     ) -> Self {
         Self { request, cap_table }
@@ -82,7 +82,7 @@ impl ParamsHook for Params {
 }
 
 struct Results {
-    message: Option<message::Builder<message::HeapAllocator>>,
+    message: Option<message::Builder<crate::MessageAllocator>>,
     cap_table: Vec<Option<Box<dyn ClientHook>>>,
     results_done_fulfiller: Option<oneshot::Sender<Box<dyn ResultsDoneHook>>>,
 }
@@ -90,7 +90,7 @@ struct Results {
 impl Results {
     fn new(fulfiller: oneshot::Sender<Box<dyn ResultsDoneHook>>) -> Self {
         Self {
-            message: Some(::capnp::message::Builder::new_default()),
+            message: Some(crate::new_message_builder()),
             cap_table: Vec::new(),
             results_done_fulfiller: Some(fulfiller),
         }
@@ -143,7 +143,7 @@ impl ResultsHook for Results {
 }
 
 struct ResultsDoneInner {
-    message: ::capnp::message::Builder<::capnp::message::HeapAllocator>,
+    message: ::capnp::message::Builder<crate::MessageAllocator>,
     cap_table: Vec<Option<Box<dyn ClientHook>>>,
 }
 
@@ -153,7 +153,7 @@ struct ResultsDone {
 
 impl ResultsDone {
     fn new(
-        message: message::Builder<message::HeapAllocator>,
+        message: message::Builder<crate::MessageAllocator>,
         cap_table: Vec<Option<Box<dyn ClientHook>>>,
     ) -> Self {
         Self {
@@ -176,7 +176,7 @@ impl ResultsDoneHook for ResultsDone {
 }
 
 pub struct Request {
-    message: message::Builder<::capnp::message::HeapAllocator>,
+    message: message::Builder<crate::MessageAllocator>,
     cap_table: Vec<Option<Box<dyn ClientHook>>>,
     interface_id: u64,
     method_id: u16,
@@ -191,7 +191,7 @@ impl Request {
         client: Box<dyn ClientHook>,
     ) -> Self {
         Self {
-            message: message::Builder::new_default(),
+            message: crate::new_message_builder(),
             cap_table: Vec::new(),
             interface_id,
             method_id,
@@ -2168,7 +2168,7 @@ enum ResultsVariant {
         Vec<Option<Box<dyn ClientHook>>>,
     ),
     LocallyRedirected(
-        ::capnp::message::Builder<::capnp::message::HeapAllocator>,
+        ::capnp::message::Builder<crate::MessageAllocator>,
         Vec<Option<Box<dyn ClientHook>>>,
     ),
 }
@@ -2208,7 +2208,7 @@ where
                 }
                 _ => {
                     self.variant = Some(ResultsVariant::LocallyRedirected(
-                        ::capnp::message::Builder::new_default(),
+                        crate::new_message_builder(),
                         Vec::new(),
                     ));
                 }
@@ -2346,11 +2346,11 @@ impl<VatId> ResultsHook for Results<VatId> {
 
 enum ResultsDoneVariant {
     Rpc(
-        Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+        Rc<::capnp::message::Builder<crate::MessageAllocator>>,
         Vec<Option<Box<dyn ClientHook>>>,
     ),
     LocallyRedirected(
-        ::capnp::message::Builder<::capnp::message::HeapAllocator>,
+        ::capnp::message::Builder<crate::MessageAllocator>,
         Vec<Option<Box<dyn ClientHook>>>,
     ),
 }
@@ -2480,7 +2480,7 @@ impl ResultsDone {
     }
 
     fn rpc(
-        message: Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+        message: Rc<::capnp::message::Builder<crate::MessageAllocator>>,
         cap_table: Vec<Option<Box<dyn ClientHook>>>,
     ) -> Self {
         Self {
@@ -2489,7 +2489,7 @@ impl ResultsDone {
     }
 
     fn redirected(
-        message: ::capnp::message::Builder<::capnp::message::HeapAllocator>,
+        message: ::capnp::message::Builder<crate::MessageAllocator>,
         cap_table: Vec<Option<Box<dyn ClientHook>>>,
     ) -> Self {
         Self {
@@ -12,11 +12,13 @@ pub mod message {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -55,11 +57,13 @@ pub mod message {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -313,11 +317,13 @@ pub mod message {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -335,6 +341,16 @@ pub mod message {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -394,6 +410,39 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_unimplemented(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 0);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_unimplemented(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 0);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_unimplemented(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 0,
+                "disown_unimplemented() called when unimplemented is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_unimplemented(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 0 {
                 return false;
@@ -418,6 +467,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_abort(self) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_abort(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 1);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_abort(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 1,
+                "disown_abort() called when abort is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_abort(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 1 {
                 return false;
@@ -442,6 +522,35 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_call(self) -> ::capnp::Result<crate::rpc_capnp::call::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 2);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_call(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::call::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 2);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_call(&mut self) -> ::capnp::orphan::Orphan<crate::rpc_capnp::call::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 2,
+                "disown_call() called when call is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_call(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 2 {
                 return false;
@@ -466,6 +575,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_return(self) -> ::capnp::Result<crate::rpc_capnp::return_::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 3);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_return(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::return_::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 3);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_return(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::return_::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 3,
+                "disown_return() called when return is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_return(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 3 {
                 return false;
@@ -490,6 +630,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_finish(self) -> ::capnp::Result<crate::rpc_capnp::finish::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 4);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_finish(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::finish::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 4);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_finish(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::finish::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 4,
+                "disown_finish() called when finish is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_finish(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 4 {
                 return false;
@@ -514,6 +685,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_resolve(self) -> ::capnp::Result<crate::rpc_capnp::resolve::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 5);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_resolve(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::resolve::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 5);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_resolve(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::resolve::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 5,
+                "disown_resolve() called when resolve is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_resolve(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 5 {
                 return false;
@@ -538,6 +740,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_release(self) -> ::capnp::Result<crate::rpc_capnp::release::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 6);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_release(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::release::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 6);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_release(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::release::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 6,
+                "disown_release() called when release is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_release(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 6 {
                 return false;
@@ -551,6 +784,29 @@ pub mod message {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_obsolete_save(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 7);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_obsolete_save(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 7,
+                "disown_obsolete_save() called when obsolete_save is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_obsolete_save(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 7 {
@@ -576,6 +832,39 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_bootstrap(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::bootstrap::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 8);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_bootstrap(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::bootstrap::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 8);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_bootstrap(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::bootstrap::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 8,
+                "disown_bootstrap() called when bootstrap is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_bootstrap(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 8 {
                 return false;
@@ -589,6 +878,26 @@ pub mod message {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_obsolete_delete(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 9);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_obsolete_delete(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            assert!(self.builder.get_data_field::<u16>(0) == 9, "disown_obsolete_delete() called when obsolete_delete is not the active union member");
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_obsolete_delete(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 9 {
@@ -614,6 +923,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_provide(self) -> ::capnp::Result<crate::rpc_capnp::provide::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 10);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_provide(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::provide::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 10);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_provide(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::provide::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 10,
+                "disown_provide() called when provide is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_provide(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 10 {
                 return false;
@@ -638,6 +978,37 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_accept(self) -> ::capnp::Result<crate::rpc_capnp::accept::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 11);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_accept(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::accept::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 11);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_accept(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::accept::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 11,
+                "disown_accept() called when accept is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_accept(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 11 {
                 return false;
@@ -662,6 +1033,35 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_join(self) -> ::capnp::Result<crate::rpc_capnp::join::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 12);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_join(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::join::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 12);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_join(&mut self) -> ::capnp::orphan::Orphan<crate::rpc_capnp::join::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 12,
+                "disown_join() called when join is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_join(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 12 {
                 return false;
@@ -686,6 +1086,39 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_disembargo(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::disembargo::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 13);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_disembargo(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::disembargo::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 13);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_disembargo(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::disembargo::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 13,
+                "disown_disembargo() called when disembargo is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_disembargo(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 13 {
                 return false;
@@ -1115,11 +1548,13 @@ pub mod bootstrap {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -1158,11 +1593,13 @@ pub mod bootstrap {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -1246,11 +1683,13 @@ pub mod bootstrap {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -1268,6 +1707,16 @@ pub mod bootstrap {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -1327,6 +1776,24 @@ pub mod bootstrap {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_deprecated_object_id(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_deprecated_object_id(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_deprecated_object_id(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
@@ -1431,11 +1898,13 @@ pub mod call {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -1474,11 +1943,13 @@ pub mod call {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -1592,11 +2063,13 @@ pub mod call {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -1614,6 +2087,16 @@ pub mod call {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -1686,6 +2169,33 @@ pub mod call {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -1728,6 +2238,31 @@ pub mod call {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
         }
         #[inline]
+        pub fn try_init_params(self) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_params(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_params(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_params(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -1934,11 +2469,11 @@ pub mod call {
         impl ::capnp::introspect::Introspect for Owned {
             fn introspect() -> ::capnp::introspect::Type {
                 ::capnp::introspect::TypeVariant::Struct(
-                    ::capnp::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    },
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
                 )
                 .into()
             }
@@ -1979,11 +2514,11 @@ pub mod call {
                 Self::Struct(::capnp::dynamic_struct::Reader::new(
                     reader.reader,
                     ::capnp::schema::StructSchema::new(
-                        ::capnp::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        ::capnp::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     ),
                 ))
             }
@@ -2075,11 +2610,11 @@ pub mod call {
                 Self::Struct(::capnp::dynamic_struct::Builder::new(
                     builder.builder,
                     ::capnp::schema::StructSchema::new(
-                        ::capnp::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        ::capnp::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     ),
                 ))
             }
@@ -2101,6 +2636,16 @@ pub mod call {
                     .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> ::capnp::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: ::capnp::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -2158,6 +2703,29 @@ pub mod call {
                 result.clear();
                 result
             }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_third_party(
+                &mut self,
+                orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+            ) {
+                self.builder.set_data_field::<u16>(3, 2);
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(2)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_third_party(
+                &mut self,
+            ) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+                assert!(
+                    self.builder.get_data_field::<u16>(3) == 2,
+                    "disown_third_party() called when third_party is not the active union member"
+                );
+                ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+            }
             #[inline]
             pub fn has_third_party(&self) -> bool {
                 if self.builder.get_data_field::<u16>(3) != 2 {
@@ -2303,11 +2871,13 @@ pub mod return_ {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -2346,11 +2916,13 @@ pub mod return_ {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -2477,11 +3049,13 @@ pub mod return_ {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -2499,6 +3073,16 @@ pub mod return_ {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -2574,6 +3158,37 @@ pub mod return_ {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_results(self) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'a>> {
+            self.builder.set_data_field::<u16>(3, 0);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_results(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(3, 0);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_results(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(3) == 0,
+                "disown_results() called when results is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_results(&self) -> bool {
             if self.builder.get_data_field::<u16>(3) != 0 {
                 return false;
@@ -2598,6 +3213,39 @@ pub mod return_ {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_exception(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'a>> {
+            self.builder.set_data_field::<u16>(3, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_exception(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(3, 1);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_exception(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(3) == 1,
+                "disown_exception() called when exception is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_exception(&self) -> bool {
             if self.builder.get_data_field::<u16>(3) != 1 {
                 return false;
@@ -2624,6 +3272,26 @@ pub mod return_ {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_accept_from_third_party(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(3, 5);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_accept_from_third_party(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            assert!(self.builder.get_data_field::<u16>(3) == 5, "disown_accept_from_third_party() called when accept_from_third_party is not the active union member");
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_accept_from_third_party(&self) -> bool {
             if self.builder.get_data_field::<u16>(3) != 5 {
@@ -2875,11 +3543,13 @@ pub mod finish {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -2918,11 +3588,13 @@ pub mod finish {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -3002,11 +3674,13 @@ pub mod finish {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -3024,6 +3698,16 @@ pub mod finish {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -3179,11 +3863,13 @@ pub mod resolve {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -3222,11 +3908,13 @@ pub mod resolve {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -3334,11 +4022,13 @@ pub mod resolve {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -3356,6 +4046,16 @@ pub mod resolve {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -3423,6 +4123,39 @@ pub mod resolve {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_cap(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::cap_descriptor::Builder<'a>> {
+            self.builder.set_data_field::<u16>(2, 0);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_cap(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::cap_descriptor::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(2, 0);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_cap(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::cap_descriptor::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(2) == 0,
+                "disown_cap() called when cap is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_cap(&self) -> bool {
             if self.builder.get_data_field::<u16>(2) != 0 {
                 return false;
@@ -3447,6 +4180,39 @@ pub mod resolve {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_exception(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'a>> {
+            self.builder.set_data_field::<u16>(2, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_exception(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(2, 1);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_exception(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(2) == 1,
+                "disown_exception() called when exception is not the active union member"
+            );
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_exception(&self) -> bool {
             if self.builder.get_data_field::<u16>(2) != 1 {
                 return false;
@@ -3594,11 +4360,13 @@ pub mod release {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -3637,11 +4405,13 @@ pub mod release {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -3721,11 +4491,13 @@ pub mod release {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -3743,6 +4515,16 @@ pub mod release {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -3894,11 +4676,13 @@ pub mod disembargo {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -3937,11 +4721,13 @@ pub mod disembargo {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -4028,11 +4814,13 @@ pub mod disembargo {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -4050,6 +4838,16 @@ pub mod disembargo {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -4114,6 +4912,33 @@ pub mod disembargo {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -4222,11 +5047,11 @@ pub mod disembargo {
         impl ::capnp::introspect::Introspect for Owned {
             fn introspect() -> ::capnp::introspect::Type {
                 ::capnp::introspect::TypeVariant::Struct(
-                    ::capnp::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    },
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
                 )
                 .into()
             }
@@ -4267,11 +5092,11 @@ pub mod disembargo {
                 Self::Struct(::capnp::dynamic_struct::Reader::new(
                     reader.reader,
                     ::capnp::schema::StructSchema::new(
-                        ::capnp::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        ::capnp::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     ),
                 ))
             }
@@ -4359,11 +5184,11 @@ pub mod disembargo {
                 Self::Struct(::capnp::dynamic_struct::Builder::new(
                     builder.builder,
                     ::capnp::schema::StructSchema::new(
-                        ::capnp::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        ::capnp::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     ),
                 ))
             }
@@ -4385,6 +5210,16 @@ pub mod disembargo {
                     .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> ::capnp::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: ::capnp::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -4596,11 +5431,13 @@ pub mod provide {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -4639,11 +5476,13 @@ pub mod provide {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -4738,11 +5577,13 @@ pub mod provide {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -4760,6 +5601,16 @@ pub mod provide {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -4832,6 +5683,33 @@ pub mod provide {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -4845,6 +5723,22 @@ pub mod provide {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_recipient(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_recipient(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
         #[inline]
         pub fn has_recipient(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
@@ -4967,11 +5861,13 @@ pub mod accept {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -5010,11 +5906,13 @@ pub mod accept {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -5102,11 +6000,13 @@ pub mod accept {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -5124,6 +6024,16 @@ pub mod accept {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -5183,6 +6093,22 @@ pub mod accept {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_provision(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_provision(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_provision(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
@@ -5310,11 +6236,13 @@ pub mod join {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -5353,11 +6281,13 @@ pub mod join {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -5452,11 +6382,13 @@ pub mod join {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -5474,6 +6406,16 @@ pub mod join {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -5546,6 +6488,33 @@ pub mod join {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -5559,6 +6528,22 @@ pub mod join {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_key_part(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_key_part(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
         #[inline]
         pub fn has_key_part(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
@@ -5681,11 +6666,13 @@ pub mod message_target {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -5724,11 +6711,13 @@ pub mod message_target {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -5820,11 +6809,13 @@ pub mod message_target {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -5842,6 +6833,16 @@ pub mod message_target {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -5906,6 +6907,36 @@ pub mod message_target {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_promised_answer(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'a>> {
+            self.builder.set_data_field::<u16>(2, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_promised_answer(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(2, 1);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_promised_answer(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned> {
+            assert!(self.builder.get_data_field::<u16>(2) == 1, "disown_promised_answer() called when promised_answer is not the active union member");
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_promised_answer(&self) -> bool {
             if self.builder.get_data_field::<u16>(2) != 1 {
                 return false;
@@ -6028,11 +7059,13 @@ pub mod payload {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -6071,11 +7104,13 @@ pub mod payload {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -6170,11 +7205,13 @@ pub mod payload {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -6192,6 +7229,16 @@ pub mod payload {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -6243,6 +7290,22 @@ pub mod payload {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_content(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_content(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_content(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
@@ -6280,6 +7343,40 @@ pub mod payload {
             )
         }
         #[inline]
+        pub fn try_init_cap_table(
+            self,
+            size: u32,
+        ) -> ::capnp::Result<
+            ::capnp::struct_list::Builder<'a, crate::rpc_capnp::cap_descriptor::Owned>,
+        > {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_cap_table(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<
+                ::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_cap_table(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<
+            ::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned>,
+        > {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_cap_table(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -6389,11 +7486,13 @@ pub mod cap_descriptor {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -6432,11 +7531,13 @@ pub mod cap_descriptor {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -6552,11 +7653,13 @@ pub mod cap_descriptor {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -6574,6 +7677,16 @@ pub mod cap_descriptor {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -6652,6 +7765,36 @@ pub mod cap_descriptor {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_receiver_answer(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 4);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_receiver_answer(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 4);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_receiver_answer(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned> {
+            assert!(self.builder.get_data_field::<u16>(0) == 4, "disown_receiver_answer() called when receiver_answer is not the active union member");
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_receiver_answer(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 4 {
                 return false;
@@ -6678,6 +7821,36 @@ pub mod cap_descriptor {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_third_party_hosted(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::third_party_cap_descriptor::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 5);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_third_party_hosted(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::third_party_cap_descriptor::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 5);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_third_party_hosted(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::third_party_cap_descriptor::Owned> {
+            assert!(self.builder.get_data_field::<u16>(0) == 5, "disown_third_party_hosted() called when third_party_hosted is not the active union member");
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_third_party_hosted(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 5 {
                 return false;
@@ -6916,11 +8089,13 @@ pub mod promised_answer {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -6959,11 +8134,13 @@ pub mod promised_answer {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -7054,11 +8231,13 @@ pub mod promised_answer {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -7076,6 +8255,16 @@ pub mod promised_answer {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -7159,6 +8348,40 @@ pub mod promised_answer {
             )
         }
         #[inline]
+        pub fn try_init_transform(
+            self,
+            size: u32,
+        ) -> ::capnp::Result<
+            ::capnp::struct_list::Builder<'a, crate::rpc_capnp::promised_answer::op::Owned>,
+        > {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_transform(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<
+                ::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_transform(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<
+            ::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned>,
+        > {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_transform(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -7267,11 +8490,11 @@ pub mod promised_answer {
         impl ::capnp::introspect::Introspect for Owned {
             fn introspect() -> ::capnp::introspect::Type {
                 ::capnp::introspect::TypeVariant::Struct(
-                    ::capnp::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    },
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
                 )
                 .into()
             }
@@ -7312,11 +8535,11 @@ pub mod promised_answer {
                 Self::Struct(::capnp::dynamic_struct::Reader::new(
                     reader.reader,
                     ::capnp::schema::StructSchema::new(
-                        ::capnp::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        ::capnp::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     ),
                 ))
             }
@@ -7400,11 +8623,11 @@ pub mod promised_answer {
                 Self::Struct(::capnp::dynamic_struct::Builder::new(
                     builder.builder,
                     ::capnp::schema::StructSchema::new(
-                        ::capnp::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        ::capnp::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     ),
                 ))
             }
@@ -7426,6 +8649,16 @@ pub mod promised_answer {
                     .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> ::capnp::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: ::capnp::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -7588,11 +8821,13 @@ pub mod third_party_cap_descriptor {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -7631,11 +8866,13 @@ pub mod third_party_cap_descriptor {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -7719,11 +8956,13 @@ pub mod third_party_cap_descriptor {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -7741,6 +8980,16 @@ pub mod third_party_cap_descriptor {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -7792,6 +9041,19 @@ pub mod third_party_cap_descriptor {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_id(&mut self, orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_id(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_id(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
@@ -7903,11 +9165,13 @@ pub mod exception {
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                ),
+            )
             .into()
         }
     }
@@ -7946,11 +9210,13 @@ pub mod exception {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Reader::new(
                 reader.reader,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -8059,11 +9325,13 @@ pub mod exception {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(::capnp::dynamic_struct::Builder::new(
                 builder.builder,
-                ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                ::capnp::schema::StructSchema::new(
+                    ::capnp::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                ),
             ))
         }
     }
@@ -8081,6 +9349,16 @@ pub mod exception {
                 .init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> ::capnp::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: ::capnp::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [::capnp::Word]>,
@@ -8134,10 +9412,34 @@ pub mod exception {
             self.builder.reborrow().get_pointer_field(0).set_text(value);
         }
         #[inline]
+        pub fn try_set_reason(&mut self, value: ::capnp::text::Reader<'_>) -> ::capnp::Result<()> {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_reason(self, size: u32) -> ::capnp::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_reason(self, size: u32) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_reason(&mut self, orphan: ::capnp::orphan::Orphan<::capnp::text::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_reason(&mut self) -> ::capnp::orphan::Orphan<::capnp::text::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_reason(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -8180,10 +9482,34 @@ pub mod exception {
             self.builder.reborrow().get_pointer_field(1).set_text(value);
         }
         #[inline]
+        pub fn try_set_trace(&mut self, value: ::capnp::text::Reader<'_>) -> ::capnp::Result<()> {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_trace(self, size: u32) -> ::capnp::text::Builder<'a> {
             self.builder.get_pointer_field(1).init_text(size)
         }
         #[inline]
+        pub fn try_init_trace(self, size: u32) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+            self.builder.get_pointer_field(1).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_trace(&mut self, orphan: ::capnp::orphan::Orphan<::capnp::text::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_trace(&mut self) -> ::capnp::orphan::Orphan<::capnp::text::Owned> {
+            ::capnp::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_trace(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -8340,10 +9666,10 @@ pub mod exception {
 
     impl ::capnp::introspect::Introspect for Type {
         fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Enum(::capnp::introspect::RawEnumSchema {
-                encoded_node: &type_::ENCODED_NODE,
-                annotation_types: type_::get_annotation_types,
-            })
+            ::capnp::introspect::TypeVariant::Enum(::capnp::introspect::RawEnumSchema::new(
+                &type_::ENCODED_NODE,
+                type_::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -8351,10 +9677,10 @@ pub mod exception {
         fn from(e: Type) -> Self {
             ::capnp::dynamic_value::Enum::new(
                 e.into(),
-                ::capnp::introspect::RawEnumSchema {
-                    encoded_node: &type_::ENCODED_NODE,
-                    annotation_types: type_::get_annotation_types,
-                }
+                ::capnp::introspect::RawEnumSchema::new(
+                    &type_::ENCODED_NODE,
+                    type_::get_annotation_types,
+                )
                 .into(),
             )
             .into()
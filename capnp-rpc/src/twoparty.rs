@@ -48,8 +48,8 @@ impl crate::IncomingMessage for IncomingMessage {
 }
 
 struct OutgoingMessage {
-    message: ::capnp::message::Builder<::capnp::message::HeapAllocator>,
-    sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>>,
+    message: ::capnp::message::Builder<crate::MessageAllocator>,
+    sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<crate::MessageAllocator>>>,
 }
 
 impl crate::OutgoingMessage for OutgoingMessage {
@@ -64,8 +64,8 @@ impl crate::OutgoingMessage for OutgoingMessage {
     fn send(
         self: Box<Self>,
     ) -> (
-        Promise<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>, ::capnp::Error>,
-        Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+        Promise<Rc<::capnp::message::Builder<crate::MessageAllocator>>, ::capnp::Error>,
+        Rc<::capnp::message::Builder<crate::MessageAllocator>>,
     ) {
         let tmp = *self;
         let Self {
@@ -76,7 +76,7 @@ impl crate::OutgoingMessage for OutgoingMessage {
         (Promise::from_future(sender.send(m.clone())), m)
     }
 
-    fn take(self: Box<Self>) -> ::capnp::message::Builder<::capnp::message::HeapAllocator> {
+    fn take(self: Box<Self>) -> ::capnp::message::Builder<crate::MessageAllocator> {
         self.message
     }
 }
@@ -86,10 +86,11 @@ where
     T: AsyncRead + 'static,
 {
     input_stream: Rc<RefCell<Option<T>>>,
-    sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>>,
+    sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<crate::MessageAllocator>>>,
     side: crate::rpc_twoparty_capnp::Side,
     receive_options: ReaderOptions,
     on_disconnect_fulfiller: Option<oneshot::Sender<()>>,
+    allocator_factory: Box<dyn Fn() -> crate::MessageAllocator>,
 }
 
 struct Connection<T>
@@ -119,9 +120,7 @@ where
 {
     fn new(
         input_stream: T,
-        sender: ::capnp_futures::Sender<
-            Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
-        >,
+        sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<crate::MessageAllocator>>>,
         side: crate::rpc_twoparty_capnp::Side,
         receive_options: ReaderOptions,
         on_disconnect_fulfiller: oneshot::Sender<()>,
@@ -133,6 +132,9 @@ where
                 side,
                 receive_options,
                 on_disconnect_fulfiller: Some(on_disconnect_fulfiller),
+                allocator_factory: Box::new(|| {
+                    Box::new(::capnp::message::HeapAllocator::new()) as crate::MessageAllocator
+                }),
             })),
         }
     }
@@ -150,9 +152,10 @@ where
         &mut self,
         _first_segment_word_size: u32,
     ) -> Box<dyn crate::OutgoingMessage> {
+        let inner = self.inner.borrow();
         Box::new(OutgoingMessage {
-            message: ::capnp::message::Builder::new_default(),
-            sender: self.inner.borrow().sender.clone(),
+            message: ::capnp::message::Builder::new((inner.allocator_factory)()),
+            sender: inner.sender.clone(),
         })
     }
 
@@ -256,6 +259,28 @@ where
             side,
         }
     }
+
+    /// Sets the function that creates an allocator for each outgoing message. By default,
+    /// each message gets a new `HeapAllocator`.
+    ///
+    /// Combined with a `capnp::message::SegmentPool`, this lets the connection reuse the
+    /// segments of messages that have already been sent.
+    ///
+    /// Returns false, and does nothing, if the connection is already gone.
+    pub fn set_allocator_factory<F, A>(&mut self, factory: F) -> bool
+    where
+        F: Fn() -> A + 'static,
+        A: ::capnp::message::Allocator + 'static,
+    {
+        match self.weak_connection_inner.upgrade() {
+            Some(connection_inner) => {
+                connection_inner.borrow_mut().allocator_factory =
+                    Box::new(move || Box::new(factory()) as crate::MessageAllocator);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl<T> crate::VatNetwork<VatId> for VatNetwork<T>
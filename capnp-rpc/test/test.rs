@@ -272,6 +272,98 @@ fn basic_rpc_calls() {
     });
 }
 
+#[test]
+fn pooled_allocator_rpc_calls() {
+    let mut pool = futures::executor::LocalPool::new();
+    let mut spawner = pool.spawner();
+    let (client_writer, server_reader) = async_byte_channel::channel();
+    let (server_writer, client_reader) = async_byte_channel::channel();
+
+    let join_handle = std::thread::spawn(move || {
+        let network = Box::new(twoparty::VatNetwork::new(
+            server_reader,
+            server_writer,
+            rpc_twoparty_capnp::Side::Server,
+            Default::default(),
+        ));
+
+        let bootstrap: test_capnp::bootstrap::Client = capnp_rpc::new_client(impls::Bootstrap);
+        let rpc_system = RpcSystem::new(network, Some(bootstrap.client));
+        futures::executor::block_on(rpc_system).unwrap();
+    });
+
+    let segment_pool = capnp::message::SegmentPool::new(1 << 16);
+    let mut network = Box::new(twoparty::VatNetwork::new(
+        client_reader,
+        client_writer,
+        rpc_twoparty_capnp::Side::Client,
+        Default::default(),
+    ));
+    let factory_pool = segment_pool.clone();
+    assert!(network.set_allocator_factory(move || factory_pool.allocator()));
+
+    let mut rpc_system = RpcSystem::new(network, None);
+    let client: test_capnp::bootstrap::Client =
+        rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+
+    let disconnector = rpc_system.get_disconnector();
+    spawn(&mut spawner, rpc_system);
+    let pool_handle = segment_pool.clone();
+    pool.run_until(async move {
+        let response = client.test_interface_request().send().promise.await?;
+        let client = response.get()?.get_cap()?;
+        for _ in 0..3 {
+            let mut request = client.foo_request();
+            request.get().set_i(123);
+            request.get().set_j(true);
+            let response = request.send().promise.await?;
+            assert_eq!(response.get()?.get_x()?, "foo");
+
+            // Messages that have already been written out are returned to the pool.
+            assert!(!pool_handle.is_empty());
+        }
+        Ok::<(), Error>(())
+    })
+    .unwrap();
+
+    pool.run_until(disconnector).unwrap();
+    join_handle.join().unwrap();
+}
+
+#[test]
+fn dynamic_rpc_calls() {
+    use capnp::dynamic_capability;
+
+    rpc_top_level(|_spawner, client| async move {
+        let response = client.test_interface_request().send().promise.await?;
+        let interface = dynamic_capability::Client::from_typed(response.get()?.get_cap()?);
+
+        let mut request = interface.new_request_named("foo", None)?;
+        request.get().set_named("i", 123u32.into())?;
+        request.get().set_named("j", true.into())?;
+        let response = request.send().await?;
+        let x: capnp::text::Reader<'_> = response.get()?.get_named("x")?.downcast();
+        assert_eq!(x, "foo");
+
+        assert!(interface
+            .new_request_named("bar", None)?
+            .send()
+            .await
+            .is_err());
+        assert!(interface.new_request_named("nonexistent", None).is_err());
+
+        // Methods inherited from superclasses are found by name.
+        let response = client.test_extends_request().send().promise.await?;
+        let extends = dynamic_capability::Client::from_typed(response.get()?.get_cap()?);
+        let mut request = extends.new_request_named("foo", None)?;
+        request.get().set_named("i", 321u32.into())?;
+        let response = request.send().await?;
+        let x: capnp::text::Reader<'_> = response.get()?.get_named("x")?.downcast();
+        assert_eq!(x, "bar");
+        Ok(())
+    });
+}
+
 #[test]
 fn basic_pipelining() {
     rpc_top_level(|_spawner, client| async move {
@@ -879,6 +971,55 @@ fn local_client_return_cap() {
     assert_eq!(response1.get().unwrap().get_x().unwrap(), "foo");
 }
 
+#[test]
+fn dynamic_server() {
+    use crate::test_capnp::test_extends;
+    use capnp::dynamic_capability::{self, Params, Results};
+    use capnp::introspect::{Introspect, TypeVariant};
+    use capnp::schema::Method;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let TypeVariant::Capability(schema) =
+        <test_extends::Client as Introspect>::introspect().which()
+    else {
+        panic!()
+    };
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let calls1 = calls.clone();
+    let server = dynamic_capability::Server::new(
+        schema.into(),
+        move |method: Method, params: Params, mut results: Results| {
+            let name = method.get_proto().get_name().unwrap().to_string().unwrap();
+            calls1.borrow_mut().push(name.clone());
+            match &name[..] {
+                "foo" => Promise::from_future(async move {
+                    let i: u32 = params.get()?.get_named("i")?.downcast();
+                    results
+                        .get()?
+                        .set_named("x", capnp::text::Reader::from(&*i.to_string()).into())
+                }),
+                "qux" => Promise::ok(()),
+                _ => Promise::err(Error::unimplemented(name)),
+            }
+        },
+    );
+    let client: test_extends::Client =
+        FromClientHook::new(capnp_rpc::new_client::<capnp::capability::Client, _>(server).hook);
+
+    let mut request = client
+        .clone()
+        .cast_to::<crate::test_capnp::test_interface::Client>()
+        .foo_request();
+    request.get().set_i(42);
+    let response = futures::executor::block_on(request.send().promise).unwrap();
+    assert_eq!(response.get().unwrap().get_x().unwrap(), "42");
+
+    futures::executor::block_on(client.qux_request().send().promise).unwrap();
+    assert!(futures::executor::block_on(client.grault_request().send().promise).is_err());
+    assert_eq!(*calls.borrow(), ["foo", "qux", "grault"]);
+}
+
 #[test]
 fn capability_list() {
     rpc_top_level(|_spawner, client| async move {
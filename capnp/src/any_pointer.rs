@@ -26,9 +26,13 @@ use alloc::{boxed::Box, vec::Vec};
 
 #[cfg(feature = "alloc")]
 use crate::capability::FromClientHook;
+use crate::dynamic_struct;
+#[cfg(target_has_atomic = "ptr")]
+use crate::orphan::Orphan;
 #[cfg(feature = "alloc")]
 use crate::private::capability::{ClientHook, PipelineHook, PipelineOp};
 use crate::private::layout::{PointerBuilder, PointerReader};
+use crate::schema::StructSchema;
 use crate::traits::{FromPointerBuilder, FromPointerReader, SetPointerBuilder};
 use crate::Result;
 
@@ -75,6 +79,14 @@ impl<'a> Reader<'a> {
         FromPointerReader::get_from_pointer(&self.reader, None)
     }
 
+    /// Interprets the target as a struct with the given schema.
+    pub fn get_as_dynamic(&self, schema: StructSchema) -> Result<dynamic_struct::Reader<'a>> {
+        Ok(dynamic_struct::Reader::new(
+            self.reader.get_struct(None)?,
+            schema,
+        ))
+    }
+
     #[cfg(feature = "alloc")]
     pub fn get_as_capability<T: FromClientHook>(&self) -> Result<T> {
         Ok(FromClientHook::new(self.reader.get_capability()?))
@@ -165,6 +177,34 @@ impl<'a> Builder<'a> {
         FromPointerBuilder::init_pointer(self.builder, size)
     }
 
+    /// Like `init_as()`, but returns an error if the message cannot allocate the space.
+    pub fn try_init_as<T: FromPointerBuilder<'a>>(self) -> Result<T> {
+        FromPointerBuilder::try_init_pointer(self.builder, 0)
+    }
+
+    /// Like `initn_as()`, but returns an error if the message cannot allocate the space.
+    pub fn try_initn_as<T: FromPointerBuilder<'a>>(self, size: u32) -> Result<T> {
+        FromPointerBuilder::try_init_pointer(self.builder, size)
+    }
+
+    /// Interprets the target as a struct with the given schema.
+    pub fn get_as_dynamic(self, schema: StructSchema) -> Result<dynamic_struct::Builder<'a>> {
+        let size = dynamic_struct::struct_size_from_schema(schema)?;
+        Ok(dynamic_struct::Builder::new(
+            self.builder.get_struct(size, None)?,
+            schema,
+        ))
+    }
+
+    /// Initializes the target as a struct with the given schema.
+    pub fn init_dynamic(self, schema: StructSchema) -> Result<dynamic_struct::Builder<'a>> {
+        let size = dynamic_struct::struct_size_from_schema(schema)?;
+        Ok(dynamic_struct::Builder::new(
+            self.builder.init_struct(size),
+            schema,
+        ))
+    }
+
     pub fn set_as<From: SetPointerBuilder>(&mut self, value: From) -> Result<()> {
         SetPointerBuilder::set_pointer_builder(self.builder.reborrow(), value, false)
     }
@@ -180,6 +220,19 @@ impl<'a> Builder<'a> {
         self.builder.clear()
     }
 
+    /// Detaches the target, leaving this pointer null.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn disown_as<T: crate::traits::Owned>(&mut self) -> Orphan<T> {
+        Orphan::new(self.builder.disown())
+    }
+
+    /// Makes this pointer point to the object held by `orphan`, which must belong to the same
+    /// message.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn adopt<T: crate::traits::Owned>(&mut self, orphan: Orphan<T>) {
+        self.builder.adopt(orphan.into_internal())
+    }
+
     pub fn into_reader(self) -> Reader<'a> {
         Reader {
             reader: self.builder.into_reader(),
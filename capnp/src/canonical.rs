@@ -0,0 +1,132 @@
+//! Hashing and comparison of values by their canonical encoding.
+//!
+//! Two values have the same [canonical form] exactly when they hold the same data, regardless
+//! of how each happens to be laid out in its message. That makes the canonical form a good
+//! basis for content addressing. [`message::Reader::canonicalize()`] produces it, but only by
+//! building a whole new message. The functions here instead stream it out in pieces, so that
+//! it can be fed straight into a hash function or digest:
+//!
+//! ```ignore
+//! let mut digest = Sha256::new();
+//! capnp::canonical::write(&person, |bytes| digest.update(bytes))?;
+//! let id = digest.finalize();
+//! ```
+//!
+//! A value is canonicalized as if it were the root of its own message, so the stream starts
+//! with the root pointer and is byte-for-byte what `canonicalize()` would return for a message
+//! with that root.
+//!
+//! [canonical form]: https://capnproto.org/encoding.html#canonicalization
+//! [`message::Reader::canonicalize()`]: crate::message::Reader::canonicalize
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::any_pointer;
+use crate::traits::IntoInternalStructReader;
+use crate::Result;
+
+/// A value that can be written in canonical form. Implemented by the readers of all struct
+/// types, including [`dynamic_struct::Reader`](crate::dynamic_struct::Reader), and by
+/// [`any_pointer::Reader`], which may point to any kind of object.
+pub trait Canonicalize {
+    #[doc(hidden)]
+    fn write_canonical(&self, sink: &mut dyn FnMut(&[u8])) -> Result<()>;
+}
+
+impl<'a, T> Canonicalize for T
+where
+    T: IntoInternalStructReader<'a> + Copy,
+{
+    fn write_canonical(&self, sink: &mut dyn FnMut(&[u8])) -> Result<()> {
+        self.into_internal_struct_reader().write_canonical(sink)
+    }
+}
+
+impl<'a> Canonicalize for any_pointer::Reader<'a> {
+    fn write_canonical(&self, sink: &mut dyn FnMut(&[u8])) -> Result<()> {
+        self.reader.write_canonical(sink)
+    }
+}
+
+/// Passes the canonical form of `value` to `sink`, in pieces.
+///
+/// Where the stream is split into pieces depends only on the canonical form, so two values
+/// with the same canonical form are always split the same way.
+///
+/// Fails if `value` contains a capability, or if traversing it would exceed the limits of the
+/// message it was read from. Traversal counts twice against the read limit, as it does for
+/// `canonicalize()`.
+pub fn write<T: Canonicalize>(value: &T, mut sink: impl FnMut(&[u8])) -> Result<()> {
+    value.write_canonical(&mut sink)
+}
+
+/// Feeds the canonical form of `value` into `state`.
+///
+/// Unlike `Hash::hash()`, this does not write any length prefixes, so the result is the same
+/// as that of writing the bytes of `canonicalize()`'s output to `state` in the pieces
+/// described at [`write()`].
+pub fn hash<T: Canonicalize, H: Hasher>(value: &T, state: &mut H) -> Result<()> {
+    write(value, |bytes| state.write(bytes))
+}
+
+/// Returns the canonical form of `value` as bytes.
+pub fn to_bytes<T: Canonicalize>(value: &T) -> Result<Vec<u8>> {
+    let mut result = Vec::new();
+    write(value, |bytes| result.extend_from_slice(bytes))?;
+    Ok(result)
+}
+
+/// A value together with its canonical form, which it is compared and hashed by.
+///
+/// This allows readers to be used as keys in a `HashMap` or `HashSet`, with values that hold
+/// the same data counted as equal even if they are laid out differently.
+#[derive(Clone)]
+pub struct Canonical<T> {
+    value: T,
+    bytes: Vec<u8>,
+}
+
+impl<T: Canonicalize> Canonical<T> {
+    /// Canonicalizes `value`. Fails in the same cases as [`write()`].
+    pub fn new(value: T) -> Result<Self> {
+        let bytes = to_bytes(&value)?;
+        Ok(Self { value, bytes })
+    }
+}
+
+impl<T> Canonical<T> {
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// The canonical form of the value.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> PartialEq for Canonical<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<T> Eq for Canonical<T> {}
+
+impl<T> Hash for Canonical<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Canonical<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
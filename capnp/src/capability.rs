@@ -329,6 +329,21 @@ impl Client {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl FromClientHook for Client {
+    fn new(hook: Box<dyn ClientHook>) -> Self {
+        Self { hook }
+    }
+
+    fn into_client_hook(self) -> Box<dyn ClientHook> {
+        self.hook
+    }
+
+    fn as_client_hook(&self) -> &dyn ClientHook {
+        &*self.hook
+    }
+}
+
 /// An untyped server.
 #[cfg(feature = "alloc")]
 pub trait Server {
@@ -40,16 +40,16 @@ where
 
 impl<T> crate::introspect::Introspect for Owned<T>
 where
-    T: FromClientHook,
+    T: crate::introspect::Introspect + FromClientHook,
 {
     fn introspect() -> crate::introspect::Type {
-        crate::introspect::Type::list_of(crate::introspect::TypeVariant::Capability.into())
+        crate::introspect::Type::list_of(T::introspect())
     }
 }
 
 impl<T> crate::traits::Owned for Owned<T>
 where
-    T: FromClientHook,
+    T: crate::introspect::Introspect + FromClientHook,
 {
     type Reader<'a> = Reader<'a, T>;
     type Builder<'a> = Builder<'a, T>;
@@ -213,6 +213,12 @@ where
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            marker: PhantomData,
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -278,20 +284,24 @@ where
     }
 }
 
-impl<'a, T: FromClientHook> From<Reader<'a, T>> for crate::dynamic_value::Reader<'a> {
+impl<'a, T: crate::introspect::Introspect + FromClientHook> From<Reader<'a, T>>
+    for crate::dynamic_value::Reader<'a>
+{
     fn from(t: Reader<'a, T>) -> crate::dynamic_value::Reader<'a> {
         crate::dynamic_value::Reader::List(crate::dynamic_list::Reader::new(
             t.reader,
-            crate::introspect::TypeVariant::Capability.into(),
+            T::introspect(),
         ))
     }
 }
 
-impl<'a, T: FromClientHook> From<Builder<'a, T>> for crate::dynamic_value::Builder<'a> {
+impl<'a, T: crate::introspect::Introspect + FromClientHook> From<Builder<'a, T>>
+    for crate::dynamic_value::Builder<'a>
+{
     fn from(t: Builder<'a, T>) -> crate::dynamic_value::Builder<'a> {
         crate::dynamic_value::Builder::List(crate::dynamic_list::Builder::new(
             t.builder,
-            crate::introspect::TypeVariant::Capability.into(),
+            T::introspect(),
         ))
     }
 }
@@ -63,6 +63,9 @@ impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     fn init_pointer(builder: PointerBuilder<'a>, size: u32) -> Builder<'a> {
         builder.init_data(size)
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        builder.try_init_data(size)
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -77,8 +80,7 @@ impl<'a> crate::traits::SetPointerBuilder for Reader<'a> {
         value: Reader<'a>,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_data(value);
-        Ok(())
+        pointer.try_set_data(value)
     }
 }
 
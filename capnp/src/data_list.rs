@@ -150,6 +150,12 @@ impl<'a> FromPointerBuilder<'a> for Builder<'a> {
         }
     }
 
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        Ok(Builder {
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
+
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -0,0 +1,264 @@
+//! Dynamically-typed capabilities, for calling and implementing interfaces that are only
+//! known through their schemas.
+
+use alloc::boxed::Box;
+
+use alloc::string::ToString;
+
+use crate::capability::{self, FromClientHook, FromServer, Promise};
+use crate::introspect::{Introspect, TypeVariant};
+use crate::private::capability::{ParamsHook, RequestHook, ResponseHook, ResultsHook};
+use crate::schema::{InterfaceSchema, Method, StructSchema};
+use crate::{any_pointer, dynamic_struct};
+use crate::{Error, MessageSize, Result};
+
+/// A client for a capability whose interface is described by an `InterfaceSchema`.
+pub struct Client {
+    pub client: capability::Client,
+    schema: InterfaceSchema,
+}
+
+impl Client {
+    /// Wraps `client`, which is assumed to implement the interface described by `schema`.
+    /// If it does not, method calls will fail with "unimplemented" errors.
+    pub fn new(client: capability::Client, schema: InterfaceSchema) -> Self {
+        Self { client, schema }
+    }
+
+    /// Converts a generated client type into a dynamic one.
+    pub fn from_typed<C: FromClientHook + Introspect>(typed: C) -> Self {
+        let TypeVariant::Capability(raw) = C::introspect().which() else {
+            panic!("client type does not introspect to a capability");
+        };
+        Self::new(
+            capability::Client::new(typed.into_client_hook()),
+            raw.into(),
+        )
+    }
+
+    pub fn get_schema(&self) -> InterfaceSchema {
+        self.schema
+    }
+
+    /// Starts a call to `method`, which must belong to this client's interface or one of its
+    /// superclasses.
+    pub fn new_request(&self, method: Method, size_hint: Option<MessageSize>) -> Request {
+        let params_schema = method.get_param_type();
+        let results_schema = method.get_result_type();
+        let typeless = self.client.hook.new_call(
+            method.get_containing_interface().get_proto().get_id(),
+            method.get_ordinal(),
+            size_hint,
+        );
+        Request {
+            hook: typeless.hook,
+            params_schema,
+            results_schema,
+        }
+    }
+
+    /// Like `new_request()`, but looks up the method by name.
+    pub fn new_request_named(
+        &self,
+        method_name: &str,
+        size_hint: Option<MessageSize>,
+    ) -> Result<Request> {
+        let method = self.schema.get_method_by_name(method_name)?;
+        Ok(self.new_request(method, size_hint))
+    }
+
+    /// Casts to a generated client type.
+    pub fn downcast<T: FromClientHook>(self) -> T {
+        FromClientHook::new(self.client.hook)
+    }
+}
+
+impl Clone for Client {
+    fn clone(&self) -> Self {
+        Self {
+            client: capability::Client::new(self.client.hook.add_ref()),
+            schema: self.schema,
+        }
+    }
+}
+
+/// A method call that has not been sent yet.
+pub struct Request {
+    hook: Box<dyn RequestHook>,
+    params_schema: StructSchema,
+    results_schema: StructSchema,
+}
+
+impl Request {
+    /// Gets the params struct, for filling in.
+    pub fn get(&mut self) -> dynamic_struct::Builder<'_> {
+        self.hook
+            .get()
+            .get_as_dynamic(self.params_schema)
+            .expect("params schema is a struct")
+    }
+
+    pub fn send(self) -> Promise<Response, Error> {
+        let results_schema = self.results_schema;
+        let promise = self.hook.send().promise;
+        Promise::from_future(async move {
+            Ok(Response {
+                hook: promise.await?.hook,
+                schema: results_schema,
+            })
+        })
+    }
+}
+
+/// A response from a method call, as seen by the client.
+pub struct Response {
+    hook: Box<dyn ResponseHook>,
+    schema: StructSchema,
+}
+
+impl Response {
+    pub fn get(&self) -> Result<dynamic_struct::Reader<'_>> {
+        self.hook.get()?.get_as_dynamic(self.schema)
+    }
+}
+
+/// The parameters of a call, as seen by a `Server`.
+pub struct Params {
+    hook: Box<dyn ParamsHook>,
+    schema: StructSchema,
+}
+
+impl Params {
+    pub fn get(&self) -> Result<dynamic_struct::Reader<'_>> {
+        self.hook.get()?.get_as_dynamic(self.schema)
+    }
+}
+
+/// The results of a call, written in-place by a `Server`.
+pub struct Results {
+    hook: Box<dyn ResultsHook>,
+    schema: StructSchema,
+}
+
+impl Results {
+    pub fn get(&mut self) -> Result<dynamic_struct::Builder<'_>> {
+        self.hook.get()?.get_as_dynamic(self.schema)
+    }
+}
+
+/// Implements the interface described by an `InterfaceSchema`, including the methods of its
+/// superclasses, by passing every call to `handler`.
+///
+/// `Params` and `Results` are owned, so the promise returned by `handler` may hold onto them,
+/// for example to fill in the results once a forwarded call completes.
+///
+/// To obtain a client, pass a `Server` to `capnp_rpc::new_client()` with
+/// `capability::Client` as the client type.
+pub struct Server<F> {
+    schema: InterfaceSchema,
+    handler: F,
+}
+
+impl<F> Server<F>
+where
+    F: FnMut(Method, Params, Results) -> Promise<(), Error>,
+{
+    pub fn new(schema: InterfaceSchema, handler: F) -> Self {
+        Self { schema, handler }
+    }
+
+    pub fn get_schema(&self) -> InterfaceSchema {
+        self.schema
+    }
+}
+
+/// Finds the method that a call to (`interface_id`, `method_id`) refers to, if `schema` or one
+/// of its superclasses has it.
+fn find_method(
+    schema: InterfaceSchema,
+    interface_id: u64,
+    method_id: u16,
+) -> Result<Option<Method>> {
+    schema.find_in_hierarchy(&mut |schema| {
+        if schema.get_proto().get_id() != interface_id {
+            return Ok(None);
+        }
+        let methods = schema.get_methods()?;
+        Ok((method_id < methods.len()).then(|| methods.get(method_id)))
+    })
+}
+
+impl<F> capability::Server for Server<F>
+where
+    F: FnMut(Method, Params, Results) -> Promise<(), Error>,
+{
+    fn dispatch_call(
+        &mut self,
+        interface_id: u64,
+        method_id: u16,
+        params: capability::Params<any_pointer::Owned>,
+        results: capability::Results<any_pointer::Owned>,
+    ) -> Promise<(), Error> {
+        let method = match find_method(self.schema, interface_id, method_id) {
+            Ok(Some(method)) => method,
+            Ok(None) => {
+                return Promise::err(Error::unimplemented("Method not implemented.".to_string()))
+            }
+            Err(e) => return Promise::err(e),
+        };
+        let params = Params {
+            hook: params.hook,
+            schema: method.get_param_type(),
+        };
+        let results = Results {
+            hook: results.hook,
+            schema: method.get_result_type(),
+        };
+        (self.handler)(method, params, results)
+    }
+}
+
+/// Wraps a `Server` for use with `capnp_rpc::new_client()`.
+pub struct ServerDispatch<F> {
+    pub server: Server<F>,
+}
+
+impl<F> core::ops::Deref for ServerDispatch<F> {
+    type Target = Server<F>;
+    fn deref(&self) -> &Server<F> {
+        &self.server
+    }
+}
+
+impl<F> core::ops::DerefMut for ServerDispatch<F> {
+    fn deref_mut(&mut self) -> &mut Server<F> {
+        &mut self.server
+    }
+}
+
+impl<F> capability::Server for ServerDispatch<F>
+where
+    F: FnMut(Method, Params, Results) -> Promise<(), Error>,
+{
+    fn dispatch_call(
+        &mut self,
+        interface_id: u64,
+        method_id: u16,
+        params: capability::Params<any_pointer::Owned>,
+        results: capability::Results<any_pointer::Owned>,
+    ) -> Promise<(), Error> {
+        self.server
+            .dispatch_call(interface_id, method_id, params, results)
+    }
+}
+
+impl<F> FromServer<Server<F>> for capability::Client
+where
+    F: FnMut(Method, Params, Results) -> Promise<(), Error> + 'static,
+{
+    type Dispatch = ServerDispatch<F>;
+
+    fn from_server(server: Server<F>) -> ServerDispatch<F> {
+        ServerDispatch { server }
+    }
+}
@@ -1,5 +1,7 @@
 //! Dynamically-typed lists.
 
+#[cfg(feature = "alloc")]
+use crate::dynamic_struct::FieldPath;
 use crate::dynamic_value;
 use crate::introspect::{Type, TypeVariant};
 use crate::private::layout::{self, PrimitiveElement};
@@ -115,12 +117,18 @@ impl<'a> Reader<'a> {
             TypeVariant::AnyPointer => {
                 Ok(crate::any_pointer::Reader::new(self.reader.get_pointer_element(index)).into())
             }
-            TypeVariant::Capability => {
+            TypeVariant::Capability(_) => {
                 Ok(dynamic_value::Reader::Capability(dynamic_value::Capability))
             }
         }
     }
 
+    /// Gets the value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn get_path(self, path: &FieldPath) -> Result<dynamic_value::Reader<'a>> {
+        dynamic_value::Reader::from(self).get_path(path)
+    }
+
     pub fn iter(self) -> ListIter<Reader<'a>, Result<dynamic_value::Reader<'a>>> {
         ListIter::new(self, self.len())
     }
@@ -253,7 +261,7 @@ impl<'a> Builder<'a> {
                 self.builder.get_pointer_element(index),
             )
             .into()),
-            TypeVariant::Capability => Ok(dynamic_value::Builder::Capability(
+            TypeVariant::Capability(_) => Ok(dynamic_value::Builder::Capability(
                 dynamic_value::Capability,
             )),
         }
@@ -311,20 +319,16 @@ impl<'a> Builder<'a> {
                 PrimitiveElement::set(&self.builder, index, e.get_value());
                 Ok(())
             }
-            (TypeVariant::Text, dynamic_value::Reader::Text(t)) => {
-                self.builder
-                    .reborrow()
-                    .get_pointer_element(index)
-                    .set_text(t);
-                Ok(())
-            }
-            (TypeVariant::Data, dynamic_value::Reader::Data(d)) => {
-                self.builder
-                    .reborrow()
-                    .get_pointer_element(index)
-                    .set_data(d);
-                Ok(())
-            }
+            (TypeVariant::Text, dynamic_value::Reader::Text(t)) => self
+                .builder
+                .reborrow()
+                .get_pointer_element(index)
+                .try_set_text(t),
+            (TypeVariant::Data, dynamic_value::Reader::Data(d)) => self
+                .builder
+                .reborrow()
+                .get_pointer_element(index)
+                .try_set_data(d),
             (TypeVariant::Struct(ss), dynamic_value::Reader::Struct(s)) => {
                 assert_eq!(ss, s.get_schema().raw);
                 self.builder
@@ -340,7 +344,7 @@ impl<'a> Builder<'a> {
             (TypeVariant::AnyPointer, _) => {
                 Err(Error::from_kind(ErrorKind::ListAnyPointerNotSupported))
             }
-            (TypeVariant::Capability, dynamic_value::Reader::Capability(_)) => {
+            (TypeVariant::Capability(_), dynamic_value::Reader::Capability(_)) => {
                 Err(Error::from_kind(ErrorKind::ListCapabilityNotSupported))
             }
             (_, _) => Err(Error::from_kind(ErrorKind::TypeMismatch)),
@@ -364,7 +368,7 @@ impl<'a> Builder<'a> {
             | TypeVariant::Float64
             | TypeVariant::Enum(_)
             | TypeVariant::Struct(_)
-            | TypeVariant::Capability => Err(Error::from_kind(ErrorKind::ExpectedAListOrBlob)),
+            | TypeVariant::Capability(_) => Err(Error::from_kind(ErrorKind::ExpectedAListOrBlob)),
             TypeVariant::Text => Ok(self
                 .builder
                 .get_pointer_element(index)
@@ -395,6 +399,30 @@ impl<'a> Builder<'a> {
             TypeVariant::AnyPointer => Err(Error::from_kind(ErrorKind::ListAnyPointerNotSupported)),
         }
     }
+
+    /// Gets the value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn get_path(self, path: &FieldPath) -> Result<dynamic_value::Builder<'a>> {
+        dynamic_value::Builder::from(self).get_path(path)
+    }
+
+    /// Sets the value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn set_path(&mut self, path: &FieldPath, value: dynamic_value::Reader<'_>) -> Result<()> {
+        dynamic_value::Builder::from(self.reborrow()).set_path(path, value)
+    }
+
+    /// Initializes the struct or AnyPointer value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn init_path(self, path: &FieldPath) -> Result<dynamic_value::Builder<'a>> {
+        dynamic_value::Builder::from(self).init_path(path)
+    }
+
+    /// Initializes the list, text or data value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn initn_path(self, path: &FieldPath, size: u32) -> Result<dynamic_value::Builder<'a>> {
+        dynamic_value::Builder::from(self).initn_path(path, size)
+    }
 }
 
 impl<'a> crate::traits::SetPointerBuilder for Reader<'a> {
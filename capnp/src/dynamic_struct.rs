@@ -157,7 +157,7 @@ impl<'a> Reader<'a> {
                         };
                         Ok(dynamic_value::Reader::AnyPointer(a1))
                     }
-                    (TypeVariant::Capability, value::Interface(())) => {
+                    (TypeVariant::Capability(_), value::Interface(())) => {
                         Ok(dynamic_value::Reader::Capability(dynamic_value::Capability))
                     }
                     _ => Err(Error::from_kind(ErrorKind::FieldAndDefaultMismatch)),
@@ -230,11 +230,17 @@ impl<'a> Reader<'a> {
         let field = self.schema.get_field_by_name(field_name)?;
         self.has(field)
     }
+
+    /// Gets the value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn get_path(self, path: &FieldPath) -> Result<dynamic_value::Reader<'a>> {
+        dynamic_value::Reader::from(self).get_path(path)
+    }
 }
 
 /// A mutable dynamically-typed struct.
 pub struct Builder<'a> {
-    builder: layout::StructBuilder<'a>,
+    pub(crate) builder: layout::StructBuilder<'a>,
     schema: StructSchema,
 }
 
@@ -334,7 +340,7 @@ impl<'a> Builder<'a> {
                             // If the type is a generic, then the default value
                             // is always an empty AnyPointer. Ignore that case.
                             if let value::Text(t) = dval {
-                                p.set_text(t?);
+                                p.try_set_text(t?)?;
                             }
                         }
                         Ok(dynamic_value::Builder::Text(p.get_text(None)?))
@@ -345,7 +351,7 @@ impl<'a> Builder<'a> {
                             // If the type is a generic, then the default value
                             // is always an empty AnyPointer. Ignore that case.
                             if let value::Data(d) = dval {
-                                p.set_data(d?);
+                                p.try_set_data(d?)?;
                             }
                         }
                         Ok(dynamic_value::Builder::Data(p.get_data(None)?))
@@ -387,7 +393,7 @@ impl<'a> Builder<'a> {
                         )
                         .into())
                     }
-                    (TypeVariant::Capability, value::Interface(())) => Ok(
+                    (TypeVariant::Capability(_), value::Interface(())) => Ok(
                         dynamic_value::Builder::Capability(dynamic_value::Capability),
                     ),
                     _ => Err(Error::from_kind(ErrorKind::FieldAndDefaultMismatch)),
@@ -502,13 +508,11 @@ impl<'a> Builder<'a> {
                     }
                     (TypeVariant::Text, dynamic_value::Reader::Text(tv), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
-                        p.set_text(tv);
-                        Ok(())
+                        p.try_set_text(tv)
                     }
                     (TypeVariant::Data, dynamic_value::Reader::Data(v), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
-                        p.set_data(v);
-                        Ok(())
+                        p.try_set_data(v)
                     }
                     (TypeVariant::List(_), dynamic_value::Reader::List(l), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
@@ -535,7 +539,7 @@ impl<'a> Builder<'a> {
                             )),
                         }
                     }
-                    (TypeVariant::Capability, _, _) => Err(Error::from_kind(
+                    (TypeVariant::Capability(_), _, _) => Err(Error::from_kind(
                         ErrorKind::SettingDynamicCapabilitiesIsUnsupported,
                     )),
                     _ => Err(Error::from_kind(ErrorKind::TypeMismatch)),
@@ -661,6 +665,30 @@ impl<'a> Builder<'a> {
         self.initn(field, size)
     }
 
+    /// Gets the value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn get_path(self, path: &FieldPath) -> Result<dynamic_value::Builder<'a>> {
+        dynamic_value::Builder::from(self).get_path(path)
+    }
+
+    /// Sets the value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn set_path(&mut self, path: &FieldPath, value: dynamic_value::Reader<'_>) -> Result<()> {
+        dynamic_value::Builder::from(self.reborrow()).set_path(path, value)
+    }
+
+    /// Initializes the struct or AnyPointer value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn init_path(self, path: &FieldPath) -> Result<dynamic_value::Builder<'a>> {
+        dynamic_value::Builder::from(self).init_path(path)
+    }
+
+    /// Initializes the list, text or data value at the end of `path`. See [`FieldPath`].
+    #[cfg(feature = "alloc")]
+    pub fn initn_path(self, path: &FieldPath, size: u32) -> Result<dynamic_value::Builder<'a>> {
+        dynamic_value::Builder::from(self).initn_path(path, size)
+    }
+
     /// Clears a field, setting it to its default value. For pointer fields,
     /// this makes the field null.
     pub fn clear(&mut self, field: Field) -> Result<()> {
@@ -725,7 +753,7 @@ impl<'a> Builder<'a> {
                     | TypeVariant::Struct(_)
                     | TypeVariant::List(_)
                     | TypeVariant::AnyPointer
-                    | TypeVariant::Capability => {
+                    | TypeVariant::Capability(_) => {
                         self.builder.reborrow().get_pointer_field(offset).clear();
                         Ok(())
                     }
@@ -780,3 +808,201 @@ impl<'a> crate::traits::SetPointerBuilder for Reader<'a> {
         pointer.set_struct(&value.reader, canonicalize)
     }
 }
+
+impl<'a> crate::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> layout::StructReader<'a> {
+        self.reader
+    }
+}
+
+/// One step of a [`FieldPath`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A field of a struct, by name.
+    Field(alloc::string::String),
+
+    /// An element of a list, by index.
+    Index(u32),
+}
+
+/// A path to a value nested inside a struct or list, such as `orders[2].items[0].price`.
+///
+/// Each `.name` selects a field, including group fields, and each `[n]` selects a list
+/// element. A path may start with either; the empty path refers to the value itself. Paths
+/// are resolved with `get_path()`, `set_path()`, `init_path()` and `initn_path()` on the
+/// dynamic readers and builders. A path that does not resolve fails with
+/// `ErrorKind::FieldPathSegmentNotFound`, which holds the index of the offending segment.
+///
+/// Union members other than the active one do not exist for the purposes of `get_path()`.
+/// The last segment given to `set_path()`, `init_path()` or `initn_path()` may name an
+/// inactive union member, which then becomes active.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldPath {
+    segments: alloc::vec::Vec<PathSegment>,
+}
+
+#[cfg(feature = "alloc")]
+impl FieldPath {
+    /// Parses a path of the form `foo.bar[3].baz`.
+    pub fn parse(path: &str) -> Result<Self> {
+        let bytes = path.as_bytes();
+        let mut segments = alloc::vec::Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if bytes[pos] == b'[' {
+                let start = pos + 1;
+                let end = start
+                    + bytes[start..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                if end == start || bytes.get(end) != Some(&b']') {
+                    return Err(invalid_path(path, start, "expected list index"));
+                }
+                let Ok(index) = path[start..end].parse() else {
+                    return Err(invalid_path(path, start, "list index is too large"));
+                };
+                segments.push(PathSegment::Index(index));
+                pos = end + 1;
+            } else {
+                if !segments.is_empty() {
+                    if bytes[pos] != b'.' {
+                        return Err(invalid_path(path, pos, "expected '.' or '['"));
+                    }
+                    pos += 1;
+                }
+                let start = pos;
+                let end = start
+                    + bytes[start..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+                        .count();
+                if end == start {
+                    return Err(invalid_path(path, start, "expected field name"));
+                }
+                segments.push(PathSegment::Field(path[start..end].into()));
+                pos = end;
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Looks up the field named by segment `index`.
+    pub(crate) fn field(&self, index: usize, schema: StructSchema, name: &str) -> Result<Field> {
+        match schema.find_field_by_name(name)? {
+            Some(field) => Ok(field),
+            None => Err(self.not_found(index, "no such field")),
+        }
+    }
+
+    /// Checks that `field`, named by segment `index`, is not an inactive union member.
+    pub(crate) fn check_active(
+        &self,
+        index: usize,
+        field: Field,
+        active: Option<Field>,
+    ) -> Result<()> {
+        if has_discriminant_value(field.get_proto())
+            && active.map(|f| f.get_index()) != Some(field.get_index())
+        {
+            Err(self.not_found(index, "not the active union member"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the list element selected by segment `index` exists.
+    pub(crate) fn check_index(&self, index: usize, element: u32, len: u32) -> Result<()> {
+        if element < len {
+            Ok(())
+        } else {
+            let mut error = self.not_found(index, "index out of bounds");
+            write!(error, " for list of length {len}");
+            Err(error)
+        }
+    }
+
+    /// Returns the error for segment `index` being applied to a value of the wrong type.
+    pub(crate) fn mismatch(&self, index: usize) -> Error {
+        match self.segments[index] {
+            PathSegment::Field(_) => self.not_found(index, "not a struct"),
+            PathSegment::Index(_) => self.not_found(index, "not a list"),
+        }
+    }
+
+    /// Splits off the last segment, which `set_path()` and friends act on.
+    pub(crate) fn split_last(&self) -> Result<(usize, &PathSegment)> {
+        match self.segments.last() {
+            Some(last) => Ok((self.segments.len() - 1, last)),
+            None => {
+                let mut error = Error::from_kind(ErrorKind::InvalidFieldPath);
+                write!(error, "path is empty");
+                Err(error)
+            }
+        }
+    }
+
+    fn not_found(&self, index: usize, reason: &str) -> Error {
+        let mut error = Error::from_kind(ErrorKind::FieldPathSegmentNotFound(index));
+        write!(
+            error,
+            "{}: {reason}",
+            DisplaySegments(&self.segments[..=index])
+        );
+        error
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn invalid_path(path: &str, pos: usize, message: &str) -> Error {
+    let mut error = Error::from_kind(ErrorKind::InvalidFieldPath);
+    write!(error, "{message} at column {} of `{path}`", pos + 1);
+    error
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for FieldPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self> {
+        Self::parse(path)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        DisplaySegments(&self.segments).fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct DisplaySegments<'a>(&'a [PathSegment]);
+
+#[cfg(feature = "alloc")]
+impl<'a> core::fmt::Display for DisplaySegments<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 //! Dynamically typed values.
 
+#[cfg(feature = "alloc")]
+use crate::dynamic_struct::{FieldPath, PathSegment};
 use crate::introspect::{self, TypeVariant};
 use crate::schema_capnp::value;
 use crate::Result;
@@ -57,7 +59,7 @@ impl<'a> Reader<'a> {
                     element_type,
                 )))
             }
-            (value::Interface(()), TypeVariant::Capability) => Ok(Capability.into()),
+            (value::Interface(()), TypeVariant::Capability(_)) => Ok(Capability.into()),
             (value::AnyPointer(a), TypeVariant::AnyPointer) => Ok(a.into()),
             _ => Err(crate::Error::from_kind(crate::ErrorKind::TypeMismatch)),
         }
@@ -315,3 +317,365 @@ impl<'a> From<Capability> for Builder<'a> {
         Builder::Capability(c)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<'a> Reader<'a> {
+    /// Returns the value that `path` leads to from this one.
+    pub fn get_path(self, path: &FieldPath) -> Result<Reader<'a>> {
+        let mut value = self;
+        for (index, segment) in path.segments().iter().enumerate() {
+            value = match (value, segment) {
+                (Reader::Struct(s), PathSegment::Field(name)) => {
+                    let field = path.field(index, s.get_schema(), name)?;
+                    path.check_active(index, field, s.which()?)?;
+                    s.get(field)?
+                }
+                (Reader::List(l), PathSegment::Index(element)) => {
+                    path.check_index(index, *element, l.len())?;
+                    l.get(*element)?
+                }
+                _ => return Err(path.mismatch(index)),
+            };
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Builder<'a> {
+    /// Returns the value that `path` leads to from this one.
+    pub fn get_path(self, path: &FieldPath) -> Result<Builder<'a>> {
+        self.get_path_prefix(path, path.len())
+    }
+
+    /// Sets the value that `path` leads to from this one. The path must not be empty.
+    pub fn set_path(&mut self, path: &FieldPath, value: Reader<'_>) -> Result<()> {
+        let (index, last) = path.split_last()?;
+        match (self.reborrow().get_path_prefix(path, index)?, last) {
+            (Builder::Struct(mut s), PathSegment::Field(name)) => {
+                let field = path.field(index, s.get_schema(), name)?;
+                s.set(field, value)
+            }
+            (Builder::List(mut l), PathSegment::Index(element)) => {
+                path.check_index(index, *element, l.len())?;
+                l.set(*element, value)
+            }
+            _ => Err(path.mismatch(index)),
+        }
+    }
+
+    /// Initializes the struct or AnyPointer field that `path` leads to from this one, and
+    /// returns it. The path must not be empty.
+    pub fn init_path(self, path: &FieldPath) -> Result<Builder<'a>> {
+        let (index, last) = path.split_last()?;
+        match (self.get_path_prefix(path, index)?, last) {
+            (Builder::Struct(s), PathSegment::Field(name)) => {
+                let field = path.field(index, s.get_schema(), name)?;
+                s.init(field)
+            }
+            (Builder::List(_), PathSegment::Index(_)) => Err(crate::Error::from_kind(
+                crate::ErrorKind::InitIsOnlyValidForStructAndAnyPointerFields,
+            )),
+            _ => Err(path.mismatch(index)),
+        }
+    }
+
+    /// Initializes the list, text or data value that `path` leads to from this one to
+    /// `size` elements, and returns it. The path must not be empty.
+    pub fn initn_path(self, path: &FieldPath, size: u32) -> Result<Builder<'a>> {
+        let (index, last) = path.split_last()?;
+        match (self.get_path_prefix(path, index)?, last) {
+            (Builder::Struct(s), PathSegment::Field(name)) => {
+                let field = path.field(index, s.get_schema(), name)?;
+                s.initn(field, size)
+            }
+            (Builder::List(l), PathSegment::Index(element)) => {
+                path.check_index(index, *element, l.len())?;
+                l.init(*element, size)
+            }
+            _ => Err(path.mismatch(index)),
+        }
+    }
+
+    /// Follows the first `len` segments of `path`.
+    fn get_path_prefix(self, path: &FieldPath, len: usize) -> Result<Builder<'a>> {
+        let mut value = self;
+        for (index, segment) in path.segments()[..len].iter().enumerate() {
+            value = match (value, segment) {
+                (Builder::Struct(s), PathSegment::Field(name)) => {
+                    let field = path.field(index, s.get_schema(), name)?;
+                    path.check_active(index, field, s.which()?)?;
+                    s.get(field)?
+                }
+                (Builder::List(l), PathSegment::Index(element)) => {
+                    path.check_index(index, *element, l.len())?;
+                    l.get(*element)?
+                }
+                _ => return Err(path.mismatch(index)),
+            };
+        }
+        Ok(value)
+    }
+}
+
+/// A place where two values differ, as found by [`diff()`].
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Difference<'a> {
+    /// Where the values differ, like `foo.bar[3].baz`. Empty if the top-level values differ.
+    pub path: alloc::string::String,
+
+    /// The value on the left, or `None` if it has no value at `path`, because another union
+    /// member is set or a list is shorter.
+    pub left: Option<Reader<'a>>,
+
+    /// The value on the right, or `None` if it has no value at `path`.
+    pub right: Option<Reader<'a>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> core::fmt::Display for Difference<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.path.is_empty() {
+            f.write_str("<root>")?;
+        } else {
+            f.write_str(&self.path)?;
+        }
+        f.write_str(": ")?;
+        match &self.left {
+            Some(value) => write!(f, "{value:?}")?,
+            None => f.write_str("<absent>")?,
+        }
+        f.write_str(" -> ")?;
+        match &self.right {
+            Some(value) => write!(f, "{value:?}"),
+            None => f.write_str("<absent>"),
+        }
+    }
+}
+
+/// Walks two values of the same type and returns the places where they differ.
+///
+/// Structs are compared field by field, including groups and the active member of a union;
+/// if the active members differ, both are reported. Lists are compared element by element.
+/// Floats are compared by their bit patterns, so that NaNs compare equal to themselves, and
+/// AnyPointers by their canonical encodings. Null pointers compare equal to their defaults.
+///
+/// Returns an error if the two values are of different types.
+#[cfg(feature = "alloc")]
+pub fn diff<'a>(
+    left: impl Into<Reader<'a>>,
+    right: impl Into<Reader<'a>>,
+) -> Result<alloc::vec::Vec<Difference<'a>>> {
+    let mut differences = alloc::vec::Vec::new();
+    let mut path = alloc::string::String::new();
+    diff_values(left.into(), right.into(), &mut path, &mut differences)?;
+    Ok(differences)
+}
+
+#[cfg(feature = "alloc")]
+fn diff_values<'a>(
+    left: Reader<'a>,
+    right: Reader<'a>,
+    path: &mut alloc::string::String,
+    differences: &mut alloc::vec::Vec<Difference<'a>>,
+) -> Result<()> {
+    let equal = match (left, right) {
+        (Reader::Void, Reader::Void) => true,
+        (Reader::Bool(a), Reader::Bool(b)) => a == b,
+        (Reader::Int8(a), Reader::Int8(b)) => a == b,
+        (Reader::Int16(a), Reader::Int16(b)) => a == b,
+        (Reader::Int32(a), Reader::Int32(b)) => a == b,
+        (Reader::Int64(a), Reader::Int64(b)) => a == b,
+        (Reader::UInt8(a), Reader::UInt8(b)) => a == b,
+        (Reader::UInt16(a), Reader::UInt16(b)) => a == b,
+        (Reader::UInt32(a), Reader::UInt32(b)) => a == b,
+        (Reader::UInt64(a), Reader::UInt64(b)) => a == b,
+        (Reader::Float32(a), Reader::Float32(b)) => a.to_bits() == b.to_bits(),
+        (Reader::Float64(a), Reader::Float64(b)) => a.to_bits() == b.to_bits(),
+        (Reader::Enum(a), Reader::Enum(b)) => a.get_value() == b.get_value(),
+        (Reader::Text(a), Reader::Text(b)) => a.as_bytes() == b.as_bytes(),
+        (Reader::Data(a), Reader::Data(b)) => a == b,
+        (Reader::Capability(_), Reader::Capability(_)) => true,
+        (Reader::AnyPointer(a), Reader::AnyPointer(b)) => {
+            canonical_bytes(a)? == canonical_bytes(b)?
+        }
+        (Reader::Struct(a), Reader::Struct(b)) => {
+            return diff_structs(a, b, path, differences);
+        }
+        (Reader::List(a), Reader::List(b)) => {
+            return diff_lists(a, b, path, differences);
+        }
+        _ => return Err(crate::Error::from_kind(crate::ErrorKind::TypeMismatch)),
+    };
+    if !equal {
+        differences.push(Difference {
+            path: path.clone(),
+            left: Some(left),
+            right: Some(right),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+fn diff_structs<'a>(
+    left: dynamic_struct::Reader<'a>,
+    right: dynamic_struct::Reader<'a>,
+    path: &mut alloc::string::String,
+    differences: &mut alloc::vec::Vec<Difference<'a>>,
+) -> Result<()> {
+    let schema = left.get_schema();
+    if schema.raw != right.get_schema().raw {
+        return Err(crate::Error::from_kind(crate::ErrorKind::TypeMismatch));
+    }
+    let path_len = path.len();
+    for field in schema.get_non_union_fields()? {
+        if both_null(left, right, field)? {
+            continue;
+        }
+        push_field_name(path, field)?;
+        diff_values(left.get(field)?, right.get(field)?, path, differences)?;
+        path.truncate(path_len);
+    }
+    match (left.which()?, right.which()?) {
+        (Some(a), Some(b)) if a.get_index() == b.get_index() => {
+            if !both_null(left, right, a)? {
+                push_field_name(path, a)?;
+                diff_values(left.get(a)?, right.get(b)?, path, differences)?;
+                path.truncate(path_len);
+            }
+        }
+        (a, b) => {
+            if let Some(a) = a {
+                push_field_name(path, a)?;
+                differences.push(Difference {
+                    path: path.clone(),
+                    left: Some(left.get(a)?),
+                    right: None,
+                });
+                path.truncate(path_len);
+            }
+            if let Some(b) = b {
+                push_field_name(path, b)?;
+                differences.push(Difference {
+                    path: path.clone(),
+                    left: None,
+                    right: Some(right.get(b)?),
+                });
+                path.truncate(path_len);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if `field` is a pointer field that is null in both structs. Such fields are
+/// skipped, because comparing their defaults would recurse forever on a self-referential struct.
+#[cfg(feature = "alloc")]
+fn both_null(
+    left: dynamic_struct::Reader,
+    right: dynamic_struct::Reader,
+    field: crate::schema::Field,
+) -> Result<bool> {
+    Ok(field.get_type().is_pointer_type() && !left.has(field)? && !right.has(field)?)
+}
+
+#[cfg(feature = "alloc")]
+fn diff_lists<'a>(
+    left: dynamic_list::Reader<'a>,
+    right: dynamic_list::Reader<'a>,
+    path: &mut alloc::string::String,
+    differences: &mut alloc::vec::Vec<Difference<'a>>,
+) -> Result<()> {
+    use core::fmt::Write;
+
+    let path_len = path.len();
+    for index in 0..core::cmp::max(left.len(), right.len()) {
+        let _ = write!(path, "[{index}]");
+        if index >= right.len() {
+            differences.push(Difference {
+                path: path.clone(),
+                left: Some(left.get(index)?),
+                right: None,
+            });
+        } else if index >= left.len() {
+            differences.push(Difference {
+                path: path.clone(),
+                left: None,
+                right: Some(right.get(index)?),
+            });
+        } else {
+            diff_values(left.get(index)?, right.get(index)?, path, differences)?;
+        }
+        path.truncate(path_len);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+fn push_field_name(path: &mut alloc::string::String, field: crate::schema::Field) -> Result<()> {
+    if !path.is_empty() {
+        path.push('.');
+    }
+    path.push_str(field.get_proto().get_name()?.to_str()?);
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+fn canonical_bytes(pointer: crate::any_pointer::Reader) -> Result<alloc::vec::Vec<u8>> {
+    if pointer.is_null() {
+        return Ok(alloc::vec::Vec::new());
+    }
+    let mut message = crate::message::Builder::new_default();
+    message.set_root_canonical(pointer)?;
+    Ok(message.get_segments_for_output().concat())
+}
+
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn assert_eq_impl<'a>(
+    left: impl Into<Reader<'a>>,
+    right: impl Into<Reader<'a>>,
+    args: Option<core::fmt::Arguments>,
+) {
+    let differences = match diff(left, right) {
+        Ok(differences) => differences,
+        Err(e) => panic!("assertion `left == right` failed: could not compare values: {e}"),
+    };
+    if differences.is_empty() {
+        return;
+    }
+    let mut message = alloc::string::String::new();
+    for difference in &differences {
+        use core::fmt::Write;
+        let _ = write!(message, "\n  {difference}");
+    }
+    match args {
+        Some(args) => panic!("assertion `left == right` failed: {args}{message}"),
+        None => panic!("assertion `left == right` failed{message}"),
+    }
+}
+
+/// Asserts that two dynamically-typed values are equal, as determined by [`dynamic_value::diff()`].
+/// On failure, the panic message lists each difference on its own line, as
+/// `path: left -> right`.
+///
+/// Like `assert_eq!`, accepts an optional format string and arguments to add to the message.
+///
+/// [`dynamic_value::diff()`]: crate::dynamic_value::diff
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_capnp_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::dynamic_value::assert_eq_impl($left, $right, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::dynamic_value::assert_eq_impl(
+            $left,
+            $right,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
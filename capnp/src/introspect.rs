@@ -63,7 +63,7 @@ impl Type {
                 BaseType::Enum(re) => TypeVariant::Enum(re),
                 BaseType::Struct(rs) => TypeVariant::Struct(rs),
                 BaseType::AnyPointer => TypeVariant::AnyPointer,
-                BaseType::Capability => TypeVariant::Capability,
+                BaseType::Capability(ri) => TypeVariant::Capability(ri),
             }
         }
     }
@@ -81,9 +81,10 @@ impl Type {
                 BaseType::Int16 | BaseType::UInt16 | BaseType::Enum(_) => ElementSize::TwoBytes,
                 BaseType::Int32 | BaseType::UInt32 | BaseType::Float32 => ElementSize::FourBytes,
                 BaseType::Int64 | BaseType::UInt64 | BaseType::Float64 => ElementSize::EightBytes,
-                BaseType::Text | BaseType::Data | BaseType::AnyPointer | BaseType::Capability => {
-                    ElementSize::Pointer
-                }
+                BaseType::Text
+                | BaseType::Data
+                | BaseType::AnyPointer
+                | BaseType::Capability(_) => ElementSize::Pointer,
                 BaseType::Struct(_) => ElementSize::InlineComposite,
             }
         }
@@ -100,7 +101,7 @@ impl Type {
                     | BaseType::Data
                     | BaseType::AnyPointer
                     | BaseType::Struct(_)
-                    | BaseType::Capability
+                    | BaseType::Capability(_)
             )
         }
     }
@@ -126,7 +127,7 @@ pub enum TypeVariant {
     Data,
     Struct(RawBrandedStructSchema),
     AnyPointer,
-    Capability,
+    Capability(RawInterfaceSchema),
     Enum(RawEnumSchema),
     List(Type),
 }
@@ -150,7 +151,7 @@ impl From<TypeVariant> for Type {
             TypeVariant::Data => Type::new_base(BaseType::Data),
             TypeVariant::Struct(rbs) => Type::new_base(BaseType::Struct(rbs)),
             TypeVariant::AnyPointer => Type::new_base(BaseType::AnyPointer),
-            TypeVariant::Capability => Type::new_base(BaseType::Capability),
+            TypeVariant::Capability(ri) => Type::new_base(BaseType::Capability(ri)),
             TypeVariant::Enum(es) => Type::new_base(BaseType::Enum(es)),
             TypeVariant::List(list) => Type::list_of(list),
         }
@@ -176,7 +177,7 @@ enum BaseType {
     Data,
     Struct(RawBrandedStructSchema),
     AnyPointer,
-    Capability,
+    Capability(RawInterfaceSchema),
     Enum(RawEnumSchema),
 }
 
@@ -225,19 +226,48 @@ pub struct RawBrandedStructSchema {
     /// The unbranded base schema.
     pub generic: &'static RawStructSchema,
 
-    /// Map from field index (not ordinal) to Type.
-    pub field_types: fn(u16) -> Type,
+    /// Resolves the types of the struct's fields and annotations.
+    pub(crate) types: StructTypes,
+}
 
-    /// Map from (maybe field index, annotation index) to the Type
-    /// of the value held by that annotation.
-    pub annotation_types: fn(Option<u16>, u32) -> Type,
+impl RawBrandedStructSchema {
+    /// Constructs a branded schema from the lookup functions emitted by capnpc.
+    ///
+    /// `field_types` maps a field index (not ordinal) to its Type, and `annotation_types`
+    /// maps (maybe field index, annotation index) to the Type of the value held by that annotation.
+    pub const fn new(
+        generic: &'static RawStructSchema,
+        field_types: fn(u16) -> Type,
+        annotation_types: fn(Option<u16>, u32) -> Type,
+    ) -> Self {
+        Self {
+            generic,
+            types: StructTypes::Generated {
+                field_types,
+                annotation_types,
+            },
+        }
+    }
 }
 
 impl core::cmp::PartialEq for RawBrandedStructSchema {
     fn eq(&self, other: &Self) -> bool {
-        core::ptr::eq(self.generic, other.generic) && self.field_types == other.field_types
-        // don't need to compare annotation_types.
-        // that field is equal iff field_types is.
+        core::ptr::eq(self.generic, other.generic)
+            && match (self.types, other.types) {
+                (
+                    StructTypes::Generated { field_types, .. },
+                    StructTypes::Generated {
+                        field_types: other_field_types,
+                        ..
+                    },
+                ) => field_types == other_field_types,
+                // don't need to compare annotation_types.
+                // that field is equal iff field_types is.
+                #[cfg(feature = "alloc")]
+                (StructTypes::Loaded(_), StructTypes::Loaded(_)) => true,
+                #[cfg(feature = "alloc")]
+                _ => false,
+            }
     }
 }
 
@@ -245,14 +275,61 @@ impl core::cmp::Eq for RawBrandedStructSchema {}
 
 impl core::fmt::Debug for RawBrandedStructSchema {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(
-            f,
-            "RawBrandedStructSchema({:?}, {:?})",
-            self.generic as *const _, self.field_types as *const fn(u16) -> Type
-        )
+        match self.types {
+            StructTypes::Generated { field_types, .. } => write!(
+                f,
+                "RawBrandedStructSchema({:?}, {:?})",
+                self.generic as *const _, field_types as *const fn(u16) -> Type
+            ),
+            #[cfg(feature = "alloc")]
+            StructTypes::Loaded(_) => {
+                write!(
+                    f,
+                    "RawBrandedStructSchema({:?}, loaded)",
+                    self.generic as *const _
+                )
+            }
+        }
     }
 }
 
+/// Where the types referenced by a struct node come from.
+#[derive(Copy, Clone)]
+pub(crate) enum StructTypes {
+    /// Lookup functions emitted by capnpc.
+    Generated {
+        field_types: fn(u16) -> Type,
+        annotation_types: fn(Option<u16>, u32) -> Type,
+    },
+
+    /// Nodes loaded at run time by a `schema::SchemaLoader`. Generic parameters are unbound.
+    #[cfg(feature = "alloc")]
+    Loaded(&'static crate::schema::NodeTable),
+}
+
+impl StructTypes {
+    pub(crate) fn annotation_types(self) -> AnnotationTypes {
+        match self {
+            StructTypes::Generated {
+                annotation_types, ..
+            } => AnnotationTypes::Generated(annotation_types),
+            #[cfg(feature = "alloc")]
+            StructTypes::Loaded(table) => AnnotationTypes::Loaded(table),
+        }
+    }
+}
+
+/// Where the types of the values held by a node's annotations come from.
+#[derive(Copy, Clone)]
+pub(crate) enum AnnotationTypes {
+    /// Map from (maybe child index, annotation index) to Type, emitted by capnpc.
+    Generated(fn(Option<u16>, u32) -> Type),
+
+    /// Nodes loaded at run time by a `schema::SchemaLoader`.
+    #[cfg(feature = "alloc")]
+    Loaded(&'static crate::schema::NodeTable),
+}
+
 /// Type information that gets included in the generated code for every
 /// user-defined Cap'n Proto enum.
 /// To use one of this, you will usually want to convert it to a `schema::EnumSchema`,
@@ -262,9 +339,24 @@ pub struct RawEnumSchema {
     /// The Node (as defined in schema.capnp), as a single segment message.
     pub encoded_node: &'static [crate::Word],
 
-    /// Map from (maybe enumerant index, annotation index) to the Type
+    /// Resolves the types of the values held by the enum's annotations.
+    pub(crate) annotation_types: AnnotationTypes,
+}
+
+impl RawEnumSchema {
+    /// Constructs an enum schema from the data emitted by capnpc.
+    ///
+    /// `annotation_types` maps (maybe enumerant index, annotation index) to the Type
     /// of the value held by that annotation.
-    pub annotation_types: fn(Option<u16>, u32) -> Type,
+    pub const fn new(
+        encoded_node: &'static [crate::Word],
+        annotation_types: fn(Option<u16>, u32) -> Type,
+    ) -> Self {
+        Self {
+            encoded_node,
+            annotation_types: AnnotationTypes::Generated(annotation_types),
+        }
+    }
 }
 
 impl core::cmp::PartialEq for RawEnumSchema {
@@ -280,3 +372,94 @@ impl core::fmt::Debug for RawEnumSchema {
         write!(f, "RawEnumSchema({:?})", self.encoded_node as *const _)
     }
 }
+
+/// Type information that gets included in the generated code for every
+/// user-defined Cap'n Proto interface.
+/// To use one of this, you will usually want to convert it to a `schema::InterfaceSchema`,
+/// which can be done via `into()`.
+#[derive(Clone, Copy)]
+pub struct RawInterfaceSchema {
+    /// The Node (as defined in schema.capnp), as a single segment message.
+    pub encoded_node: &'static [crate::Word],
+
+    /// Resolves the types of the interface's methods, superclasses, and annotations.
+    pub(crate) types: InterfaceTypes,
+}
+
+impl RawInterfaceSchema {
+    /// Constructs an interface schema from the data emitted by capnpc.
+    ///
+    /// `method_types` maps a method ordinal to the Types of its params and results structs,
+    /// `superclass_types` maps a superclass index to its Type, and `annotation_types`
+    /// maps (maybe method ordinal, annotation index) to the Type of the value held by that annotation.
+    pub const fn new(
+        encoded_node: &'static [crate::Word],
+        method_types: fn(u16) -> (Type, Type),
+        superclass_types: fn(u16) -> Type,
+        annotation_types: fn(Option<u16>, u32) -> Type,
+    ) -> Self {
+        Self {
+            encoded_node,
+            types: InterfaceTypes::Generated {
+                method_types,
+                superclass_types,
+                annotation_types,
+            },
+        }
+    }
+}
+
+impl core::cmp::PartialEq for RawInterfaceSchema {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.encoded_node, other.encoded_node)
+            && match (self.types, other.types) {
+                (
+                    InterfaceTypes::Generated { method_types, .. },
+                    InterfaceTypes::Generated {
+                        method_types: other_method_types,
+                        ..
+                    },
+                ) => method_types as usize == other_method_types as usize,
+                // as for structs, the other lookup functions are equal iff method_types is.
+                #[cfg(feature = "alloc")]
+                (InterfaceTypes::Loaded(_), InterfaceTypes::Loaded(_)) => true,
+                #[cfg(feature = "alloc")]
+                _ => false,
+            }
+    }
+}
+
+impl core::cmp::Eq for RawInterfaceSchema {}
+
+impl core::fmt::Debug for RawInterfaceSchema {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        write!(f, "RawInterfaceSchema({:?})", self.encoded_node as *const _)
+    }
+}
+
+/// Where the types referenced by an interface node come from.
+#[derive(Copy, Clone)]
+pub(crate) enum InterfaceTypes {
+    /// Lookup functions emitted by capnpc.
+    Generated {
+        method_types: fn(u16) -> (Type, Type),
+        superclass_types: fn(u16) -> Type,
+        annotation_types: fn(Option<u16>, u32) -> Type,
+    },
+
+    /// Nodes loaded at run time by a `schema::SchemaLoader`. Generic parameters are unbound.
+    #[cfg(feature = "alloc")]
+    Loaded(&'static crate::schema::NodeTable),
+}
+
+impl InterfaceTypes {
+    pub(crate) fn annotation_types(self) -> AnnotationTypes {
+        match self {
+            InterfaceTypes::Generated {
+                annotation_types, ..
+            } => AnnotationTypes::Generated(annotation_types),
+            #[cfg(feature = "alloc")]
+            InterfaceTypes::Loaded(table) => AnnotationTypes::Loaded(table),
+        }
+    }
+}
@@ -0,0 +1,1095 @@
+//! Conversion between dynamically-typed values and JSON.
+//!
+//! The encoding follows the conventions of the C++ implementation's `capnp/compat/json.h`:
+//!
+//! * Void is `null`.
+//! * Int64 and UInt64 are strings, so that they survive parsers that read every number as a
+//!   double. Other integers are numbers.
+//! * Infinite and NaN floats are the strings `"Infinity"`, `"-Infinity"` and `"NaN"`.
+//! * Enums are their enumerant names.
+//! * Data is an array of byte values, or a string if the field is annotated with `$Json.base64`
+//!   or `$Json.hex`.
+//! * Structs are objects. The active member of a union appears as the only key taken from that
+//!   union, so a named union is an object with a single key.
+//!
+//! The annotations from [json.capnp](crate::json_capnp) are honored: `$Json.name` renames fields
+//! and enumerants, `$Json.flatten` merges a struct or group into its parent object, and
+//! `$Json.discriminator` stores the active union member in a separate tag field. To annotate
+//! a schema, import `/capnp/compat/json.capnp` and tell `capnpc` that this crate provides it:
+//!
+//! ```ignore
+//! // build.rs
+//! capnpc::CompilerCommand::new()
+//!     .crate_provides("capnp", [0x8ef99297a43a5e34])
+//!     .file("my_schema.capnp")
+//!     .run()
+//!     .unwrap();
+//! ```
+//!
+//! A field of type `Json.Value` holds arbitrary JSON and is written out as the value it
+//! describes. `AnyPointer` and capability fields have no JSON representation and cannot be
+//! encoded.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::introspect::{Type, TypeVariant};
+use crate::json_capnp::{discriminator_options, flatten_options, value as json_value};
+use crate::schema::{Enumerant, Field, StructSchema};
+use crate::schema_capnp::{annotation, field, value};
+use crate::text_format::{error_at, type_name};
+use crate::traits::HasTypeId;
+use crate::{dynamic_list, dynamic_struct, dynamic_value, struct_list};
+use crate::{Error, ErrorKind, Result};
+
+const NAME_ANNOTATION_ID: u64 = 0xfa5b1fd61c2e7c3d;
+const FLATTEN_ANNOTATION_ID: u64 = 0x82d3e852af0336bf;
+const DISCRIMINATOR_ANNOTATION_ID: u64 = 0xcfa794e8d19a0162;
+const BASE64_ANNOTATION_ID: u64 = 0xd7d879450a253e4b;
+const HEX_ANNOTATION_ID: u64 = 0xf061e22f0ae5c7b5;
+
+/// Encodes `value` as JSON using the default options.
+pub fn encode<'a>(value: impl Into<dynamic_value::Reader<'a>>) -> Result<String> {
+    Codec::new().encode(value)
+}
+
+/// Decodes a JSON object into `builder` using the default options.
+pub fn decode(input: &str, builder: dynamic_struct::Builder) -> Result<()> {
+    Codec::new().decode(input, builder)
+}
+
+/// Options controlling how values are converted to and from JSON.
+#[derive(Clone, Copy, Debug)]
+pub struct Codec {
+    pretty_print: bool,
+    reject_unknown_fields: bool,
+    nesting_limit: usize,
+}
+
+impl Default for Codec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Codec {
+    pub fn new() -> Self {
+        Self {
+            pretty_print: false,
+            reject_unknown_fields: false,
+            nesting_limit: 64,
+        }
+    }
+
+    /// If true, the encoder emits one value per line with two-space indentation. Defaults to
+    /// false, which produces compact output with no whitespace.
+    pub fn pretty_print(&mut self, value: bool) -> &mut Self {
+        self.pretty_print = value;
+        self
+    }
+
+    /// If true, decoding fails on object keys that do not correspond to any field. Defaults to
+    /// false, in which case such keys are ignored.
+    pub fn reject_unknown_fields(&mut self, value: bool) -> &mut Self {
+        self.reject_unknown_fields = value;
+        self
+    }
+
+    /// Limits how deeply arrays and objects may be nested in decoded input. Defaults to 64.
+    pub fn nesting_limit(&mut self, value: usize) -> &mut Self {
+        self.nesting_limit = value;
+        self
+    }
+
+    /// Encodes `value` as JSON.
+    pub fn encode<'a>(&self, value: impl Into<dynamic_value::Reader<'a>>) -> Result<String> {
+        let json = self.encode_value(value.into(), &Annotations::default())?;
+        let mut result = String::new();
+        write_value(&json, self.pretty_print.then_some(0), &mut result);
+        Ok(result)
+    }
+
+    /// Decodes `input`, which must be a JSON object, into `builder`. Fields that do not appear
+    /// in the input are left untouched.
+    pub fn decode(&self, input: &str, builder: dynamic_struct::Builder) -> Result<()> {
+        let mut parser = Parser { input, pos: 0 };
+        let json = parser.parse_value(0, self.nesting_limit)?;
+        parser.skip_whitespace();
+        if parser.pos < input.len() {
+            return Err(parser.error("expected end of input"));
+        }
+        if is_json_value(builder.get_schema()) {
+            return self.decode_json_value(json, builder.builder.into());
+        }
+        let Value::Object(mut fields) = json else {
+            let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+            write!(error, "expected a JSON object");
+            return Err(error);
+        };
+        self.scatter(&mut fields, builder, None, None, "")?;
+        self.check_unknown_fields(&fields)
+    }
+
+    fn encode_value(
+        &self,
+        value: dynamic_value::Reader,
+        annotations: &Annotations,
+    ) -> Result<Value> {
+        Ok(match value {
+            dynamic_value::Reader::Void => Value::Null,
+            dynamic_value::Reader::Bool(b) => Value::Bool(b),
+            dynamic_value::Reader::Int8(x) => Value::Number(x.to_string()),
+            dynamic_value::Reader::Int16(x) => Value::Number(x.to_string()),
+            dynamic_value::Reader::Int32(x) => Value::Number(x.to_string()),
+            dynamic_value::Reader::Int64(x) => Value::String(x.to_string()),
+            dynamic_value::Reader::UInt8(x) => Value::Number(x.to_string()),
+            dynamic_value::Reader::UInt16(x) => Value::Number(x.to_string()),
+            dynamic_value::Reader::UInt32(x) => Value::Number(x.to_string()),
+            dynamic_value::Reader::UInt64(x) => Value::String(x.to_string()),
+            dynamic_value::Reader::Float32(x) if x.is_finite() => Value::Number(x.to_string()),
+            dynamic_value::Reader::Float32(x) => encode_non_finite(x.into()),
+            dynamic_value::Reader::Float64(x) if x.is_finite() => Value::Number(x.to_string()),
+            dynamic_value::Reader::Float64(x) => encode_non_finite(x),
+            dynamic_value::Reader::Enum(e) => match e.get_enumerant()? {
+                Some(enumerant) => Value::String(enumerant_name(enumerant)?.into()),
+                None => Value::Number(e.get_value().to_string()),
+            },
+            dynamic_value::Reader::Text(t) => Value::String(t.to_str()?.into()),
+            dynamic_value::Reader::Data(d) => match annotations.data_encoding {
+                DataEncoding::Array => {
+                    Value::Array(d.iter().map(|b| Value::Number(b.to_string())).collect())
+                }
+                DataEncoding::Base64 => Value::String(encode_base64(d)),
+                DataEncoding::Hex => Value::String(encode_hex(d)),
+            },
+            dynamic_value::Reader::List(list) => {
+                let mut items = Vec::with_capacity(list.len() as usize);
+                for item in list.iter() {
+                    items.push(self.encode_value(item?, &Annotations::default())?);
+                }
+                Value::Array(items)
+            }
+            dynamic_value::Reader::Struct(st) if is_json_value(st.get_schema()) => {
+                self.encode_json_value(st.reader.into())?
+            }
+            dynamic_value::Reader::Struct(st) => {
+                let mut fields = Vec::new();
+                self.gather(st, annotations.discriminator, None, "", &mut fields)?;
+                Value::Object(fields)
+            }
+            dynamic_value::Reader::AnyPointer(_) => {
+                return Err(Error::unimplemented(
+                    "AnyPointer cannot be encoded as JSON".into(),
+                ))
+            }
+            dynamic_value::Reader::Capability(_) => {
+                return Err(Error::unimplemented(
+                    "capabilities cannot be encoded as JSON".into(),
+                ))
+            }
+        })
+    }
+
+    /// Appends the JSON object entries for the fields of `reader` to `out`. Flattened fields
+    /// recurse with a longer `prefix`, so that their entries end up in the same object.
+    fn gather(
+        &self,
+        reader: dynamic_struct::Reader,
+        discriminator: Option<Discriminator>,
+        union_decl_name: Option<&str>,
+        prefix: &str,
+        out: &mut Vec<(String, Value)>,
+    ) -> Result<()> {
+        let schema = reader.get_schema();
+        let discriminator = match discriminator {
+            Some(d) => Some(d),
+            None => struct_discriminator(schema)?,
+        };
+        for field in schema.get_non_union_fields()? {
+            if reader.has(field)? {
+                let annotations = Annotations::read(field.get_proto().get_annotations()?)?;
+                let key = json_name(field, &annotations)?;
+                self.gather_field(reader, field, &annotations, key, prefix, out)?;
+            }
+        }
+
+        if let Some(field) = reader.which()? {
+            let annotations = Annotations::read(field.get_proto().get_annotations()?)?;
+            let name = json_name(field, &annotations)?;
+            let tag_name = discriminator.and_then(|d| d.name.or(union_decl_name));
+            if let Some(tag) = tag_name {
+                out.push((format!("{prefix}{tag}"), Value::String(name.into())));
+            }
+            let is_void = matches!(field.get_type().which(), TypeVariant::Void);
+            if tag_name.is_some() && annotations.flatten.is_none() && is_void {
+                // The tag already says everything there is to say.
+            } else {
+                let key = match (tag_name, discriminator.and_then(|d| d.value_name)) {
+                    (Some(_), Some(value_name)) => value_name,
+                    _ => name,
+                };
+                self.gather_field(reader, field, &annotations, key, prefix, out)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn gather_field(
+        &self,
+        reader: dynamic_struct::Reader,
+        field: Field,
+        annotations: &Annotations,
+        key: &str,
+        prefix: &str,
+        out: &mut Vec<(String, Value)>,
+    ) -> Result<()> {
+        let value = reader.get(field)?;
+        match annotations.flatten {
+            Some(more_prefix) => {
+                let dynamic_value::Reader::Struct(inner) = value else {
+                    return Err(only_structs_can_be_flattened(key));
+                };
+                self.gather(
+                    inner,
+                    annotations.discriminator,
+                    group_name(field)?,
+                    &format!("{prefix}{more_prefix}"),
+                    out,
+                )
+            }
+            None => {
+                out.push((
+                    format!("{prefix}{key}"),
+                    self.encode_value(value, annotations)?,
+                ));
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the entries for the fields of `builder` from `fields` and decodes them. This is
+    /// the inverse of `gather()`; whatever is left in `fields` afterwards is unknown.
+    fn scatter(
+        &self,
+        fields: &mut Vec<(String, Value)>,
+        mut builder: dynamic_struct::Builder,
+        discriminator: Option<Discriminator>,
+        union_decl_name: Option<&str>,
+        prefix: &str,
+    ) -> Result<()> {
+        let schema = builder.get_schema();
+        let discriminator = match discriminator {
+            Some(d) => Some(d),
+            None => struct_discriminator(schema)?,
+        };
+        for field in schema.get_non_union_fields()? {
+            let annotations = Annotations::read(field.get_proto().get_annotations()?)?;
+            let name = json_name(field, &annotations)?;
+            if let Some(more_prefix) = annotations.flatten {
+                let prefix = format!("{prefix}{more_prefix}");
+                if !fields.iter().any(|(key, _)| key.starts_with(&prefix)) {
+                    continue;
+                }
+                let dynamic_value::Builder::Struct(inner) = builder.reborrow().get(field)? else {
+                    return Err(only_structs_can_be_flattened(name));
+                };
+                self.scatter(
+                    fields,
+                    inner,
+                    annotations.discriminator,
+                    group_name(field)?,
+                    &prefix,
+                )?;
+            } else if let Some(value) = take_field(fields, prefix, name) {
+                self.decode_field(builder.reborrow(), field, &annotations, value)
+                    .map_err(|e| in_field(e, name))?;
+            }
+        }
+
+        let union_fields = schema.get_union_fields()?;
+        let tag_name = discriminator.and_then(|d| d.name.or(union_decl_name));
+        if let (Some(discriminator), Some(tag)) = (discriminator, tag_name) {
+            let Some(tag_value) = take_field(fields, prefix, tag) else {
+                return Ok(());
+            };
+            let Value::String(member_name) = tag_value else {
+                return Err(in_field(mismatch("a string", &tag_value), tag));
+            };
+            let mut member = None;
+            for field in union_fields {
+                let annotations = Annotations::read(field.get_proto().get_annotations()?)?;
+                if json_name(field, &annotations)? == member_name {
+                    member = Some((field, annotations));
+                    break;
+                }
+            }
+            let Some((field, annotations)) = member else {
+                if !self.reject_unknown_fields {
+                    return Ok(());
+                }
+                let mut error = Error::from_kind(ErrorKind::FieldNotFound);
+                write!(error, "{member_name}, in field `{tag}`");
+                return Err(error);
+            };
+
+            // Clearing the member is enough to make it the active one. A member that is already
+            // active is kept, so that it is merged into like any other field.
+            if !builder.has(field)? {
+                builder.clear(field)?;
+            }
+            if let Some(more_prefix) = annotations.flatten {
+                let dynamic_value::Builder::Struct(inner) = builder.get(field)? else {
+                    return Err(only_structs_can_be_flattened(&member_name));
+                };
+                self.scatter(
+                    fields,
+                    inner,
+                    annotations.discriminator,
+                    group_name(field)?,
+                    &format!("{prefix}{more_prefix}"),
+                )?;
+            } else {
+                let key = discriminator.value_name.unwrap_or(&member_name);
+                if let Some(value) = take_field(fields, prefix, key) {
+                    self.decode_field(builder, field, &annotations, value)
+                        .map_err(|e| in_field(e, key))?;
+                }
+            }
+        } else {
+            for field in union_fields {
+                let annotations = Annotations::read(field.get_proto().get_annotations()?)?;
+                let name = json_name(field, &annotations)?;
+                if let Some(value) = take_field(fields, prefix, name) {
+                    self.decode_field(builder.reborrow(), field, &annotations, value)
+                        .map_err(|e| in_field(e, name))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_field(
+        &self,
+        mut builder: dynamic_struct::Builder,
+        field: Field,
+        annotations: &Annotations,
+        value: Value,
+    ) -> Result<()> {
+        let ty = field.get_type();
+        match (ty.which(), value) {
+            (TypeVariant::Struct(schema), value) if is_json_value(schema.into()) => {
+                let inner: dynamic_struct::Builder = builder.init(field)?.downcast();
+                self.decode_json_value(value, inner.builder.into())
+            }
+            (
+                TypeVariant::Text
+                | TypeVariant::Data
+                | TypeVariant::List(_)
+                | TypeVariant::Struct(_)
+                | TypeVariant::AnyPointer
+                | TypeVariant::Capability(_),
+                Value::Null,
+            ) => builder.clear(field),
+            (TypeVariant::Struct(_), Value::Object(mut fields)) => {
+                // Merge into a struct that is already set, so that its fields that do not
+                // appear in the input are left untouched too.
+                let inner = if builder.has(field)? {
+                    builder.get(field)?
+                } else {
+                    builder.init(field)?
+                };
+                let inner = inner.downcast();
+                self.scatter(&mut fields, inner, annotations.discriminator, None, "")?;
+                self.check_unknown_fields(&fields)
+            }
+            (TypeVariant::List(_), Value::Array(items)) => {
+                let list = builder.initn(field, items.len() as u32)?.downcast();
+                self.decode_list(list, items)
+            }
+            (TypeVariant::Data, value) => {
+                let bytes = decode_data(&value, annotations.data_encoding)?;
+                builder.set(field, dynamic_value::Reader::Data(&bytes))
+            }
+            (_, value) => builder.set(field, decode_scalar(ty, &value)?),
+        }
+    }
+
+    fn decode_list(&self, mut list: dynamic_list::Builder, items: Vec<Value>) -> Result<()> {
+        let element_type = list.element_type();
+        for (index, item) in items.into_iter().enumerate() {
+            let index = index as u32;
+            match (element_type.which(), item) {
+                (TypeVariant::Struct(schema), item) if is_json_value(schema.into()) => {
+                    let inner: dynamic_struct::Builder = list.reborrow().get(index)?.downcast();
+                    self.decode_json_value(item, inner.builder.into())?;
+                }
+                (TypeVariant::Struct(_), Value::Object(mut fields)) => {
+                    let inner = list.reborrow().get(index)?.downcast();
+                    self.scatter(&mut fields, inner, None, None, "")?;
+                    self.check_unknown_fields(&fields)?;
+                }
+                (TypeVariant::List(_), Value::Array(inner)) => {
+                    let inner_list = list.reborrow().init(index, inner.len() as u32)?.downcast();
+                    self.decode_list(inner_list, inner)?;
+                }
+                (TypeVariant::Data, item) => {
+                    let bytes = decode_data(&item, DataEncoding::Array)?;
+                    list.set(index, dynamic_value::Reader::Data(&bytes))?;
+                }
+                (_, item) => list.set(index, decode_scalar(element_type, &item)?)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts a `Json.Value` into the JSON that it describes.
+    fn encode_json_value(&self, reader: json_value::Reader) -> Result<Value> {
+        Ok(match reader.which()? {
+            json_value::Null(()) => Value::Null,
+            json_value::Boolean(b) => Value::Bool(b),
+            json_value::Number(x) if x.is_finite() => Value::Number(x.to_string()),
+            json_value::Number(x) => encode_non_finite(x),
+            json_value::String(s) => Value::String(s?.to_str()?.into()),
+            json_value::Array(items) => {
+                let items = items?;
+                let mut result = Vec::with_capacity(items.len() as usize);
+                for item in items {
+                    result.push(self.encode_json_value(item)?);
+                }
+                Value::Array(result)
+            }
+            json_value::Object(fields) => {
+                let fields = fields?;
+                let mut result = Vec::with_capacity(fields.len() as usize);
+                for field in fields {
+                    result.push((
+                        field.get_name()?.to_str()?.into(),
+                        self.encode_json_value(field.get_value()?)?,
+                    ));
+                }
+                Value::Object(result)
+            }
+            json_value::Call(_) => {
+                return Err(Error::unimplemented(
+                    "Json.Value.call has no standard JSON representation".into(),
+                ))
+            }
+            json_value::Raw(raw) => {
+                // Parse the text rather than trusting it, so that the output stays valid JSON.
+                let raw = raw?.to_str()?;
+                let mut parser = Parser { input: raw, pos: 0 };
+                let value = parser.parse_value(0, self.nesting_limit)?;
+                parser.skip_whitespace();
+                if parser.pos < raw.len() {
+                    return Err(parser.error("expected end of input"));
+                }
+                value
+            }
+        })
+    }
+
+    /// Stores `value` in a `Json.Value`.
+    fn decode_json_value(&self, value: Value, mut builder: json_value::Builder) -> Result<()> {
+        match value {
+            Value::Null => builder.set_null(()),
+            Value::Bool(b) => builder.set_boolean(b),
+            Value::Number(text) => match text.parse() {
+                Ok(x) => builder.set_number(x),
+                Err(_) => return Err(mismatch("a number", &Value::Number(text))),
+            },
+            Value::String(s) => builder.set_string(s.as_str().into()),
+            Value::Array(items) => {
+                let mut list = builder.init_array(items.len() as u32);
+                for (idx, item) in items.into_iter().enumerate() {
+                    self.decode_json_value(item, list.reborrow().get(idx as u32))?;
+                }
+            }
+            Value::Object(fields) => {
+                let mut list = builder.init_object(fields.len() as u32);
+                for (idx, (name, value)) in fields.into_iter().enumerate() {
+                    let mut field = list.reborrow().get(idx as u32);
+                    field.set_name(name.as_str().into());
+                    self.decode_json_value(value, field.init_value())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_unknown_fields(&self, fields: &[(String, Value)]) -> Result<()> {
+        match fields.first() {
+            Some((key, _)) if self.reject_unknown_fields => {
+                let mut error = Error::from_kind(ErrorKind::FieldNotFound);
+                write!(error, "{key}");
+                Err(error)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// How a `Data` field is represented.
+#[derive(Clone, Copy, Default)]
+enum DataEncoding {
+    #[default]
+    Array,
+    Base64,
+    Hex,
+}
+
+/// The contents of a `$Json.discriminator` annotation.
+#[derive(Clone, Copy)]
+struct Discriminator {
+    name: Option<&'static str>,
+    value_name: Option<&'static str>,
+}
+
+/// The JSON annotations found on a field.
+#[derive(Clone, Copy, Default)]
+struct Annotations {
+    name: Option<&'static str>,
+    flatten: Option<&'static str>,
+    discriminator: Option<Discriminator>,
+    data_encoding: DataEncoding,
+}
+
+impl Annotations {
+    fn read(annotations: struct_list::Reader<'static, annotation::Owned>) -> Result<Self> {
+        let mut result = Self::default();
+        for annotation in annotations {
+            match (annotation.get_id(), annotation.get_value()?.which()?) {
+                (NAME_ANNOTATION_ID, value::Text(name)) => result.name = Some(name?.to_str()?),
+                (FLATTEN_ANNOTATION_ID, value::Struct(options)) => {
+                    let options = options.get_as::<flatten_options::Reader>()?;
+                    result.flatten = Some(options.get_prefix()?.to_str()?);
+                }
+                (DISCRIMINATOR_ANNOTATION_ID, value::Struct(options)) => {
+                    result.discriminator = Some(Discriminator::read(options.get_as()?)?);
+                }
+                (BASE64_ANNOTATION_ID, _) => result.data_encoding = DataEncoding::Base64,
+                (HEX_ANNOTATION_ID, _) => result.data_encoding = DataEncoding::Hex,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Discriminator {
+    fn read(options: discriminator_options::Reader<'static>) -> Result<Self> {
+        Ok(Self {
+            name: if options.has_name() {
+                Some(options.get_name()?.to_str()?)
+            } else {
+                None
+            },
+            value_name: if options.has_value_name() {
+                Some(options.get_value_name()?.to_str()?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+fn is_json_value(schema: StructSchema) -> bool {
+    schema.get_proto().get_id() == <json_value::Reader as HasTypeId>::TYPE_ID
+}
+
+/// Gets the discriminator that a struct's own annotations place on its unnamed union.
+fn struct_discriminator(schema: StructSchema) -> Result<Option<Discriminator>> {
+    Ok(Annotations::read(schema.get_proto().get_annotations()?)?.discriminator)
+}
+
+fn json_name(field: Field, annotations: &Annotations) -> Result<&'static str> {
+    match annotations.name {
+        Some(name) => Ok(name),
+        None => Ok(field.get_proto().get_name()?.to_str()?),
+    }
+}
+
+/// For a group, gets its declared name, which a `$Json.discriminator` without an explicit
+/// name uses as the tag name once the group is flattened.
+fn group_name(field: Field) -> Result<Option<&'static str>> {
+    let proto = field.get_proto();
+    match proto.which()? {
+        field::Group(_) => Ok(Some(proto.get_name()?.to_str()?)),
+        field::Slot(_) => Ok(None),
+    }
+}
+
+fn enumerant_name(enumerant: Enumerant) -> Result<&'static str> {
+    let proto = enumerant.get_proto();
+    match Annotations::read(proto.get_annotations()?)?.name {
+        Some(name) => Ok(name),
+        None => Ok(proto.get_name()?.to_str()?),
+    }
+}
+
+fn take_field(fields: &mut Vec<(String, Value)>, prefix: &str, name: &str) -> Option<Value> {
+    let idx = fields.iter().position(|(key, _)| {
+        key.len() == prefix.len() + name.len() && key.starts_with(prefix) && key.ends_with(name)
+    })?;
+    Some(fields.remove(idx).1)
+}
+
+fn in_field(mut error: Error, name: &str) -> Error {
+    if error.extra.is_empty() {
+        write!(error, "in field `{name}`");
+    } else {
+        write!(error, ", in field `{name}`");
+    }
+    error
+}
+
+fn only_structs_can_be_flattened(name: &str) -> Error {
+    let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+    write!(
+        error,
+        "only struct and group fields can be flattened, in field `{name}`"
+    );
+    error
+}
+
+fn mismatch(expected: &str, found: &Value) -> Error {
+    let found = match found {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    };
+    let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+    write!(error, "expected {expected}, found {found}");
+    error
+}
+
+fn encode_non_finite(x: f64) -> Value {
+    Value::String(
+        if x.is_nan() {
+            "NaN"
+        } else if x > 0.0 {
+            "Infinity"
+        } else {
+            "-Infinity"
+        }
+        .into(),
+    )
+}
+
+fn decode_integer<T: TryFrom<i128>>(value: &Value) -> Option<T> {
+    let (Value::Number(text) | Value::String(text)) = value else {
+        return None;
+    };
+    let x = match text.parse::<i128>() {
+        Ok(x) => x,
+        Err(_) => {
+            // Tolerate numbers like `1e3` and `5.0` that are integral nonetheless.
+            let f = text.parse::<f64>().ok()?;
+            let x = f as i128;
+            if x as f64 != f {
+                return None;
+            }
+            x
+        }
+    };
+    T::try_from(x).ok()
+}
+
+fn decode_float<T: core::str::FromStr>(value: &Value) -> Option<T> {
+    match value {
+        // Rust's float parser accepts "NaN", "Infinity" and "-Infinity".
+        Value::Number(text) | Value::String(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+fn decode_scalar<'v>(ty: Type, value: &'v Value) -> Result<dynamic_value::Reader<'v>> {
+    let result = match (ty.which(), value) {
+        (TypeVariant::Void, Value::Null) => Some(dynamic_value::Reader::Void),
+        (TypeVariant::Bool, Value::Bool(b)) => Some((*b).into()),
+        (TypeVariant::Int8, _) => decode_integer::<i8>(value).map(Into::into),
+        (TypeVariant::Int16, _) => decode_integer::<i16>(value).map(Into::into),
+        (TypeVariant::Int32, _) => decode_integer::<i32>(value).map(Into::into),
+        (TypeVariant::Int64, _) => decode_integer::<i64>(value).map(Into::into),
+        (TypeVariant::UInt8, _) => decode_integer::<u8>(value).map(Into::into),
+        (TypeVariant::UInt16, _) => decode_integer::<u16>(value).map(Into::into),
+        (TypeVariant::UInt32, _) => decode_integer::<u32>(value).map(Into::into),
+        (TypeVariant::UInt64, _) => decode_integer::<u64>(value).map(Into::into),
+        (TypeVariant::Float32, _) => decode_float::<f32>(value).map(Into::into),
+        (TypeVariant::Float64, _) => decode_float::<f64>(value).map(Into::into),
+        (TypeVariant::Enum(raw), Value::String(name)) => {
+            let schema: crate::schema::EnumSchema = raw.into();
+            let mut found = None;
+            for enumerant in schema.get_enumerants()? {
+                if enumerant_name(enumerant)? == name {
+                    found = Some(enumerant.get_ordinal());
+                    break;
+                }
+            }
+            found.map(|ordinal| dynamic_value::Enum::new(ordinal, schema).into())
+        }
+        (TypeVariant::Enum(raw), Value::Number(_)) => decode_integer::<u16>(value)
+            .map(|ordinal| dynamic_value::Enum::new(ordinal, raw.into()).into()),
+        (TypeVariant::Text, Value::String(s)) => Some(s.as_str().into()),
+        (TypeVariant::AnyPointer | TypeVariant::Capability(_), _) => {
+            let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+            write!(error, "{} cannot be decoded from JSON", type_name(ty));
+            return Err(error);
+        }
+        _ => None,
+    };
+    result.ok_or_else(|| mismatch(type_name(ty), value))
+}
+
+fn decode_data(value: &Value, encoding: DataEncoding) -> Result<Vec<u8>> {
+    let result = match (value, encoding) {
+        (Value::Array(items), _) => items.iter().map(decode_integer::<u8>).collect(),
+        (Value::String(text), DataEncoding::Base64) => decode_base64(text),
+        (Value::String(text), DataEncoding::Hex) => decode_hex(text),
+        _ => None,
+    };
+    result.ok_or_else(|| {
+        let expected = match encoding {
+            DataEncoding::Array => "an array of bytes",
+            DataEncoding::Base64 => "a base64 string",
+            DataEncoding::Hex => "a hex string",
+        };
+        mismatch(expected, value)
+    })
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_base64(data: &[u8]) -> String {
+    let mut result = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(BASE64_ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+/// Decodes base64 in either the standard or the URL-safe alphabet, with or without padding.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut result = Vec::with_capacity(text.len() * 3 / 4);
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        accumulator = accumulator << 6 | u32::from(sextet);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((accumulator >> bits) as u8);
+            accumulator &= (1 << bits) - 1;
+        }
+    }
+    Some(result)
+}
+
+fn encode_hex(data: &[u8]) -> String {
+    let mut result = String::with_capacity(data.len() * 2);
+    for b in data {
+        result.push_str(&format!("{b:02x}"));
+    }
+    result
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(text.get(idx..idx + 2)?, 16).ok())
+        .collect()
+}
+
+/// A parsed JSON value. Numbers keep their original text so that 64-bit integers do not
+/// lose precision.
+enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+fn write_newline(indent: Option<usize>, out: &mut String) {
+    if let Some(indent) = indent {
+        out.push('\n');
+        for _ in 0..indent {
+            out.push_str("  ");
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\x08' => out.push_str("\\b"),
+            '\x0c' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes `value` to `out`. `indent` is `None` for compact output, or else the current
+/// indentation level.
+fn write_value(value: &Value, indent: Option<usize>, out: &mut String) {
+    let inner_indent = indent.map(|x| x + 1);
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => out.push_str(n),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) if items.is_empty() => out.push_str("[]"),
+        Value::Array(items) => {
+            out.push('[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_newline(inner_indent, out);
+                write_value(item, inner_indent, out);
+            }
+            write_newline(indent, out);
+            out.push(']');
+        }
+        Value::Object(fields) if fields.is_empty() => out.push_str("{}"),
+        Value::Object(fields) => {
+            out.push('{');
+            for (idx, (key, value)) in fields.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_newline(inner_indent, out);
+                write_string(key, out);
+                out.push_str(if indent.is_some() { ": " } else { ":" });
+                write_value(value, inner_indent, out);
+            }
+            write_newline(indent, out);
+            out.push('}');
+        }
+    }
+}
+
+struct Parser<'i> {
+    input: &'i str,
+    pos: usize,
+}
+
+impl<'i> Parser<'i> {
+    fn error(&self, message: &str) -> Error {
+        error_at(
+            ErrorKind::InvalidJson,
+            self.input,
+            self.pos,
+            format_args!("{message}"),
+        )
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.input[self.pos..].chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize, nesting_limit: usize) -> Result<Value> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'{' | b'[') if depth >= nesting_limit => Err(error_at(
+                ErrorKind::NestingLimitExceeded,
+                self.input,
+                self.pos,
+                format_args!("JSON nesting limit of {nesting_limit} exceeded"),
+            )),
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                self.skip_whitespace();
+                if self.peek() == Some(b'}') {
+                    self.pos += 1;
+                    return Ok(Value::Object(fields));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.peek() != Some(b'"') {
+                        return Err(self.error("expected a string key"));
+                    }
+                    let key = self.parse_string()?;
+                    self.skip_whitespace();
+                    if self.peek() != Some(b':') {
+                        return Err(self.error("expected `:`"));
+                    }
+                    self.pos += 1;
+                    fields.push((key, self.parse_value(depth + 1, nesting_limit)?));
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => {
+                            self.pos += 1;
+                            return Ok(Value::Object(fields));
+                        }
+                        _ => return Err(self.error("expected `,` or `}`")),
+                    }
+                }
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                loop {
+                    items.push(self.parse_value(depth + 1, nesting_limit)?);
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => {
+                            self.pos += 1;
+                            return Ok(Value::Array(items));
+                        }
+                        _ => return Err(self.error("expected `,` or `]`")),
+                    }
+                }
+            }
+            Some(b'"') => Ok(Value::String(self.parse_string()?)),
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.pos;
+                while matches!(
+                    self.peek(),
+                    Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+                ) {
+                    self.pos += 1;
+                }
+                let text = &self.input[start..self.pos];
+                if text.parse::<f64>().is_err() {
+                    self.pos = start;
+                    return Err(self.error("invalid number"));
+                }
+                Ok(Value::Number(text.into()))
+            }
+            _ if rest.starts_with("null") => {
+                self.pos += 4;
+                Ok(Value::Null)
+            }
+            _ if rest.starts_with("true") => {
+                self.pos += 4;
+                Ok(Value::Bool(true))
+            }
+            _ if rest.starts_with("false") => {
+                self.pos += 5;
+                Ok(Value::Bool(false))
+            }
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32> {
+        match self.input.get(self.pos..self.pos + 4) {
+            Some(digits) if digits.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                self.pos += 4;
+                Ok(u32::from_str_radix(digits, 16).unwrap_or_default())
+            }
+            _ => Err(self.error("expected four hex digits")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        // Skip the opening quote.
+        self.pos += 1;
+        let mut result = String::new();
+        loop {
+            let start = self.pos;
+            match self.next_char() {
+                None => return Err(self.error("unterminated string")),
+                Some('"') => return Ok(result),
+                Some('\\') => {
+                    let c = match self.next_char() {
+                        Some(c @ ('"' | '\\' | '/')) => c,
+                        Some('b') => '\x08',
+                        Some('f') => '\x0c',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            let mut code = self.parse_hex4()?;
+                            if (0xd800..0xdc00).contains(&code)
+                                && self.input[self.pos..].starts_with("\\u")
+                            {
+                                self.pos += 2;
+                                let low = self.parse_hex4()?;
+                                if (0xdc00..0xe000).contains(&low) {
+                                    code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                                }
+                            }
+                            match char::from_u32(code) {
+                                Some(c) => c,
+                                None => {
+                                    self.pos = start;
+                                    return Err(self.error("invalid unicode escape"));
+                                }
+                            }
+                        }
+                        _ => {
+                            self.pos = start;
+                            return Err(self.error("invalid escape sequence"));
+                        }
+                    };
+                    result.push(c);
+                }
+                Some(c) if (c as u32) < 0x20 => {
+                    self.pos = start;
+                    return Err(self.error("unescaped control character in string"));
+                }
+                Some(c) => result.push(c),
+            }
+        }
+    }
+}
@@ -37,32 +37,48 @@ extern crate alloc;
 /// [schema.capnp](https://github.com/capnproto/capnproto/blob/master/c%2B%2B/src/capnp/schema.capnp).
 pub mod schema_capnp;
 
+/// Code generated from
+/// [json.capnp](https://github.com/capnproto/capnproto/blob/master/c%2B%2B/src/capnp/compat/json.capnp).
+pub mod json_capnp;
+
 pub mod any_pointer;
 pub mod any_pointer_list;
+#[cfg(feature = "alloc")]
+pub mod canonical;
 pub mod capability;
 pub mod capability_list;
 pub mod constant;
 pub mod data;
 pub mod data_list;
+#[cfg(feature = "alloc")]
+pub mod dynamic_capability;
 pub mod dynamic_list;
 pub mod dynamic_struct;
 pub mod dynamic_value;
 pub mod enum_list;
 pub mod introspect;
 pub mod io;
+#[cfg(feature = "alloc")]
+pub mod json;
 pub mod list_list;
 pub mod message;
+#[cfg(target_has_atomic = "ptr")]
+pub mod orphan;
 pub mod primitive_list;
 pub mod private;
 pub mod raw;
 pub mod schema;
 pub mod serialize;
 pub mod serialize_packed;
-pub(crate) mod stringify;
+pub mod stringify;
 pub mod struct_list;
 pub mod text;
+#[cfg(feature = "alloc")]
+pub mod text_format;
 pub mod text_list;
 pub mod traits;
+#[cfg(feature = "alloc")]
+pub mod transcode;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
@@ -202,6 +218,9 @@ pub enum ErrorKind {
     /// approach based on other methods.
     Unimplemented,
 
+    /// The allocator refused to allocate more memory for a message
+    AllocationLimitExceeded,
+
     /// Buffer is not large enough
     BufferNotLargeEnough,
 
@@ -214,6 +233,9 @@ pub enum ErrorKind {
     /// Don't know how to handle non-STRUCT inline composite.
     CantHandleNonStructInlineComposite,
 
+    /// A different schema node with id {id} was already loaded
+    ConflictingSchemaNode(u64),
+
     /// Empty buffer
     EmptyBuffer,
 
@@ -250,6 +272,9 @@ pub enum ErrorKind {
     /// field not found
     FieldNotFound,
 
+    /// Segment {index} of a field path does not exist
+    FieldPathSegmentNotFound(usize),
+
     /// Found bit list where struct list was expected; upgrading boolean lists to struct lists is no longer supported
     FoundBitListWhereStructListWasExpected,
 
@@ -283,9 +308,24 @@ pub enum ErrorKind {
     /// Too many or too few segments {segment_count}
     InvalidNumberOfSegments(usize),
 
+    /// Invalid field path
+    InvalidFieldPath,
+
+    /// Input is not valid JSON
+    InvalidJson,
+
+    /// Message index does not match the log it describes
+    InvalidMessageIndex,
+
+    /// Invalid schema node {id}
+    InvalidSchemaNode(u64),
+
     /// Invalid segment id {id}
     InvalidSegmentId(u32),
 
+    /// Input is not valid Cap'n Proto text format
+    InvalidTextFormat,
+
     /// List(AnyPointer) not supported.
     ListAnyPointerNotSupported,
 
@@ -349,9 +389,18 @@ pub enum ErrorKind {
     /// Message is too large
     MessageTooLarge(usize),
 
+    /// method not found
+    MethodNotFound,
+
     /// Nesting limit exceeded
     NestingLimitExceeded,
 
+    /// Not an enum
+    NotAnEnum,
+
+    /// Not an interface
+    NotAnInterface,
+
     /// Not a struct
     NotAStruct,
 
@@ -370,6 +419,9 @@ pub enum ErrorKind {
     /// Read limit exceeded
     ReadLimitExceeded,
 
+    /// Schema node {id} not found
+    SchemaNodeNotFound(u64),
+
     /// setting dynamic capabilities is unsupported
     SettingDynamicCapabilitiesIsUnsupported,
 
@@ -527,6 +579,7 @@ impl core::fmt::Display for ErrorKind {
             Self::Overloaded => write!(fmt, "Overloaded"),
             Self::Disconnected => write!(fmt, "Disconnected"),
             Self::Unimplemented => write!(fmt, "Unimplemented"),
+            Self::AllocationLimitExceeded => write!(fmt, "The allocator refused to allocate more memory for a message"),
             Self::BufferNotLargeEnough => write!(fmt, "buffer is not large enough"),
             Self::ExistingListPointerIsNotByteSized => write!(fmt, "Called get_writable_{{data|text}}_pointer() but existing list pointer is not byte-sized."),
             Self::ExistingPointerIsNotAList => write!(fmt, "Called get_writable_{{data|text|list|struct_list}}_pointer() but existing pointer is not a list."),
@@ -535,6 +588,7 @@ impl core::fmt::Display for ErrorKind {
             Self::FourByteSegmentLengthTooBigForUSize => write!(fmt, "Cannot represent 4 byte segment length as usize. This may indicate that you are running on 8 or 16 bit platform or segment is too large"),
             Self::CannotSetAnyPointerFieldToAPrimitiveValue => write!(fmt, "cannot set AnyPointer field to a primitive value"),
             Self::CantHandleNonStructInlineComposite => write!(fmt, "Don't know how to handle non-STRUCT inline composite."),
+            Self::ConflictingSchemaNode(id) => write!(fmt, "A different schema node with id {id:#018x} was already loaded"),
             Self::EmptyBuffer => write!(fmt, "empty buffer"),
             Self::EmptySlice => write!(fmt, "empty slice"),
             Self::EnumValueOrUnionDiscriminantNotPresent(val) => write!(fmt, "Enum value or union discriminant {val} was not present in schema"),
@@ -545,6 +599,7 @@ impl core::fmt::Display for ErrorKind {
             Self::FailedToFillTheWholeBuffer => write!(fmt, "failed to fill the whole buffer"),
             Self::FieldAndDefaultMismatch => write!(fmt, "field and default mismatch"),
             Self::FieldNotFound => write!(fmt, "field not found"),
+            Self::FieldPathSegmentNotFound(index) => write!(fmt, "Segment {index} of field path does not exist"),
             Self::FoundBitListWhereStructListWasExpected => write!(fmt, "Found bit list where struct list was expected; upgrading boolean lists to struct lists is no longer supported."),
             Self::FoundStructListWhereBitListWasExpected => write!(fmt, "Found struct list where bit list was expected."),
             Self::GroupFieldButTypeIsNotStruct => write!(fmt, "group field but type is not Struct"),
@@ -554,7 +609,12 @@ impl core::fmt::Display for ErrorKind {
             Self::InlineCompositeListsElementsOverrunItsWordCount => write!(fmt, "InlineComposite list's elements overrun its word count."),
             Self::InlineCompositeListsOfNonStructTypeAreNotSupported => write!(fmt, "InlineComposite lists of non-STRUCT type are not supported."),
             Self::InvalidNumberOfSegments(segment_count) => write!(fmt, "Too many or too few segments {segment_count}"),
+            Self::InvalidFieldPath => write!(fmt, "Invalid field path"),
+            Self::InvalidJson => write!(fmt, "Invalid JSON"),
+            Self::InvalidMessageIndex => write!(fmt, "Message index does not match the log it describes"),
+            Self::InvalidSchemaNode(id) => write!(fmt, "Invalid schema node {id:#018x}"),
             Self::InvalidSegmentId(id) => write!(fmt, "Invalid segment id {id}"),
+            Self::InvalidTextFormat => write!(fmt, "Invalid Cap'n Proto text format"),
             Self::ListAnyPointerNotSupported => write!(fmt, "List(AnyPointer) not supported."),
             Self::ListCapabilityNotSupported => write!(fmt, "List(Capability) not supported"),
             Self::MalformedDoubleFarPointer => write!(fmt, "Malformed double-far pointer."),
@@ -576,13 +636,17 @@ impl core::fmt::Display for ErrorKind {
             Self::MessageSizeOverflow => write!(fmt, "Message's size cannot be represented in usize"),
             Self::MessageTooLarge(val) => write!(fmt, "Message is too large: {val}"),
             Self::MessageNotAlignedBy8BytesBoundary => write!(fmt, "Message was not aligned by 8 bytes boundary. Either ensure that message is properly aligned or compile `capnp` crate with \"unaligned\" feature enabled."),
+            Self::MethodNotFound => write!(fmt, "method not found"),
             Self::NestingLimitExceeded => write!(fmt, "nesting limit exceeded"),
+            Self::NotAnEnum => write!(fmt, "not an enum"),
+            Self::NotAnInterface => write!(fmt, "not an interface"),
             Self::NotAStruct => write!(fmt, "not a struct"),
             Self::OnlyOneOfTheSectionPointersIsPointingToOurself => write!(fmt, "Only one of the section pointers is pointing to ourself"),
             Self::PackedInputDidNotEndCleanlyOnASegmentBoundary => write!(fmt, "Packed input did not end cleanly on a segment boundary."),
             Self::PrematureEndOfFile => write!(fmt, "Premature end of file"),
             Self::PrematureEndOfPackedInput => write!(fmt, "Premature end of packed input."),
             Self::ReadLimitExceeded => write!(fmt, "Read limit exceeded"),
+            Self::SchemaNodeNotFound(id) => write!(fmt, "Schema node {id:#018x} not found"),
             Self::SettingDynamicCapabilitiesIsUnsupported => write!(fmt, "setting dynamic capabilities is unsupported"),
             Self::StructReaderHadBitwidthOtherThan1 => write!(fmt, "struct reader had bitwidth other than 1"),
             Self::TextBlobMissingNULTerminator => write!(fmt, "Text blob missing NUL terminator."),
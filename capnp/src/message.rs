@@ -73,6 +73,8 @@ use alloc::vec::Vec;
 use core::convert::From;
 
 use crate::any_pointer;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use crate::orphan::Orphanage;
 #[cfg(feature = "alloc")]
 use crate::private::arena::{BuilderArena, BuilderArenaImpl};
 use crate::private::arena::{ReaderArena, ReaderArenaImpl};
@@ -84,6 +86,16 @@ use crate::traits::{FromPointerReader, Owned};
 #[cfg(feature = "alloc")]
 use crate::OutputSegments;
 use crate::Result;
+#[cfg(feature = "alloc")]
+use crate::{Error, ErrorKind};
+
+mod arena_allocator;
+pub use arena_allocator::{Arena, ArenaAllocator};
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+mod pooled_allocator;
+#[cfg(all(feature = "alloc", feature = "std"))]
+pub use pooled_allocator::{PooledAllocator, SegmentPool};
 
 /// Options controlling how data is read.
 #[derive(Clone, Copy, Debug)]
@@ -205,6 +217,20 @@ impl<'b> ReaderSegments for SegmentArray<'b> {
     }
 }
 
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<S> ReaderSegments for alloc::sync::Arc<S>
+where
+    S: ReaderSegments,
+{
+    fn get_segment(&self, idx: u32) -> Option<&[u8]> {
+        (**self).get_segment(idx)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
 impl<'b> ReaderSegments for [&'b [u8]] {
     fn get_segment(&self, id: u32) -> Option<&[u8]> {
         self.get(id as usize).copied()
@@ -344,6 +370,94 @@ where
     }
 }
 
+/// A message reader of type `T` that can be shared between threads.
+///
+/// The segments are kept behind an `Arc`, so cloning a `SharedReader` does not copy them. Each
+/// clone has its own traversal limit, starting from the `ReaderOptions` that it was created
+/// with. `SharedReader` is `Send + Sync` whenever `S` is, regardless of whether the
+/// `sync_reader` feature is enabled. It is only available on targets that support
+/// `alloc::sync::Arc`.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub struct SharedReader<T, S = crate::serialize::OwnedSegments>
+where
+    S: ReaderSegments,
+    T: Owned,
+{
+    marker: ::core::marker::PhantomData<fn() -> T>,
+    arena: crate::private::arena::SyncReaderArenaImpl<alloc::sync::Arc<S>>,
+    options: ReaderOptions,
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T, S> SharedReader<T, S>
+where
+    S: ReaderSegments,
+    T: Owned,
+{
+    pub fn new(segments: S, options: ReaderOptions) -> Self {
+        Self::from_arc(alloc::sync::Arc::new(segments), options)
+    }
+
+    fn from_arc(segments: alloc::sync::Arc<S>, options: ReaderOptions) -> Self {
+        Self {
+            marker: ::core::marker::PhantomData,
+            arena: crate::private::arena::SyncReaderArenaImpl::new(segments, options),
+            options,
+        }
+    }
+
+    pub fn get(&self) -> Result<T::Reader<'_>> {
+        let (segment_start, _seg_len) = self.arena.get_segment(0)?;
+        let pointer_reader = layout::PointerReader::get_root(
+            &self.arena,
+            0,
+            segment_start,
+            self.arena.nesting_limit(),
+        )?;
+        any_pointer::Reader::new(pointer_reader).get_as()
+    }
+
+    /// Clones the reader, giving the clone a fresh traversal limit from `options`.
+    pub fn clone_with_options(&self, options: ReaderOptions) -> Self {
+        Self::from_arc(self.arena.segments().clone(), options)
+    }
+
+    pub fn segments(&self) -> &S {
+        self.arena.segments()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T, S> Clone for SharedReader<T, S>
+where
+    S: ReaderSegments,
+    T: Owned,
+{
+    fn clone(&self) -> Self {
+        self.clone_with_options(self.options)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T, S> From<TypedReader<S, T>> for SharedReader<T, S>
+where
+    S: ReaderSegments,
+    T: Owned,
+{
+    /// Shares the segments of `reader`, with the default `ReaderOptions`.
+    fn from(reader: TypedReader<S, T>) -> Self {
+        Self::new(reader.into_inner().into_segments(), ReaderOptions::new())
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+fn _assert_shared_reader_send_sync() {
+    fn _assert_send_sync<T: Send + Sync>() {}
+    fn _assert_shared_reader<T: Owned>() {
+        _assert_send_sync::<SharedReader<T>>();
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<A, T> From<Builder<A>> for TypedReader<Builder<A>, T>
 where
@@ -390,6 +504,14 @@ pub unsafe trait Allocator {
     /// previous segment.
     fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32);
 
+    /// Like `allocate_segment()`, but may refuse to allocate, for example because the message
+    /// has grown too large. The error is returned from builder methods that return a `Result`,
+    /// including the `try_init_*` and `try_set_*` variants of the infallible ones, and causes a
+    /// panic in the others. The default implementation calls `allocate_segment()`.
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        Ok(self.allocate_segment(minimum_size))
+    }
+
     /// Indicates that a segment, previously allocated via allocate_segment(), is no longer in use.
     /// `word_size` is the length of the segment in words, as returned from `allocate_segment()`.
     /// `words_used` is always less than or equal to `word_size`, and indicates how many
@@ -437,35 +559,52 @@ where
         }
     }
 
-    fn get_root_internal(&mut self) -> any_pointer::Builder<'_> {
+    fn allocate_root_pointer(&mut self) -> Result<()> {
         if self.arena.is_empty() {
-            self.arena
-                .allocate_segment(1)
-                .expect("allocate root pointer");
+            self.arena.allocate_segment(1)?;
             self.arena.allocate(0, 1).expect("allocate root pointer");
         }
+        Ok(())
+    }
+
+    fn get_root_internal(&mut self) -> Result<any_pointer::Builder<'_>> {
+        self.allocate_root_pointer()?;
         let (seg_start, _seg_len) = self.arena.get_segment_mut(0);
         let location: *mut u8 = seg_start;
         let Self { arena } = self;
 
-        any_pointer::Builder::new(layout::PointerBuilder::get_root(arena, 0, location))
+        Ok(any_pointer::Builder::new(layout::PointerBuilder::get_root(
+            arena, 0, location,
+        )))
     }
 
     /// Initializes the root as a value of the given type.
     pub fn init_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> T {
-        let root = self.get_root_internal();
+        let root = self.get_root_internal().unwrap_or_else(|e| panic!("{e}"));
         root.init_as()
     }
 
     /// Initializes the root as a value of the given list type, with the given length.
     pub fn initn_root<'a, T: FromPointerBuilder<'a>>(&'a mut self, length: u32) -> T {
-        let root = self.get_root_internal();
+        let root = self.get_root_internal().unwrap_or_else(|e| panic!("{e}"));
         root.initn_as(length)
     }
 
+    /// Like `init_root()`, but returns an error instead of panicking if the allocator refuses
+    /// to provide the space.
+    pub fn try_init_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
+        self.get_root_internal()?.try_init_as()
+    }
+
+    /// Like `initn_root()`, but returns an error instead of panicking if the allocator refuses
+    /// to provide the space.
+    pub fn try_initn_root<'a, T: FromPointerBuilder<'a>>(&'a mut self, length: u32) -> Result<T> {
+        self.get_root_internal()?.try_initn_as(length)
+    }
+
     /// Gets the root, interpreting it as the given type.
     pub fn get_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
-        let root = self.get_root_internal();
+        let root = self.get_root_internal()?;
         root.get_as()
     }
 
@@ -487,7 +626,7 @@ where
 
     /// Sets the root to a deep copy of the given value.
     pub fn set_root<From: SetPointerBuilder>(&mut self, value: From) -> Result<()> {
-        let mut root = self.get_root_internal();
+        let mut root = self.get_root_internal()?;
         root.set_as(value)
     }
 
@@ -495,12 +634,7 @@ where
     /// on this `Builder`, then a subsequent call to `get_segments_for_output()` should return
     /// a single segment, containing the full canonicalized message.
     pub fn set_root_canonical<From: SetPointerBuilder>(&mut self, value: From) -> Result<()> {
-        if self.arena.is_empty() {
-            self.arena
-                .allocate_segment(1)
-                .expect("allocate root pointer");
-            self.arena.allocate(0, 1).expect("allocate root pointer");
-        }
+        self.allocate_root_pointer()?;
         let (seg_start, _seg_len) = self.arena.get_segment_mut(0);
         let pointer = layout::PointerBuilder::get_root(&mut self.arena, 0, seg_start);
         SetPointerBuilder::set_pointer_builder(pointer, value, true)?;
@@ -508,6 +642,27 @@ where
         Ok(())
     }
 
+    /// Copies everything reachable from the root into a single fresh segment and frees the old
+    /// segments. Returns the number of bytes reclaimed.
+    ///
+    /// The space taken by a value is not reused when that value is overwritten or cleared, so
+    /// a long-lived builder that is modified repeatedly keeps growing until it is compacted.
+    ///
+    /// Orphans created before compaction cannot be used afterwards. Compaction fails, leaving
+    /// the message unchanged, if the message contains capabilities.
+    pub fn compact(&mut self) -> Result<usize> {
+        Ok(self.arena.compact()? * BYTES_PER_WORD)
+    }
+
+    /// Returns an [`Orphanage`] for creating objects in this message that are not yet
+    /// reachable from the root.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn get_orphanage(&mut self) -> Orphanage<'_> {
+        self.allocate_root_pointer()
+            .unwrap_or_else(|e| panic!("{e}"));
+        Orphanage::new(&mut self.arena)
+    }
+
     pub fn get_segments_for_output(&self) -> OutputSegments {
         self.arena.get_segments_for_output()
     }
@@ -594,6 +749,14 @@ where
         self.message.initn_root(length)
     }
 
+    pub fn try_init_root(&mut self) -> Result<T::Builder<'_>> {
+        self.message.try_init_root()
+    }
+
+    pub fn try_initn_root(&mut self, length: u32) -> Result<T::Builder<'_>> {
+        self.message.try_initn_root(length)
+    }
+
     pub fn get_root(&mut self) -> Result<T::Builder<'_>> {
         self.message.get_root()
     }
@@ -646,6 +809,12 @@ pub struct HeapAllocator {
 
     // Maximum number of words to allocate.
     max_segment_words: u32,
+
+    // Maximum number of words to have allocated at once, across all segments.
+    max_message_words: Option<u32>,
+
+    // Number of words currently allocated, across all segments.
+    allocated_words: u32,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -668,6 +837,8 @@ impl Default for HeapAllocator {
             next_size: SUGGESTED_FIRST_SEGMENT_WORDS,
             allocation_strategy: SUGGESTED_ALLOCATION_STRATEGY,
             max_segment_words: 1 << 29,
+            max_message_words: None,
+            allocated_words: 0,
         }
     }
 }
@@ -697,12 +868,34 @@ impl HeapAllocator {
         self.max_segment_words = value;
         self
     }
+
+    /// Sets the maximum number of words that may be allocated across all segments of a message.
+    /// Once the limit is reached, builder methods that return a `Result` fail with
+    /// `ErrorKind::AllocationLimitExceeded` instead of growing the message, and the others panic.
+    pub fn max_message_words(mut self, value: u32) -> Self {
+        self.max_message_words = Some(value);
+        self
+    }
 }
 
 #[cfg(feature = "alloc")]
 unsafe impl Allocator for HeapAllocator {
     fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
-        let size = core::cmp::max(minimum_size, self.next_size);
+        match self.try_allocate_segment(minimum_size) {
+            Ok(segment) => segment,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        let mut size = core::cmp::max(minimum_size, self.next_size);
+        if let Some(max_message_words) = self.max_message_words {
+            let remaining = max_message_words - self.allocated_words;
+            if minimum_size > remaining {
+                return Err(Error::from_kind(ErrorKind::AllocationLimitExceeded));
+            }
+            size = core::cmp::min(size, remaining);
+        }
         let layout =
             alloc::alloc::Layout::from_size_align(size as usize * BYTES_PER_WORD, 8).unwrap();
         let ptr = unsafe { alloc::alloc::alloc_zeroed(layout) };
@@ -719,7 +912,8 @@ unsafe impl Allocator for HeapAllocator {
             }
             AllocationStrategy::FixedSize => {}
         }
-        (ptr, size)
+        self.allocated_words += size;
+        Ok((ptr, size))
     }
 
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, _words_used: u32) {
@@ -731,6 +925,7 @@ unsafe impl Allocator for HeapAllocator {
             );
         }
         self.next_size = SUGGESTED_FIRST_SEGMENT_WORDS;
+        self.allocated_words -= word_size;
     }
 }
 
@@ -782,6 +977,7 @@ impl Builder<HeapAllocator> {
 pub struct ScratchSpaceHeapAllocator<'a> {
     scratch_space: &'a mut [u8],
     scratch_space_allocated: bool,
+    heap_fallback: bool,
     allocator: HeapAllocator,
 }
 
@@ -810,6 +1006,7 @@ impl<'a> ScratchSpaceHeapAllocator<'a> {
         ScratchSpaceHeapAllocator {
             scratch_space,
             scratch_space_allocated: false,
+            heap_fallback: true,
             allocator: HeapAllocator::new(),
         }
     }
@@ -830,21 +1027,44 @@ impl<'a> ScratchSpaceHeapAllocator<'a> {
             ..self
         }
     }
+
+    /// Sets whether segments that do not fit in the scratch space are allocated on the heap,
+    /// which is the default. If not, such allocations fail with
+    /// `ErrorKind::BufferNotLargeEnough`, and the `try_` builder methods can be used to build
+    /// a message without ever leaving the scratch space or panicking.
+    ///
+    /// This still requires the `alloc` feature: `message::Builder` keeps its table of segments
+    /// on the heap, so building messages on targets without any allocator is not supported.
+    pub fn heap_fallback(self, value: bool) -> ScratchSpaceHeapAllocator<'a> {
+        ScratchSpaceHeapAllocator {
+            heap_fallback: value,
+            ..self
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
 unsafe impl<'a> Allocator for ScratchSpaceHeapAllocator<'a> {
     fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        match self.try_allocate_segment(minimum_size) {
+            Ok(segment) => segment,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
         if (minimum_size as usize) < (self.scratch_space.len() / BYTES_PER_WORD)
             && !self.scratch_space_allocated
         {
             self.scratch_space_allocated = true;
-            (
+            Ok((
                 self.scratch_space.as_mut_ptr(),
                 (self.scratch_space.len() / BYTES_PER_WORD) as u32,
-            )
+            ))
+        } else if self.heap_fallback {
+            self.allocator.try_allocate_segment(minimum_size)
         } else {
-            self.allocator.allocate_segment(minimum_size)
+            Err(Error::from_kind(ErrorKind::BufferNotLargeEnough))
         }
     }
 
@@ -863,6 +1083,24 @@ unsafe impl<'a> Allocator for ScratchSpaceHeapAllocator<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
+unsafe impl<A> Allocator for alloc::boxed::Box<A>
+where
+    A: Allocator + ?Sized,
+{
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        (**self).allocate_segment(minimum_size)
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        (**self).try_allocate_segment(minimum_size)
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        (**self).deallocate_segment(ptr, word_size, words_used)
+    }
+}
+
 #[cfg(feature = "alloc")]
 unsafe impl<'a, A> Allocator for &'a mut A
 where
@@ -872,6 +1110,10 @@ where
         (*self).allocate_segment(minimum_size)
     }
 
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        (*self).try_allocate_segment(minimum_size)
+    }
+
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
         (*self).deallocate_segment(ptr, word_size, words_used)
     }
@@ -0,0 +1,188 @@
+use core::alloc::Layout;
+use core::ptr::NonNull;
+
+use super::{
+    AllocationStrategy, Allocator, SUGGESTED_ALLOCATION_STRATEGY, SUGGESTED_FIRST_SEGMENT_WORDS,
+};
+use crate::private::units::BYTES_PER_WORD;
+use crate::{Error, ErrorKind, Result};
+
+/// A region of memory that hands out allocations through a shared reference and frees them
+/// all at once, like `bumpalo::Bump`.
+///
+/// # Safety
+/// Implementations must ensure that the memory returned by `allocate` fits `layout`, does not
+/// overlap with other memory returned by the arena, and stays valid for as long as the arena
+/// is borrowed.
+pub unsafe trait Arena {
+    /// Allocates memory for `layout`, or returns `None` if the arena is out of space. The
+    /// memory does not need to be zeroed.
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+}
+
+unsafe impl<A> Arena for &A
+where
+    A: Arena + ?Sized,
+{
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        (**self).allocate(layout)
+    }
+}
+
+/// Segment allocator that takes its segments from an [`Arena`].
+///
+/// Segments are never freed individually; they go away when the arena itself is reset or
+/// dropped, so that all the messages built in one arena can be released in one shot. Segments
+/// are sized the same way as by `HeapAllocator`.
+pub struct ArenaAllocator<'a, A>
+where
+    A: Arena + ?Sized,
+{
+    arena: &'a A,
+
+    // Minimum number of words in the next allocation.
+    next_size: u32,
+
+    // How to update next_size after an allocation.
+    allocation_strategy: AllocationStrategy,
+}
+
+impl<'a, A> ArenaAllocator<'a, A>
+where
+    A: Arena + ?Sized,
+{
+    /// Creates an allocator whose segments live in `arena`.
+    pub fn new(arena: &'a A) -> Self {
+        Self {
+            arena,
+            next_size: SUGGESTED_FIRST_SEGMENT_WORDS,
+            allocation_strategy: SUGGESTED_ALLOCATION_STRATEGY,
+        }
+    }
+
+    /// Sets the size of the initial segment in words, where 1 word = 8 bytes.
+    pub fn first_segment_words(mut self, value: u32) -> Self {
+        self.next_size = value;
+        self
+    }
+
+    /// Sets the allocation strategy for segments after the first one.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.allocation_strategy = value;
+        self
+    }
+}
+
+unsafe impl<'a, A> Allocator for ArenaAllocator<'a, A>
+where
+    A: Arena + ?Sized,
+{
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        self.try_allocate_segment(minimum_size)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        let size = core::cmp::max(minimum_size, self.next_size);
+        let layout = Layout::from_size_align(size as usize * BYTES_PER_WORD, 8)
+            .map_err(|_| Error::from_kind(ErrorKind::AllocationLimitExceeded))?;
+        let ptr = self
+            .arena
+            .allocate(layout)
+            .ok_or_else(|| Error::from_kind(ErrorKind::AllocationLimitExceeded))?
+            .as_ptr();
+        unsafe {
+            core::ptr::write_bytes(ptr, 0, layout.size());
+        }
+        match self.allocation_strategy {
+            AllocationStrategy::GrowHeuristically => {
+                self.next_size = self.next_size.saturating_add(size).min(1 << 29);
+            }
+            AllocationStrategy::FixedSize => {}
+        }
+        Ok((ptr, size))
+    }
+
+    unsafe fn deallocate_segment(&mut self, _ptr: *mut u8, _word_size: u32, _words_used: u32) {
+        // The memory belongs to the arena.
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use core::alloc::Layout;
+    use core::cell::{Cell, UnsafeCell};
+    use core::ptr::NonNull;
+
+    use super::{Arena, ArenaAllocator};
+    use crate::message::{self, Allocator};
+    use crate::{text, ErrorKind, Word};
+
+    struct BumpArena {
+        words: UnsafeCell<alloc::vec::Vec<Word>>,
+        used: Cell<usize>,
+    }
+
+    impl BumpArena {
+        fn new(words: usize) -> Self {
+            Self {
+                words: UnsafeCell::new(Word::allocate_zeroed_vec(words)),
+                used: Cell::new(0),
+            }
+        }
+
+        fn reset(&mut self) {
+            self.words.get_mut().fill(Word {
+                raw_content: [0xff; 8],
+            });
+            self.used.set(0);
+        }
+    }
+
+    unsafe impl Arena for BumpArena {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            let words = (layout.size() + 7) / 8;
+            let len = unsafe { (*self.words.get()).len() };
+            if self.used.get() + words > len {
+                return None;
+            }
+            let ptr = unsafe { (*self.words.get()).as_mut_ptr().add(self.used.get()) };
+            self.used.set(self.used.get() + words);
+            NonNull::new(ptr as *mut u8)
+        }
+    }
+
+    #[test]
+    fn messages_share_arena() {
+        let mut arena = BumpArena::new(64);
+        for _ in 0..2 {
+            let mut message1 =
+                message::Builder::new(ArenaAllocator::new(&arena).first_segment_words(8));
+            let mut message2 =
+                message::Builder::new(ArenaAllocator::new(&arena).first_segment_words(8));
+            message1.set_root("hello").unwrap();
+            let long_text = "this text is too long to fit in a first segment of eight words";
+            message2.set_root(long_text).unwrap();
+            assert_eq!(message1.get_segments_for_output().len(), 1);
+            assert_eq!(message2.get_segments_for_output().len(), 2);
+            assert_eq!(
+                message1.get_root_as_reader::<text::Reader>().unwrap(),
+                "hello"
+            );
+            drop(message1);
+            drop(message2);
+
+            // Dirty the memory, to check that segments are zeroed when they are handed out.
+            arena.reset();
+        }
+    }
+
+    #[test]
+    fn arena_exhausted() {
+        let arena = BumpArena::new(16);
+        let mut allocator = ArenaAllocator::new(&arena).first_segment_words(8);
+        allocator.try_allocate_segment(1).unwrap();
+        let err = allocator.try_allocate_segment(1).err().unwrap();
+        assert_eq!(err.kind, ErrorKind::AllocationLimitExceeded);
+    }
+}
@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::{
+    AllocationStrategy, Allocator, SUGGESTED_ALLOCATION_STRATEGY, SUGGESTED_FIRST_SEGMENT_WORDS,
+};
+use crate::private::units::BYTES_PER_WORD;
+use crate::{Error, ErrorKind, Result};
+
+/// A free list of segments, shared by the [`PooledAllocator`]s created from it.
+///
+/// Segments deallocated by a `PooledAllocator` are kept in the pool and handed out again to
+/// later messages, instead of being returned to the heap. A segment is re-zeroed only when it
+/// is reused, and only the prefix that its previous message could have written to.
+///
+/// Cloning a `SegmentPool` produces another handle to the same pool, so a pool can be shared
+/// between threads.
+#[derive(Clone)]
+pub struct SegmentPool {
+    inner: Arc<Mutex<SegmentPoolInner>>,
+}
+
+struct FreeSegment {
+    ptr: *mut u8,
+
+    // Length of the segment in words.
+    size: u32,
+
+    // Number of words, from the start of the segment, that might be nonzero.
+    dirty: u32,
+}
+
+struct SegmentPoolInner {
+    segments: Vec<FreeSegment>,
+
+    // Total length of `segments` in words.
+    pooled_words: usize,
+
+    // Maximum value of `pooled_words`.
+    max_pooled_words: usize,
+}
+
+// The pool owns the segments it holds, so they may be handed out on any thread.
+unsafe impl Send for SegmentPoolInner {}
+
+impl SegmentPool {
+    /// Creates an empty pool that holds on to at most `max_pooled_words` words of free
+    /// segments. Segments deallocated while the pool is full go back to the heap.
+    pub fn new(max_pooled_words: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SegmentPoolInner {
+                segments: Vec::new(),
+                pooled_words: 0,
+                max_pooled_words,
+            })),
+        }
+    }
+
+    /// Creates an allocator that takes its segments from this pool.
+    pub fn allocator(&self) -> PooledAllocator {
+        PooledAllocator {
+            pool: self.clone(),
+            first_size: SUGGESTED_FIRST_SEGMENT_WORDS,
+            next_size: SUGGESTED_FIRST_SEGMENT_WORDS,
+            allocation_strategy: SUGGESTED_ALLOCATION_STRATEGY,
+            max_message_words: None,
+            allocated_words: 0,
+        }
+    }
+
+    /// Returns the number of free segments held by the pool.
+    pub fn len(&self) -> usize {
+        self.lock().segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SegmentPoolInner> {
+        // The pool is never left in an inconsistent state, so a panic on another thread
+        // does not need to poison it.
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Takes a zeroed segment of between `minimum_size` and `maximum_size` words out of the
+    /// pool, if it holds one.
+    fn take(&self, minimum_size: u32, maximum_size: u32) -> Option<(*mut u8, u32)> {
+        let segment = {
+            let mut inner = self.lock();
+            let idx = inner
+                .segments
+                .iter()
+                .rposition(|s| s.size >= minimum_size && s.size <= maximum_size)?;
+            let segment = inner.segments.swap_remove(idx);
+            inner.pooled_words -= segment.size as usize;
+            segment
+        };
+        unsafe {
+            core::ptr::write_bytes(segment.ptr, 0, segment.dirty as usize * BYTES_PER_WORD);
+        }
+        Some((segment.ptr, segment.size))
+    }
+
+    /// Puts a segment into the pool, or frees it if the pool is full.
+    fn give(&self, ptr: *mut u8, size: u32, dirty: u32) {
+        let mut inner = self.lock();
+        if inner.pooled_words + size as usize <= inner.max_pooled_words {
+            inner.pooled_words += size as usize;
+            inner.segments.push(FreeSegment { ptr, size, dirty });
+        } else {
+            drop(inner);
+            unsafe { deallocate(ptr, size) }
+        }
+    }
+}
+
+impl Drop for SegmentPoolInner {
+    fn drop(&mut self) {
+        for segment in &self.segments {
+            unsafe { deallocate(segment.ptr, segment.size) }
+        }
+    }
+}
+
+fn layout(size: u32) -> alloc::alloc::Layout {
+    alloc::alloc::Layout::from_size_align(size as usize * BYTES_PER_WORD, 8).unwrap()
+}
+
+unsafe fn deallocate(ptr: *mut u8, size: u32) {
+    unsafe { alloc::alloc::dealloc(ptr, layout(size)) }
+}
+
+/// Segment allocator that recycles segments through a [`SegmentPool`].
+///
+/// Segments are sized the same way as by `HeapAllocator`. They are taken from the pool when
+/// it has one that is large enough, and allocated on the heap otherwise.
+pub struct PooledAllocator {
+    pool: SegmentPool,
+
+    // Size of the first segment, which `next_size` goes back to once the message is freed.
+    first_size: u32,
+
+    // Minimum number of words in the next allocation.
+    next_size: u32,
+
+    // How to update next_size after an allocation.
+    allocation_strategy: AllocationStrategy,
+
+    // Maximum number of words to have allocated at once, across all segments.
+    max_message_words: Option<u32>,
+
+    // Number of words currently allocated, across all segments.
+    allocated_words: u32,
+}
+
+impl PooledAllocator {
+    /// Sets the size of the initial segment in words, where 1 word = 8 bytes.
+    pub fn first_segment_words(mut self, value: u32) -> Self {
+        self.first_size = value;
+        self.next_size = value;
+        self
+    }
+
+    /// Sets the allocation strategy for segments after the first one.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.allocation_strategy = value;
+        self
+    }
+
+    /// Sets the maximum number of words that may be allocated across all segments of a message,
+    /// with the same effect as `HeapAllocator::max_message_words()`.
+    pub fn max_message_words(mut self, value: u32) -> Self {
+        self.max_message_words = Some(value);
+        self
+    }
+}
+
+unsafe impl Allocator for PooledAllocator {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        match self.try_allocate_segment(minimum_size) {
+            Ok(segment) => segment,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        let mut size = core::cmp::max(minimum_size, self.next_size);
+        let mut maximum_size = u32::MAX;
+        if let Some(max_message_words) = self.max_message_words {
+            maximum_size = max_message_words - self.allocated_words;
+            if minimum_size > maximum_size {
+                return Err(Error::from_kind(ErrorKind::AllocationLimitExceeded));
+            }
+            size = core::cmp::min(size, maximum_size);
+        }
+        let (ptr, size) = match self.pool.take(size, maximum_size) {
+            Some(segment) => segment,
+            None => {
+                let layout = layout(size);
+                let ptr = unsafe { alloc::alloc::alloc_zeroed(layout) };
+                if ptr.is_null() {
+                    alloc::alloc::handle_alloc_error(layout);
+                }
+                (ptr, size)
+            }
+        };
+        match self.allocation_strategy {
+            AllocationStrategy::GrowHeuristically => {
+                self.next_size = self.next_size.saturating_add(size).min(1 << 29);
+            }
+            AllocationStrategy::FixedSize => {}
+        }
+        self.allocated_words += size;
+        Ok((ptr, size))
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        self.pool.give(ptr, word_size, words_used);
+        self.next_size = self.first_size;
+        self.allocated_words = self.allocated_words.saturating_sub(word_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegmentPool;
+    use crate::message::{self, Allocator};
+
+    #[test]
+    fn reuses_segments() {
+        let pool = SegmentPool::new(1 << 20);
+        let mut allocator = pool.allocator().first_segment_words(16);
+        let (ptr, size) = allocator.allocate_segment(1);
+        assert_eq!(size, 16);
+        unsafe {
+            core::ptr::write_bytes(ptr, 0xff, 4 * 8);
+            allocator.deallocate_segment(ptr, size, 4);
+        }
+        assert_eq!(pool.len(), 1);
+
+        // A request that does not fit in the pooled segment goes to the heap.
+        let (big_ptr, big_size) = allocator.allocate_segment(17);
+        assert_eq!(big_size, 17);
+        assert_eq!(pool.len(), 1);
+
+        let (ptr2, size2) = pool.allocator().first_segment_words(16).allocate_segment(1);
+        assert_eq!((ptr2, size2), (ptr, size));
+        assert!(pool.is_empty());
+        let bytes = unsafe { core::slice::from_raw_parts(ptr2, size2 as usize * 8) };
+        assert!(bytes.iter().all(|&b| b == 0));
+
+        unsafe {
+            allocator.deallocate_segment(ptr2, size2, 0);
+            allocator.deallocate_segment(big_ptr, big_size, 0);
+        }
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn full_pool_frees_segments() {
+        let pool = SegmentPool::new(16);
+        let mut message = message::Builder::new(pool.allocator().first_segment_words(16));
+        message.set_root("hello").unwrap();
+        drop(message);
+        assert_eq!(pool.len(), 1);
+
+        let mut message = message::Builder::new(pool.allocator().first_segment_words(16));
+        let mut other = message::Builder::new(pool.allocator().first_segment_words(16));
+        message.set_root("hello").unwrap();
+        other.set_root("world").unwrap();
+        assert!(pool.is_empty());
+        drop(message);
+        drop(other);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn max_message_words() {
+        let pool = SegmentPool::new(1 << 20);
+        let mut allocator = pool.allocator().first_segment_words(64);
+        let (ptr, size) = allocator.allocate_segment(1);
+        unsafe { allocator.deallocate_segment(ptr, size, 0) }
+
+        let long_text = "a".repeat(200);
+        let mut message = message::Builder::new(
+            pool.allocator()
+                .first_segment_words(8)
+                .max_message_words(16),
+        );
+        message.set_root("hello").unwrap();
+        let e = message.set_root(&long_text[..]).unwrap_err();
+        assert_eq!(e.kind, crate::ErrorKind::AllocationLimitExceeded);
+
+        // The pooled segment is larger than the limit, so it was not handed out.
+        assert_eq!(pool.len(), 1);
+        let words: usize = message
+            .get_segments_for_output()
+            .iter()
+            .map(|s| s.len() / 8)
+            .sum();
+        assert!(words <= 16);
+    }
+}
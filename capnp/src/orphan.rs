@@ -0,0 +1,169 @@
+//! Objects that belong to a message but are not reachable from its root.
+//!
+//! An [`Orphan`] can be created detached through an [`Orphanage`], or taken out of a pointer
+//! field with the generated `disown_foo()` accessor, and later put into any pointer field of
+//! the same message with `adopt_foo()`. Moving an object this way does not copy it.
+//!
+//! Orphans are only available on targets with atomic pointer-sized operations, which are
+//! needed to give every message a distinct ID that its orphans are checked against.
+//!
+#![cfg_attr(
+    feature = "alloc",
+    doc = r#"
+```
+use capnp::{message, orphan::Orphan, primitive_list};
+
+let mut message = message::Builder::new_default();
+let mut orphan: Orphan<primitive_list::Owned<u32>> =
+    message.get_orphanage().initn_orphan(3);
+orphan.get(message.get_orphanage()).unwrap().set(0, 42);
+
+let mut root: capnp::any_pointer::Builder = message.init_root();
+root.adopt(orphan);
+let list: primitive_list::Reader<u32> = root.into_reader().get_as().unwrap();
+assert_eq!(list.get(0), 42);
+```
+"#
+)]
+//!
+//! Roughly corresponds to orphan.h in the C++ implementation.
+
+use core::marker::PhantomData;
+
+use crate::private::arena::BuilderArena;
+use crate::private::layout::{CapTableBuilder, OrphanBuilder};
+use crate::traits::{FromPointerBuilder, FromPointerReader, Owned, SetPointerBuilder};
+use crate::Result;
+
+/// An object of type `T` that is owned by a message but not reachable from its root.
+///
+/// An orphan that is dropped without being adopted stays in the message as unreachable
+/// data. Use [`Orphan::discard`] to zero it out instead.
+pub struct Orphan<T> {
+    builder: OrphanBuilder,
+    marker: PhantomData<T>,
+}
+
+// The orphan's memory can only be accessed through an `Orphanage`, which mutably borrows
+// the message, or by adopting it into a builder for the message.
+unsafe impl<T> Send for Orphan<T> {}
+
+impl<T: Owned> Orphan<T> {
+    pub fn new(builder: OrphanBuilder) -> Self {
+        Self {
+            builder,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn into_internal(self) -> OrphanBuilder {
+        self.builder
+    }
+
+    /// Returns true if the orphan holds a null pointer. `orphanage` must belong to the message
+    /// that the orphan came from.
+    pub fn is_null(&self, orphanage: Orphanage<'_>) -> bool {
+        self.builder.as_pointer_builder(orphanage.arena).is_null()
+    }
+
+    /// Forgets the type of the orphan, so that it can be adopted into an `AnyPointer` field.
+    pub fn into_any_pointer(self) -> Orphan<crate::any_pointer::Owned> {
+        Orphan::new(self.builder)
+    }
+
+    /// Gets a builder for the orphaned object. `orphanage` must belong to the message that
+    /// the orphan came from.
+    pub fn get<'a>(&'a mut self, orphanage: Orphanage<'a>) -> Result<T::Builder<'a>> {
+        FromPointerBuilder::get_from_pointer(self.builder.as_pointer_builder(orphanage.arena), None)
+    }
+
+    /// Gets a reader for the orphaned object. `orphanage` must belong to the message that
+    /// the orphan came from.
+    pub fn get_reader<'a>(&'a self, orphanage: Orphanage<'a>) -> Result<T::Reader<'a>> {
+        let reader = self
+            .builder
+            .as_pointer_builder(orphanage.arena)
+            .into_reader();
+        FromPointerReader::get_from_pointer(&reader, None)
+    }
+
+    /// Zeroes out the orphaned object.
+    pub fn discard(self, orphanage: Orphanage<'_>) {
+        self.builder.as_pointer_builder(orphanage.arena).clear()
+    }
+}
+
+impl Orphan<crate::any_pointer::Owned> {
+    /// Reinterprets the orphan as holding a value of type `T`.
+    pub fn into_typed<T: Owned>(self) -> Orphan<T> {
+        Orphan::new(self.builder)
+    }
+}
+
+/// Creates orphans in a message. Obtained from
+/// [`message::Builder::get_orphanage()`](crate::message::Builder::get_orphanage).
+pub struct Orphanage<'a> {
+    arena: &'a mut dyn BuilderArena,
+    cap_table: CapTableBuilder,
+}
+
+impl<'a> Orphanage<'a> {
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(arena: &'a mut dyn BuilderArena) -> Self {
+        Self {
+            arena,
+            cap_table: Default::default(),
+        }
+    }
+
+    pub fn reborrow(&mut self) -> Orphanage<'_> {
+        Orphanage {
+            arena: self.arena,
+            cap_table: self.cap_table,
+        }
+    }
+
+    fn new_orphan_builder(&mut self) -> Result<OrphanBuilder> {
+        OrphanBuilder::new(self.arena, 0, self.cap_table)
+    }
+
+    /// Creates an orphan holding a newly-initialized value of type `T`.
+    pub fn init_orphan<T: Owned>(&mut self) -> Orphan<T> {
+        self.initn_orphan(0)
+    }
+
+    /// Creates an orphan holding a newly-initialized list, text or data value of type `T`,
+    /// with the given length.
+    pub fn initn_orphan<T: Owned>(&mut self, length: u32) -> Orphan<T> {
+        let builder = self.new_orphan_builder().unwrap_or_else(|e| panic!("{e}"));
+        let _: T::Builder<'_> =
+            FromPointerBuilder::init_pointer(builder.as_pointer_builder(self.arena), length);
+        Orphan::new(builder)
+    }
+
+    /// Like `init_orphan()`, but returns an error instead of panicking if the message cannot
+    /// allocate the space.
+    pub fn try_init_orphan<T: Owned>(&mut self) -> Result<Orphan<T>> {
+        self.try_initn_orphan(0)
+    }
+
+    /// Like `initn_orphan()`, but returns an error instead of panicking if the message cannot
+    /// allocate the space.
+    pub fn try_initn_orphan<T: Owned>(&mut self, length: u32) -> Result<Orphan<T>> {
+        let builder = self.new_orphan_builder()?;
+        let _: T::Builder<'_> =
+            FromPointerBuilder::try_init_pointer(builder.as_pointer_builder(self.arena), length)?;
+        Ok(Orphan::new(builder))
+    }
+
+    /// Creates an orphan holding a deep copy of `value`.
+    pub fn new_orphan_copy<T: Owned>(&mut self, value: T::Reader<'_>) -> Result<Orphan<T>> {
+        let builder = self.new_orphan_builder()?;
+        SetPointerBuilder::set_pointer_builder(
+            builder.as_pointer_builder(self.arena),
+            value,
+            false,
+        )?;
+        Ok(Orphan::new(builder))
+    }
+}
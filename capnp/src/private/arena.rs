@@ -21,14 +21,20 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 #[cfg(feature = "alloc")]
+use core::mem;
+#[cfg(feature = "alloc")]
 use core::slice;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
 use core::u64;
 
 use crate::message;
 #[cfg(feature = "alloc")]
 use crate::message::Allocator;
 use crate::message::ReaderSegments;
-use crate::private::read_limiter::ReadLimiter;
+#[cfg(feature = "alloc")]
+use crate::private::layout;
+use crate::private::read_limiter::{ReadLimit, ReadLimiter};
 use crate::private::units::*;
 #[cfg(feature = "alloc")]
 use crate::OutputSegments;
@@ -56,9 +62,9 @@ pub trait ReaderArena {
     //   layout::StructReader, layout::ListReader, etc. could drop their `cap_table` fields.
 }
 
-pub struct ReaderArenaImpl<S> {
+pub struct ReaderArenaImpl<S, L = ReadLimiter> {
     segments: S,
-    read_limiter: ReadLimiter,
+    read_limiter: L,
     nesting_limit: i32,
 }
 
@@ -70,12 +76,17 @@ fn _assert_sync() {
     }
 }
 
-impl<S> ReaderArenaImpl<S>
+/// A reader arena whose read limiter can be shared between threads.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub type SyncReaderArenaImpl<S> = ReaderArenaImpl<S, crate::private::read_limiter::SyncReadLimiter>;
+
+impl<S, L> ReaderArenaImpl<S, L>
 where
     S: ReaderSegments,
+    L: ReadLimit,
 {
     pub fn new(segments: S, options: message::ReaderOptions) -> Self {
-        let limiter = ReadLimiter::new(options.traversal_limit_in_words);
+        let limiter = L::new(options.traversal_limit_in_words);
         Self {
             segments,
             read_limiter: limiter,
@@ -86,11 +97,16 @@ where
     pub fn into_segments(self) -> S {
         self.segments
     }
+
+    pub fn segments(&self) -> &S {
+        &self.segments
+    }
 }
 
-impl<S> ReaderArena for ReaderArenaImpl<S>
+impl<S, L> ReaderArena for ReaderArenaImpl<S, L>
 where
     S: ReaderSegments,
+    L: ReadLimit,
 {
     fn get_segment(&self, id: u32) -> Result<(*const u8, u32)> {
         match self.segments.get_segment(id) {
@@ -156,10 +172,15 @@ where
 
 pub trait BuilderArena: ReaderArena {
     fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Option<u32>;
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32);
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)>;
     fn get_segment_mut(&mut self, id: u32) -> (*mut u8, u32);
 
     fn as_reader(&self) -> &dyn ReaderArena;
+
+    /// Returns a value that distinguishes this arena from every other arena created by this
+    /// process. Orphans record it so that they can only be adopted into their own message.
+    #[cfg(target_has_atomic = "ptr")]
+    fn id(&self) -> usize;
 }
 
 /// A wrapper around a memory segment used in building a message.
@@ -193,6 +214,23 @@ where
     A: Allocator,
 {
     inner: BuilderArenaImplInner<A>,
+    #[cfg(target_has_atomic = "ptr")]
+    id: usize,
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+static NEXT_BUILDER_ARENA_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a new arena ID. IDs are never reused, since an orphan from a dropped arena must not
+/// be accepted by a later one, so this panics once they run out.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+fn next_builder_arena_id() -> usize {
+    match NEXT_BUILDER_ARENA_ID
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| id.checked_add(1))
+    {
+        Ok(id) => id,
+        Err(_) => panic!("ran out of message builder IDs"),
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -206,6 +244,8 @@ where
                 allocator: Some(allocator),
                 segments: Vec::new(),
             },
+            #[cfg(target_has_atomic = "ptr")]
+            id: next_builder_arena_id(),
         }
     }
 
@@ -250,6 +290,74 @@ where
         self.len() == 0
     }
 
+    /// Copies everything reachable from the root pointer into a fresh segment and deallocates
+    /// the old segments. Returns the number of words that are no longer in use. If copying
+    /// fails, the arena is left as it was.
+    ///
+    /// Afterwards the arena has a new ID, so that orphans pointing into the old segments can
+    /// no longer be used.
+    pub fn compact(&mut self) -> Result<usize> {
+        if self.inner.segments.is_empty() {
+            return Ok(0);
+        }
+        let old_segments = mem::take(&mut self.inner.segments);
+        let result = self.copy_root_from(&old_segments);
+        let to_deallocate = match result {
+            Ok(()) => {
+                #[cfg(target_has_atomic = "ptr")]
+                {
+                    self.id = next_builder_arena_id();
+                }
+                old_segments
+            }
+            Err(_) => mem::replace(&mut self.inner.segments, old_segments),
+        };
+        let old_words: usize = to_deallocate.iter().map(|s| s.allocated as usize).sum();
+        if let Some(a) = &mut self.inner.allocator {
+            for seg in &to_deallocate {
+                unsafe {
+                    a.deallocate_segment(seg.ptr, seg.capacity, seg.allocated);
+                }
+            }
+        }
+        result?;
+        let new_words: usize = self
+            .inner
+            .segments
+            .iter()
+            .map(|s| s.allocated as usize)
+            .sum();
+        Ok(old_words.saturating_sub(new_words))
+    }
+
+    fn copy_root_from(&mut self, old_segments: &[BuilderSegment]) -> Result<()> {
+        let slices: Vec<&[u8]> = old_segments
+            .iter()
+            .map(|seg| unsafe {
+                slice::from_raw_parts(seg.ptr as *const _, seg.allocated as usize * BYTES_PER_WORD)
+            })
+            .collect();
+        let segment_array = message::SegmentArray::new(&slices);
+        let reader_arena = ReaderArenaImpl::<_>::new(
+            segment_array,
+            message::ReaderOptions {
+                traversal_limit_in_words: None,
+                nesting_limit: i32::MAX,
+            },
+        );
+        let root = layout::PointerReader::get_root(&reader_arena, 0, slices[0].as_ptr(), i32::MAX)?;
+        let root_words = root.total_size()?.word_count;
+        let Ok(minimum_size) = u32::try_from(root_words + 1) else {
+            return Err(Error::from_kind(ErrorKind::MessageTooLarge(
+                root_words as usize + 1,
+            )));
+        };
+        self.inner.allocate_segment(minimum_size)?;
+        self.inner.allocate(0, 1).expect("allocate root pointer");
+        let (seg_start, _seg_len) = self.inner.get_segment_mut(0);
+        layout::PointerBuilder::get_root(self, 0, seg_start).copy_from(root, false)
+    }
+
     /// Retrieves the underlying `Allocator`, deallocating all currently-allocated
     /// segments.
     pub fn into_allocator(mut self) -> A {
@@ -298,7 +406,7 @@ where
     /// Allocates a new segment with capacity for at least `minimum_size` words.
     fn allocate_segment(&mut self, minimum_size: WordCount32) -> Result<()> {
         let seg = match &mut self.allocator {
-            Some(a) => a.allocate_segment(minimum_size),
+            Some(a) => a.try_allocate_segment(minimum_size)?,
             None => unreachable!(),
         };
         self.segments.push(BuilderSegment {
@@ -320,23 +428,23 @@ where
         }
     }
 
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32) {
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)> {
         // first try the existing segments, then try allocating a new segment.
         let allocated_len = self.segments.len() as u32;
         for segment_id in 0..allocated_len {
             if let Some(idx) = self.allocate(segment_id, amount) {
-                return (segment_id, idx);
+                return Ok((segment_id, idx));
             }
         }
 
         // Need to allocate a new segment.
 
-        self.allocate_segment(amount).expect("allocate new segment");
-        (
+        self.allocate_segment(amount)?;
+        Ok((
             allocated_len,
             self.allocate(allocated_len, amount)
                 .expect("use freshly-allocated segment"),
-        )
+        ))
     }
 
     fn deallocate_all(&mut self) {
@@ -364,7 +472,7 @@ where
         self.inner.allocate(segment_id, amount)
     }
 
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32) {
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)> {
         self.inner.allocate_anywhere(amount)
     }
 
@@ -375,6 +483,11 @@ where
     fn as_reader(&self) -> &dyn ReaderArena {
         self
     }
+
+    #[cfg(target_has_atomic = "ptr")]
+    fn id(&self) -> usize {
+        self.id
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -415,7 +415,7 @@ mod wire_helpers {
         segment_id: u32,
         amount: WordCount32,
         kind: WirePointerKind,
-    ) -> (*mut u8, *mut WirePointer, u32) {
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
         let is_null = (*reff).is_null();
         if !is_null {
             zero_object(arena, segment_id, reff)
@@ -423,7 +423,7 @@ mod wire_helpers {
 
         if amount == 0 && kind == WirePointerKind::Struct {
             (*reff).set_kind_and_target_for_empty_struct();
-            return (reff as *mut _, reff, segment_id);
+            return Ok((reff as *mut _, reff, segment_id));
         }
 
         match arena.allocate(segment_id, amount) {
@@ -433,7 +433,14 @@ mod wire_helpers {
                 //# the landing pad for a far pointer.
 
                 let amount_plus_ref = amount + POINTER_SIZE_IN_WORDS as u32;
-                let (segment_id, word_idx) = arena.allocate_anywhere(amount_plus_ref);
+                let (segment_id, word_idx) = match arena.allocate_anywhere(amount_plus_ref) {
+                    Ok(r) => r,
+                    Err(e) => {
+                        // The old object is gone, so leave a null pointer behind.
+                        ptr::write_bytes(reff, 0, 1);
+                        return Err(e);
+                    }
+                };
                 let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
                 let ptr = seg_start.offset(word_idx as isize * BYTES_PER_WORD as isize);
 
@@ -448,13 +455,37 @@ mod wire_helpers {
 
                 let ptr1 = ptr.add(BYTES_PER_WORD);
                 (*reff).set_kind_and_target(kind, ptr1);
-                (ptr1, reff, segment_id)
+                Ok((ptr1, reff, segment_id))
             }
             Some(idx) => {
                 let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
                 let ptr = (seg_start).offset(idx as isize * BYTES_PER_WORD as isize);
                 (*reff).set_kind_and_target(kind, ptr);
-                (ptr, reff, segment_id)
+                Ok((ptr, reff, segment_id))
+            }
+        }
+    }
+
+    /// Like `allocate()`, but for moving an object that is being upgraded to a larger size.
+    /// The old object is neither zeroed nor released, and if the allocation fails `reff` is
+    /// left pointing at it.
+    unsafe fn reallocate(
+        arena: &mut dyn BuilderArena,
+        reff: *mut WirePointer,
+        segment_id: u32,
+        amount: WordCount32,
+        kind: WirePointerKind,
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
+        let mut old_pointer = ptr::read(reff);
+        ptr::write_bytes(reff, 0, 1);
+        match allocate(arena, reff, segment_id, amount, kind) {
+            Ok(result) => {
+                zero_pointer_and_fars(arena, segment_id, &mut old_pointer)?;
+                Ok(result)
+            }
+            Err(e) => {
+                ptr::write(reff, old_pointer);
+                Err(e)
             }
         }
     }
@@ -843,7 +874,7 @@ mod wire_helpers {
         src: *const u8,
         data_size: isize,
         pointer_count: isize,
-    ) {
+    ) -> Result<()> {
         ptr::copy_nonoverlapping(src, dst, data_size as usize * BYTES_PER_WORD);
 
         let src_refs: *const WirePointer = (src as *const WirePointer).offset(data_size);
@@ -856,8 +887,9 @@ mod wire_helpers {
                 cap_table,
                 dst_refs.offset(ii),
                 src_refs.offset(ii),
-            );
+            )?;
         }
+        Ok(())
     }
 
     // Copies from a trusted message.
@@ -868,12 +900,12 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         dst: *mut WirePointer,
         src: *const WirePointer,
-    ) -> (*mut u8, *mut WirePointer, u32) {
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
         match (*src).kind() {
             WirePointerKind::Struct => {
                 if (*src).is_null() {
                     ptr::write_bytes(dst, 0, 1);
-                    (ptr::null_mut(), dst, segment_id)
+                    Ok((ptr::null_mut(), dst, segment_id))
                 } else {
                     let src_ptr = WirePointer::target(src);
                     let (dst_ptr, dst, segment_id) = allocate(
@@ -882,7 +914,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).struct_word_size(),
                         WirePointerKind::Struct,
-                    );
+                    )?;
                     copy_struct(
                         arena,
                         segment_id,
@@ -891,12 +923,12 @@ mod wire_helpers {
                         src_ptr,
                         (*src).struct_data_size() as isize,
                         (*src).struct_ptr_count() as isize,
-                    );
+                    )?;
                     (*dst).set_struct_size_from_pieces(
                         (*src).struct_data_size(),
                         (*src).struct_ptr_count(),
                     );
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
             }
             WirePointerKind::List => match (*src).list_element_size() {
@@ -912,7 +944,7 @@ mod wire_helpers {
                     );
                     let src_ptr = WirePointer::target(src);
                     let (dst_ptr, dst, segment_id) =
-                        allocate(arena, dst, segment_id, word_count, WirePointerKind::List);
+                        allocate(arena, dst, segment_id, word_count, WirePointerKind::List)?;
                     ptr::copy_nonoverlapping(
                         src_ptr,
                         dst_ptr,
@@ -922,7 +954,7 @@ mod wire_helpers {
                         (*src).list_element_size(),
                         (*src).list_element_count(),
                     );
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
 
                 ElementSize::Pointer => {
@@ -933,7 +965,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).list_element_count(),
                         WirePointerKind::List,
-                    );
+                    )?;
                     for ii in 0..((*src).list_element_count() as isize) {
                         copy_message(
                             arena,
@@ -941,11 +973,11 @@ mod wire_helpers {
                             cap_table,
                             dst_refs.offset(ii * BYTES_PER_WORD as isize) as *mut WirePointer,
                             src_refs.offset(ii),
-                        );
+                        )?;
                     }
                     (*dst)
                         .set_list_size_and_count(ElementSize::Pointer, (*src).list_element_count());
-                    (dst_refs, dst, segment_id)
+                    Ok((dst_refs, dst, segment_id))
                 }
                 ElementSize::InlineComposite => {
                     let src_ptr = WirePointer::target(src);
@@ -955,7 +987,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).list_inline_composite_word_count() + 1,
                         WirePointerKind::List,
-                    );
+                    )?;
 
                     (*dst).set_list_inline_composite((*src).list_inline_composite_word_count());
 
@@ -977,7 +1009,7 @@ mod wire_helpers {
                             src_element,
                             (*src_tag).struct_data_size() as isize,
                             (*src_tag).struct_ptr_count() as isize,
-                        );
+                        )?;
                         src_element = src_element.offset(
                             BYTES_PER_WORD as isize * (*src_tag).struct_word_size() as isize,
                         );
@@ -985,7 +1017,7 @@ mod wire_helpers {
                             BYTES_PER_WORD as isize * (*src_tag).struct_word_size() as isize,
                         );
                     }
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
             },
             WirePointerKind::Other => {
@@ -1003,7 +1035,7 @@ mod wire_helpers {
         dst: *mut WirePointer,
         src_segment_id: u32,
         src: *mut WirePointer,
-    ) {
+    ) -> Result<()> {
         //# Make *dst point to the same object as *src. Both must
         //# reside in the same message, but can be in different
         //# segments. Not always-inline because this is rarely used.
@@ -1027,10 +1059,11 @@ mod wire_helpers {
                 src_segment_id,
                 src,
                 WirePointer::mut_target(src),
-            );
+            )?;
         } else {
             ptr::copy_nonoverlapping(src, dst, 1);
         }
+        Ok(())
     }
 
     pub unsafe fn transfer_pointer_split(
@@ -1040,7 +1073,7 @@ mod wire_helpers {
         src_segment_id: u32,
         src_tag: *mut WirePointer,
         src_ptr: *mut u8,
-    ) {
+    ) -> Result<()> {
         // Like the other transfer_pointer, but splits src into a tag and a
         // target. Particularly useful for OrphanBuilder.
 
@@ -1061,7 +1094,7 @@ mod wire_helpers {
             match arena.allocate(src_segment_id, 1) {
                 None => {
                     //# Darn, need a double-far.
-                    let (far_segment_id, word_idx) = arena.allocate_anywhere(2);
+                    let (far_segment_id, word_idx) = arena.allocate_anywhere(2)?;
                     let (seg_start, _seg_len) = arena.get_segment_mut(far_segment_id);
                     let landing_pad: *mut WirePointer =
                         (seg_start as *mut WirePointer).offset(word_idx as isize);
@@ -1104,6 +1137,7 @@ mod wire_helpers {
                 }
             }
         }
+        Ok(())
     }
 
     #[inline]
@@ -1113,17 +1147,17 @@ mod wire_helpers {
         segment_id: u32,
         cap_table: CapTableBuilder,
         size: StructSize,
-    ) -> StructBuilder<'_> {
+    ) -> Result<StructBuilder<'_>> {
         let (ptr, reff, segment_id) = allocate(
             arena,
             reff,
             segment_id,
             size.total(),
             WirePointerKind::Struct,
-        );
+        )?;
         (*reff).set_struct_size(size);
 
-        StructBuilder {
+        Ok(StructBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1131,7 +1165,7 @@ mod wire_helpers {
             pointers: ptr.offset((size.data as usize) as isize * BYTES_PER_WORD as isize) as *mut _,
             data_size: u32::from(size.data) * (BITS_PER_WORD as BitCount32),
             pointer_count: size.pointers,
-        }
+        })
     }
 
     #[inline]
@@ -1147,15 +1181,9 @@ mod wire_helpers {
 
         if (*reff).is_null() {
             match default {
-                None => {
-                    return Ok(init_struct_pointer(
-                        arena, reff, segment_id, cap_table, size,
-                    ))
-                }
+                None => return init_struct_pointer(arena, reff, segment_id, cap_table, size),
                 Some(d) if (*(d.as_ptr() as *const WirePointer)).is_null() => {
-                    return Ok(init_struct_pointer(
-                        arena, reff, segment_id, cap_table, size,
-                    ))
+                    return init_struct_pointer(arena, reff, segment_id, cap_table, size)
                 }
                 Some(d) => {
                     let (new_ref_target, new_reff, new_segment_id) = copy_message(
@@ -1164,7 +1192,7 @@ mod wire_helpers {
                         cap_table,
                         reff,
                         d.as_ptr() as *const WirePointer,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     ref_target = new_ref_target;
@@ -1198,10 +1226,8 @@ mod wire_helpers {
                 u32::from(new_data_size) + u32::from(new_pointer_count) * WORDS_PER_POINTER as u32;
 
             //# Don't let allocate() zero out the object just yet.
-            zero_pointer_and_fars(arena, segment_id, reff)?;
-
             let (ptr, reff, segment_id) =
-                allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct);
+                reallocate(arena, reff, segment_id, total_size, WirePointerKind::Struct)?;
             (*reff).set_struct_size_from_pieces(new_data_size, new_pointer_count);
 
             // Copy data section.
@@ -1218,7 +1244,7 @@ mod wire_helpers {
                     new_pointer_section.offset(i),
                     old_segment_id,
                     old_pointer_section.offset(i),
-                );
+                )?;
             }
 
             ptr::write_bytes(
@@ -1257,7 +1283,7 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         element_count: ElementCount32,
         element_size: ElementSize,
-    ) -> ListBuilder<'_> {
+    ) -> Result<ListBuilder<'_>> {
         assert!(
             element_size != InlineComposite,
             "Should have called initStructListPointer() instead"
@@ -1268,11 +1294,11 @@ mod wire_helpers {
         let step = data_size + pointer_count * BITS_PER_POINTER as u32;
         let word_count = round_bits_up_to_words(u64::from(element_count) * u64::from(step));
         let (ptr, reff, segment_id) =
-            allocate(arena, reff, segment_id, word_count, WirePointerKind::List);
+            allocate(arena, reff, segment_id, word_count, WirePointerKind::List)?;
 
         (*reff).set_list_size_and_count(element_size, element_count);
 
-        ListBuilder {
+        Ok(ListBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1282,7 +1308,7 @@ mod wire_helpers {
             element_size,
             struct_data_size: data_size,
             struct_pointer_count: pointer_count as u16,
-        }
+        })
     }
 
     #[inline]
@@ -1293,7 +1319,7 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         element_count: ElementCount32,
         element_size: StructSize,
-    ) -> ListBuilder<'_> {
+    ) -> Result<ListBuilder<'_>> {
         let words_per_element = element_size.total();
 
         //# Allocate the list, prefixed by a single WirePointer.
@@ -1304,7 +1330,7 @@ mod wire_helpers {
             segment_id,
             POINTER_SIZE_IN_WORDS as u32 + word_count,
             WirePointerKind::List,
-        );
+        )?;
         let ptr = ptr as *mut WirePointer;
 
         //# Initialize the pointer.
@@ -1317,7 +1343,7 @@ mod wire_helpers {
 
         let ptr1 = ptr.add(POINTER_SIZE_IN_WORDS);
 
-        ListBuilder {
+        Ok(ListBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1327,7 +1353,7 @@ mod wire_helpers {
             element_size: ElementSize::InlineComposite,
             struct_data_size: u32::from(element_size.data) * (BITS_PER_WORD as u32),
             struct_pointer_count: element_size.pointers,
-        }
+        })
     }
 
     #[inline]
@@ -1356,7 +1382,7 @@ mod wire_helpers {
                 cap_table,
                 orig_ref,
                 default_value as *const WirePointer,
-            );
+            )?;
             orig_ref_target = new_orig_ref_target;
             orig_ref = new_orig_ref;
             orig_segment_id = new_orig_segment_id;
@@ -1486,7 +1512,7 @@ mod wire_helpers {
                 cap_table,
                 orig_ref,
                 default_value as *const WirePointer,
-            );
+            )?;
             orig_ref_target = new_orig_ref_target;
             orig_ref = new_orig_ref;
             orig_segment_id = new_orig_segment_id;
@@ -1545,15 +1571,13 @@ mod wire_helpers {
             let total_size = new_step * element_count;
 
             // Don't let allocate() zero out the object just yet.
-            zero_pointer_and_fars(arena, orig_segment_id, orig_ref)?;
-
-            let (mut new_ptr, new_ref, new_segment_id) = allocate(
+            let (mut new_ptr, new_ref, new_segment_id) = reallocate(
                 arena,
                 orig_ref,
                 orig_segment_id,
                 total_size + POINTER_SIZE_IN_WORDS as u32,
                 WirePointerKind::List,
-            );
+            )?;
             (*new_ref).set_list_inline_composite(total_size);
 
             let new_tag: *mut WirePointer = new_ptr as *mut _;
@@ -1580,7 +1604,7 @@ mod wire_helpers {
                         new_pointer_section.offset(jj),
                         old_segment_id,
                         old_pointer_section.offset(jj),
-                    );
+                    )?;
                 }
 
                 dst = dst.offset(new_step as isize);
@@ -1614,14 +1638,14 @@ mod wire_helpers {
 
             if old_size == ElementSize::Void {
                 // Nothing to copy, just allocate a new list.
-                Ok(init_struct_list_pointer(
+                init_struct_list_pointer(
                     arena,
                     orig_ref,
                     orig_segment_id,
                     cap_table,
                     element_count,
                     element_size,
-                ))
+                )
             } else {
                 // Upgrade to an inline composite list.
 
@@ -1646,15 +1670,13 @@ mod wire_helpers {
                 let total_words = element_count * new_step;
 
                 // Don't let allocate() zero out the object just yet.
-                zero_pointer_and_fars(arena, orig_segment_id, orig_ref)?;
-
-                let (mut new_ptr, new_ref, new_segment_id) = allocate(
+                let (mut new_ptr, new_ref, new_segment_id) = reallocate(
                     arena,
                     orig_ref,
                     orig_segment_id,
                     total_words + POINTER_SIZE_IN_WORDS as u32,
                     WirePointerKind::List,
-                );
+                )?;
                 (*new_ref).set_list_inline_composite(total_words);
 
                 let tag: *mut WirePointer = new_ptr as *mut _;
@@ -1669,7 +1691,13 @@ mod wire_helpers {
                     let mut dst = new_ptr.offset(new_data_size as isize * BYTES_PER_WORD as isize);
                     let mut src: *mut WirePointer = old_ptr as *mut _;
                     for _ in 0..element_count {
-                        transfer_pointer(arena, new_segment_id, dst as *mut _, old_segment_id, src);
+                        transfer_pointer(
+                            arena,
+                            new_segment_id,
+                            dst as *mut _,
+                            old_segment_id,
+                            src,
+                        )?;
                         dst = dst.offset(new_step as isize * BYTES_PER_WORD as isize);
                         src = src.offset(1);
                     }
@@ -1712,7 +1740,7 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         size: ByteCount32,
-    ) -> SegmentAnd<text::Builder<'_>> {
+    ) -> Result<SegmentAnd<text::Builder<'_>>> {
         //# The byte list must include a NUL terminator.
         let byte_size = size + 1;
 
@@ -1723,15 +1751,15 @@ mod wire_helpers {
             segment_id,
             round_bytes_up_to_words(byte_size),
             WirePointerKind::List,
-        );
+        )?;
 
         //# Initialize the pointer.
         (*reff).set_list_size_and_count(Byte, byte_size);
 
-        SegmentAnd {
+        Ok(SegmentAnd {
             segment_id,
             value: text::Builder::new(slice::from_raw_parts_mut(ptr, size as usize)),
-        }
+        })
     }
 
     #[inline]
@@ -1740,16 +1768,16 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         value: crate::text::Reader<'_>,
-    ) -> SegmentAnd<text::Builder<'a>> {
+    ) -> Result<SegmentAnd<text::Builder<'a>>> {
         let value_bytes = value.as_bytes();
         // TODO make sure the string is not longer than 2 ** 29.
-        let mut allocation = init_text_pointer(arena, reff, segment_id, value_bytes.len() as u32);
+        let mut allocation = init_text_pointer(arena, reff, segment_id, value_bytes.len() as u32)?;
         allocation
             .value
             .reborrow()
             .as_bytes_mut()
             .copy_from_slice(value_bytes);
-        allocation
+        Ok(allocation)
     }
 
     #[inline]
@@ -1769,7 +1797,7 @@ mod wire_helpers {
                         Default::default(),
                         reff,
                         d.as_ptr() as *const _,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     new_ref_target
@@ -1808,7 +1836,7 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         size: ByteCount32,
-    ) -> SegmentAnd<data::Builder<'_>> {
+    ) -> Result<SegmentAnd<data::Builder<'_>>> {
         //# Allocate the space.
         let (ptr, reff, segment_id) = allocate(
             arena,
@@ -1816,15 +1844,15 @@ mod wire_helpers {
             segment_id,
             round_bytes_up_to_words(size),
             WirePointerKind::List,
-        );
+        )?;
 
         //# Initialize the pointer.
         (*reff).set_list_size_and_count(Byte, size);
 
-        SegmentAnd {
+        Ok(SegmentAnd {
             segment_id,
             value: data::builder_from_raw_parts(ptr, size),
-        }
+        })
     }
 
     #[inline]
@@ -1833,10 +1861,10 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         value: &[u8],
-    ) -> SegmentAnd<data::Builder<'a>> {
-        let allocation = init_data_pointer(arena, reff, segment_id, value.len() as u32);
+    ) -> Result<SegmentAnd<data::Builder<'a>>> {
+        let allocation = init_data_pointer(arena, reff, segment_id, value.len() as u32)?;
         ptr::copy_nonoverlapping(value.as_ptr(), allocation.value.as_mut_ptr(), value.len());
-        allocation
+        Ok(allocation)
     }
 
     #[inline]
@@ -1856,7 +1884,7 @@ mod wire_helpers {
                         Default::default(),
                         reff,
                         d.as_ptr() as *const _,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     new_ref_target
@@ -1891,48 +1919,20 @@ mod wire_helpers {
         value: StructReader,
         canonicalize: bool,
     ) -> Result<SegmentAnd<*mut u8>> {
-        let mut data_size: ByteCount32 = round_bits_up_to_bytes(u64::from(value.data_size));
-        let mut ptr_count = value.pointer_count;
-
-        if canonicalize {
-            // StructReaders should not have bitwidths other than 1, but let's be safe
-            if !(value.data_size == 1 || value.data_size % BITS_PER_BYTE as u32 == 0) {
-                return Err(Error::from_kind(
-                    ErrorKind::StructReaderHadBitwidthOtherThan1,
-                ));
-            }
-
-            if value.data_size == 1 {
-                if !value.get_bool_field(0) {
-                    data_size = 0;
-                }
-            } else {
-                'chop: while data_size != 0 {
-                    let end = data_size;
-                    let mut window = data_size % BYTES_PER_WORD as u32;
-                    if window == 0 {
-                        window = BYTES_PER_WORD as u32;
-                    }
-                    let start = end - window;
-                    let last_word = &value.get_data_section_as_blob()[start as usize..end as usize];
-                    if last_word == [0; 8] {
-                        data_size -= window;
-                    } else {
-                        break 'chop;
-                    }
-                }
-            }
-
-            while ptr_count != 0 && value.get_pointer_field(ptr_count as usize - 1).is_null() {
-                ptr_count -= 1;
-            }
-        }
+        let (data_size, ptr_count) = if canonicalize {
+            value.canonical_section_sizes()?
+        } else {
+            (
+                round_bits_up_to_bytes(u64::from(value.data_size)),
+                value.pointer_count,
+            )
+        };
 
         let data_words = round_bytes_up_to_words(data_size);
         let total_size: WordCount32 = data_words + u32::from(ptr_count) * WORDS_PER_POINTER as u32;
 
         let (ptr, reff, segment_id) =
-            allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct);
+            allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct)?;
         (*reff).set_struct_size_from_pieces(data_words as u16, ptr_count);
 
         if value.data_size == 1 {
@@ -1995,7 +1995,7 @@ mod wire_helpers {
         if value.element_size != ElementSize::InlineComposite {
             //# List of non-structs.
             let (ptr, reff, segment_id) =
-                allocate(arena, reff, segment_id, total_size, WirePointerKind::List);
+                allocate(arena, reff, segment_id, total_size, WirePointerKind::List)?;
 
             if value.struct_pointer_count == 1 {
                 //# List of pointers.
@@ -2057,39 +2057,12 @@ mod wire_helpers {
             let decl_data_size = value.struct_data_size / BITS_PER_WORD as u32;
             let decl_pointer_count = value.struct_pointer_count;
 
-            let mut data_size = 0;
-            let mut ptr_count = 0;
+            let data_size;
+            let ptr_count;
             let mut total_size = total_size;
 
             if canonicalize {
-                for ec in 0..value.element_count {
-                    let se = value.get_struct_element(ec);
-                    let mut local_data_size = decl_data_size;
-                    'data_chop: while local_data_size != 0 {
-                        let end = local_data_size * BYTES_PER_WORD as u32;
-                        let window = BYTES_PER_WORD as u32;
-                        let start = end - window;
-                        let last_word =
-                            &se.get_data_section_as_blob()[start as usize..end as usize];
-                        if last_word != [0; 8] {
-                            break 'data_chop;
-                        } else {
-                            local_data_size -= 1;
-                        }
-                    }
-                    if local_data_size > data_size {
-                        data_size = local_data_size;
-                    }
-                    let mut local_ptr_count = decl_pointer_count;
-                    while local_ptr_count != 0
-                        && se.get_pointer_field(local_ptr_count as usize - 1).is_null()
-                    {
-                        local_ptr_count -= 1;
-                    }
-                    if local_ptr_count > ptr_count {
-                        ptr_count = local_ptr_count;
-                    }
-                }
+                (data_size, ptr_count) = value.canonical_element_sizes();
                 total_size = (data_size + u32::from(ptr_count)) * value.element_count;
             } else {
                 data_size = decl_data_size;
@@ -2102,7 +2075,7 @@ mod wire_helpers {
                 segment_id,
                 total_size + POINTER_SIZE_IN_WORDS as u32,
                 WirePointerKind::List,
-            );
+            )?;
             (*reff).set_list_inline_composite(total_size);
 
             let tag: *mut WirePointer = ptr as *mut _;
@@ -3199,6 +3172,10 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn init_struct(self, size: StructSize) -> StructBuilder<'a> {
+        self.try_init_struct(size).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_init_struct(self, size: StructSize) -> Result<StructBuilder<'a>> {
         unsafe {
             wire_helpers::init_struct_pointer(
                 self.arena,
@@ -3215,6 +3192,15 @@ impl<'a> PointerBuilder<'a> {
         element_size: ElementSize,
         element_count: ElementCount32,
     ) -> ListBuilder<'a> {
+        self.try_init_list(element_size, element_count)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_init_list(
+        self,
+        element_size: ElementSize,
+        element_count: ElementCount32,
+    ) -> Result<ListBuilder<'a>> {
         unsafe {
             wire_helpers::init_list_pointer(
                 self.arena,
@@ -3232,6 +3218,15 @@ impl<'a> PointerBuilder<'a> {
         element_count: ElementCount32,
         element_size: StructSize,
     ) -> ListBuilder<'a> {
+        self.try_init_struct_list(element_count, element_size)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_init_struct_list(
+        self,
+        element_count: ElementCount32,
+        element_size: StructSize,
+    ) -> Result<ListBuilder<'a>> {
         unsafe {
             wire_helpers::init_struct_list_pointer(
                 self.arena,
@@ -3245,14 +3240,28 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn init_text(self, size: ByteCount32) -> text::Builder<'a> {
+        self.try_init_text(size).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_init_text(self, size: ByteCount32) -> Result<text::Builder<'a>> {
         unsafe {
-            wire_helpers::init_text_pointer(self.arena, self.pointer, self.segment_id, size).value
+            Ok(
+                wire_helpers::init_text_pointer(self.arena, self.pointer, self.segment_id, size)?
+                    .value,
+            )
         }
     }
 
     pub fn init_data(self, size: ByteCount32) -> data::Builder<'a> {
+        self.try_init_data(size).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_init_data(self, size: ByteCount32) -> Result<data::Builder<'a>> {
         unsafe {
-            wire_helpers::init_data_pointer(self.arena, self.pointer, self.segment_id, size).value
+            Ok(
+                wire_helpers::init_data_pointer(self.arena, self.pointer, self.segment_id, size)?
+                    .value,
+            )
         }
     }
 
@@ -3285,15 +3294,25 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn set_text(&mut self, value: crate::text::Reader<'_>) {
+        self.try_set_text(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_set_text(&mut self, value: crate::text::Reader<'_>) -> Result<()> {
         unsafe {
-            wire_helpers::set_text_pointer(self.arena, self.pointer, self.segment_id, value);
+            wire_helpers::set_text_pointer(self.arena, self.pointer, self.segment_id, value)?;
         }
+        Ok(())
     }
 
     pub fn set_data(&mut self, value: &[u8]) {
+        self.try_set_data(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_set_data(&mut self, value: &[u8]) -> Result<()> {
         unsafe {
-            wire_helpers::set_data_pointer(self.arena, self.pointer, self.segment_id, value);
+            wire_helpers::set_data_pointer(self.arena, self.pointer, self.segment_id, value)?;
         }
+        Ok(())
     }
 
     #[cfg(feature = "alloc")]
@@ -3341,6 +3360,49 @@ impl<'a> PointerBuilder<'a> {
         }
     }
 
+    /// Detaches the pointed-to object, leaving this pointer null.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn disown(&mut self) -> OrphanBuilder {
+        let orphan = OrphanBuilder::new(self.arena, self.segment_id, self.cap_table)
+            .unwrap_or_else(|e| panic!("{e}"));
+        unsafe {
+            wire_helpers::transfer_pointer(
+                self.arena,
+                orphan.segment_id,
+                orphan.pointer,
+                self.segment_id,
+                self.pointer,
+            )
+            .unwrap_or_else(|e| panic!("{e}"));
+            ptr::write_bytes(self.pointer, 0, 1);
+        }
+        orphan
+    }
+
+    /// Makes this pointer point to the object held by `orphan`, dropping whatever it pointed
+    /// to before.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn adopt(&mut self, orphan: OrphanBuilder) {
+        assert_eq!(
+            self.arena.id(),
+            orphan.arena_id,
+            "orphans can only be adopted into the message that they belong to"
+        );
+        unsafe {
+            wire_helpers::zero_object(self.arena, self.segment_id, self.pointer);
+            ptr::write_bytes(self.pointer, 0, 1);
+            wire_helpers::transfer_pointer(
+                self.arena,
+                self.segment_id,
+                self.pointer,
+                orphan.segment_id,
+                orphan.pointer,
+            )
+            .unwrap_or_else(|e| panic!("{e}"));
+            ptr::write_bytes(orphan.pointer, 0, 1);
+        }
+    }
+
     pub fn as_reader(&self) -> PointerReader<'_> {
         PointerReader {
             arena: self.arena.as_reader(),
@@ -3362,6 +3424,59 @@ impl<'a> PointerBuilder<'a> {
     }
 }
 
+/// An object that lives in a message but is not reachable from its root.
+///
+/// The object is held by a pointer allocated on its own in the arena, so the usual pointer
+/// operations work on it through [`OrphanBuilder::as_pointer_builder`]. Since an
+/// `OrphanBuilder` does not borrow the arena, every operation checks that it is given the
+/// arena that the orphan came from.
+#[cfg(target_has_atomic = "ptr")]
+pub struct OrphanBuilder {
+    arena_id: usize,
+    segment_id: u32,
+    cap_table: CapTableBuilder,
+    pointer: *mut WirePointer,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl OrphanBuilder {
+    /// Allocates a null orphan, preferably in segment `segment_id`.
+    pub fn new(
+        arena: &mut dyn BuilderArena,
+        segment_id: u32,
+        cap_table: CapTableBuilder,
+    ) -> Result<Self> {
+        let (segment_id, word_idx) = match arena.allocate(segment_id, 1) {
+            Some(idx) => (segment_id, idx),
+            None => arena.allocate_anywhere(1)?,
+        };
+        let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
+        Ok(Self {
+            arena_id: arena.id(),
+            segment_id,
+            cap_table,
+            pointer: unsafe { seg_start.add(word_idx as usize * BYTES_PER_WORD) } as *mut _,
+        })
+    }
+
+    /// Returns a builder for the pointer that holds the orphan.
+    ///
+    /// Panics if `arena` is not the arena that the orphan was allocated in.
+    pub fn as_pointer_builder<'a>(&self, arena: &'a mut dyn BuilderArena) -> PointerBuilder<'a> {
+        assert_eq!(
+            arena.id(),
+            self.arena_id,
+            "orphan used with a message that it does not belong to"
+        );
+        PointerBuilder {
+            arena,
+            segment_id: self.segment_id,
+            cap_table: self.cap_table,
+            pointer: self.pointer,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct StructReader<'a> {
     arena: &'a dyn ReaderArena,
@@ -3519,6 +3634,46 @@ impl<'a> StructReader<'a> {
         self.data
     }
 
+    /// Returns the size in bytes of the data section and the number of pointers that remain
+    /// after canonicalization truncates trailing zero words and null pointers.
+    pub fn canonical_section_sizes(&self) -> Result<(ByteCount32, WirePointerCount16)> {
+        // StructReaders should not have bitwidths other than 1, but let's be safe
+        if !(self.data_size == 1 || self.data_size % BITS_PER_BYTE as u32 == 0) {
+            return Err(Error::from_kind(
+                ErrorKind::StructReaderHadBitwidthOtherThan1,
+            ));
+        }
+
+        let mut data_size: ByteCount32 =
+            wire_helpers::round_bits_up_to_bytes(u64::from(self.data_size));
+        if self.data_size == 1 {
+            if !self.get_bool_field(0) {
+                data_size = 0;
+            }
+        } else {
+            'chop: while data_size != 0 {
+                let end = data_size;
+                let mut window = data_size % BYTES_PER_WORD as u32;
+                if window == 0 {
+                    window = BYTES_PER_WORD as u32;
+                }
+                let start = end - window;
+                let last_word = &self.get_data_section_as_blob()[start as usize..end as usize];
+                if last_word == [0; 8] {
+                    data_size -= window;
+                } else {
+                    break 'chop;
+                }
+            }
+        }
+
+        let mut ptr_count = self.pointer_count;
+        while ptr_count != 0 && self.get_pointer_field(ptr_count as usize - 1).is_null() {
+            ptr_count -= 1;
+        }
+        Ok((data_size, ptr_count))
+    }
+
     pub fn is_canonical(
         &self,
         read_head: &Cell<*const u8>,
@@ -3836,6 +3991,41 @@ impl<'a> ListReader<'a> {
         self.element_size
     }
 
+    /// For a list of structs, returns the data words and pointers per element that remain
+    /// after canonicalization truncates every element to the largest of their truncated sizes.
+    pub fn canonical_element_sizes(&self) -> (WordCount32, WirePointerCount16) {
+        let mut data_size = 0;
+        let mut ptr_count = 0;
+        for ec in 0..self.element_count {
+            let se = self.get_struct_element(ec);
+            let mut local_data_size = self.struct_data_size / BITS_PER_WORD as u32;
+            'data_chop: while local_data_size != 0 {
+                let end = local_data_size * BYTES_PER_WORD as u32;
+                let window = BYTES_PER_WORD as u32;
+                let start = end - window;
+                let last_word = &se.get_data_section_as_blob()[start as usize..end as usize];
+                if last_word != [0; 8] {
+                    break 'data_chop;
+                } else {
+                    local_data_size -= 1;
+                }
+            }
+            if local_data_size > data_size {
+                data_size = local_data_size;
+            }
+            let mut local_ptr_count = self.struct_pointer_count;
+            while local_ptr_count != 0
+                && se.get_pointer_field(local_ptr_count as usize - 1).is_null()
+            {
+                local_ptr_count -= 1;
+            }
+            if local_ptr_count > ptr_count {
+                ptr_count = local_ptr_count;
+            }
+        }
+        (data_size, ptr_count)
+    }
+
     pub(crate) fn into_raw_bytes(self) -> &'a [u8] {
         if self.element_count == 0 {
             // Explictly handle this case to avoid forming a slice to a null pointer,
@@ -3995,6 +4185,317 @@ impl<'a> ListReader<'a> {
     }
 }
 
+/// The pointer that canonicalization writes for an object, along with the number of words
+/// taken by that object and everything reachable from it.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy)]
+struct CanonicalTarget {
+    kind: Option<WirePointerKind>,
+    upper32bits: u32,
+    word_count: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl CanonicalTarget {
+    const NULL: Self = Self {
+        kind: None,
+        upper32bits: 0,
+        word_count: 0,
+    };
+}
+
+/// Streams the canonical form of a message, in exactly the layout that `set_root_canonical()`
+/// produces, without building it.
+///
+/// Objects are laid out in preorder, so a pointer can only be written once the sizes of all
+/// the objects before its target are known. A first pass records a `CanonicalTarget` for each
+/// pointer in the order that the second pass, which does the writing, will reach them.
+#[cfg(feature = "alloc")]
+struct CanonicalWriter<'s> {
+    targets: Vec<CanonicalTarget>,
+    next: usize,
+    sink: &'s mut dyn FnMut(&[u8]),
+}
+
+#[cfg(feature = "alloc")]
+impl<'s> CanonicalWriter<'s> {
+    fn new(
+        sink: &'s mut dyn FnMut(&[u8]),
+        measure: impl FnOnce(&mut Vec<CanonicalTarget>) -> Result<CanonicalTarget>,
+    ) -> Result<(Self, CanonicalTarget)> {
+        let mut targets = Vec::new();
+        let root = measure(&mut targets)?;
+        let size = root.word_count + 1;
+        if size >= 1 << 29 {
+            return Err(Error::from_kind(ErrorKind::MessageTooLarge(size as usize)));
+        }
+        let writer = Self {
+            targets,
+            next: 0,
+            sink,
+        };
+        Ok((writer, root))
+    }
+
+    fn measure_pointer(
+        targets: &mut Vec<CanonicalTarget>,
+        pointer: &PointerReader,
+    ) -> Result<CanonicalTarget> {
+        match pointer.get_pointer_type()? {
+            PointerType::Null => Ok(CanonicalTarget::NULL),
+            PointerType::Struct => Self::measure_struct(targets, &pointer.get_struct(None)?),
+            PointerType::List => {
+                Self::measure_list(targets, &pointer.get_list_any_size(ptr::null())?)
+            }
+            PointerType::Capability => Err(Error::from_kind(
+                ErrorKind::CannotCreateACanonicalMessageWithACapability,
+            )),
+        }
+    }
+
+    fn measure_struct(
+        targets: &mut Vec<CanonicalTarget>,
+        value: &StructReader,
+    ) -> Result<CanonicalTarget> {
+        let (data_size, ptr_count) = value.canonical_section_sizes()?;
+        let data_words = wire_helpers::round_bytes_up_to_words(data_size);
+        let base = targets.len();
+        targets.resize(base + ptr_count as usize, CanonicalTarget::NULL);
+        let mut word_count = u64::from(data_words) + u64::from(ptr_count);
+        for i in 0..ptr_count as usize {
+            let target = Self::measure_pointer(targets, &value.get_pointer_field(i))?;
+            word_count += target.word_count;
+            targets[base + i] = target;
+        }
+        Ok(CanonicalTarget {
+            kind: Some(WirePointerKind::Struct),
+            upper32bits: data_words | (u32::from(ptr_count) << 16),
+            word_count,
+        })
+    }
+
+    fn measure_list(
+        targets: &mut Vec<CanonicalTarget>,
+        value: &ListReader,
+    ) -> Result<CanonicalTarget> {
+        let (upper32bits, word_count) = match value.element_size {
+            InlineComposite => {
+                let (data_size, ptr_count) = value.canonical_element_sizes();
+                let list_words = (data_size + u32::from(ptr_count)) * value.element_count;
+                let base = targets.len();
+                let slot_count = ptr_count as usize * value.element_count as usize;
+                targets.resize(base + slot_count, CanonicalTarget::NULL);
+                let mut word_count = 1 + u64::from(list_words);
+                let mut slot = base;
+                for ec in 0..value.element_count {
+                    let element = value.get_struct_element(ec);
+                    for i in 0..ptr_count as usize {
+                        let target = Self::measure_pointer(targets, &element.get_pointer_field(i))?;
+                        word_count += target.word_count;
+                        targets[slot] = target;
+                        slot += 1;
+                    }
+                }
+                ((list_words << 3) | InlineComposite as u32, word_count)
+            }
+            Pointer => {
+                let base = targets.len();
+                targets.resize(base + value.element_count as usize, CanonicalTarget::NULL);
+                let mut word_count = u64::from(value.element_count);
+                for ec in 0..value.element_count {
+                    let target = Self::measure_pointer(targets, &value.get_pointer_element(ec))?;
+                    word_count += target.word_count;
+                    targets[base + ec as usize] = target;
+                }
+                ((value.element_count << 3) | Pointer as u32, word_count)
+            }
+            element_size => {
+                let bits = u64::from(value.element_count) * u64::from(value.step);
+                (
+                    (value.element_count << 3) | element_size as u32,
+                    u64::from(wire_helpers::round_bits_up_to_words(bits)),
+                )
+            }
+        };
+        Ok(CanonicalTarget {
+            kind: Some(WirePointerKind::List),
+            upper32bits,
+            word_count,
+        })
+    }
+
+    fn write_word(&mut self, word: u64) {
+        (self.sink)(&word.to_le_bytes());
+    }
+
+    fn write_padding(&mut self, byte_count: u64) {
+        let remainder = byte_count % BYTES_PER_WORD as u64;
+        if remainder != 0 {
+            (self.sink)(&[0; BYTES_PER_WORD][remainder as usize..]);
+        }
+    }
+
+    /// Writes the next `count` pointers, the first of which is at word `position`, given that
+    /// their targets are laid out one after another starting at word `start`. Returns the index
+    /// of the first pointer's target.
+    fn write_pointers(&mut self, position: u64, start: u64, count: usize) -> usize {
+        let base = self.next;
+        self.next += count;
+        let mut start = start;
+        for i in 0..count {
+            let target = self.targets[base + i];
+            self.write_pointer(position + i as u64, start, target);
+            start += target.word_count;
+        }
+        base
+    }
+
+    fn write_pointer(&mut self, position: u64, start: u64, target: CanonicalTarget) {
+        let lower32bits = match target.kind {
+            None => 0,
+            Some(WirePointerKind::Struct) if target.word_count == 0 => 0xfffffffc,
+            Some(kind) => (((start - position - 1) as u32) << 2) | kind as u32,
+        };
+        self.write_word(u64::from(lower32bits) | (u64::from(target.upper32bits) << 32));
+    }
+
+    fn write_object(
+        &mut self,
+        pointer: &PointerReader,
+        target: CanonicalTarget,
+        start: u64,
+    ) -> Result<()> {
+        if target.word_count == 0 {
+            // Null, or an object that takes no space.
+            Ok(())
+        } else if target.kind == Some(WirePointerKind::Struct) {
+            self.write_struct(&pointer.get_struct(None)?, start)
+        } else {
+            self.write_list(&pointer.get_list_any_size(ptr::null())?, start)
+        }
+    }
+
+    fn write_struct(&mut self, value: &StructReader, start: u64) -> Result<()> {
+        let (data_size, ptr_count) = value.canonical_section_sizes()?;
+        if value.data_size == 1 {
+            if data_size != 0 {
+                (self.sink)(&[u8::from(value.get_bool_field(0))]);
+            }
+        } else {
+            (self.sink)(&value.get_data_section_as_blob()[..data_size as usize]);
+        }
+        self.write_padding(u64::from(data_size));
+
+        let position = start + u64::from(wire_helpers::round_bytes_up_to_words(data_size));
+        let ptr_count = ptr_count as usize;
+        let mut child_start = position + ptr_count as u64;
+        let base = self.write_pointers(position, child_start, ptr_count);
+        for i in 0..ptr_count {
+            let target = self.targets[base + i];
+            self.write_object(&value.get_pointer_field(i), target, child_start)?;
+            child_start += target.word_count;
+        }
+        Ok(())
+    }
+
+    fn write_list(&mut self, value: &ListReader, start: u64) -> Result<()> {
+        match value.element_size {
+            InlineComposite => {
+                let (data_size, ptr_count) = value.canonical_element_sizes();
+                let element_count = u64::from(value.element_count);
+                let struct_size = data_size | (u32::from(ptr_count) << 16);
+                self.write_word(
+                    ((element_count << 2) | WirePointerKind::Struct as u64)
+                        | (u64::from(struct_size) << 32),
+                );
+
+                let words_per_element = u64::from(data_size) + u64::from(ptr_count);
+                let ptr_count = ptr_count as usize;
+                let elements_start = start + 1;
+                let children_start = elements_start + words_per_element * element_count;
+                let base = self.next;
+                let mut child_start = children_start;
+                for ec in 0..value.element_count {
+                    let element = value.get_struct_element(ec);
+                    let data =
+                        &element.get_data_section_as_blob()[..data_size as usize * BYTES_PER_WORD];
+                    (self.sink)(data);
+                    let position =
+                        elements_start + words_per_element * u64::from(ec) + u64::from(data_size);
+                    let first = self.write_pointers(position, child_start, ptr_count);
+                    for i in 0..ptr_count {
+                        child_start += self.targets[first + i].word_count;
+                    }
+                }
+
+                let mut child_start = children_start;
+                let mut slot = base;
+                for ec in 0..value.element_count {
+                    let element = value.get_struct_element(ec);
+                    for i in 0..ptr_count {
+                        let target = self.targets[slot];
+                        self.write_object(&element.get_pointer_field(i), target, child_start)?;
+                        child_start += target.word_count;
+                        slot += 1;
+                    }
+                }
+            }
+            Pointer => {
+                let element_count = value.element_count as usize;
+                let mut child_start = start + element_count as u64;
+                let base = self.write_pointers(start, child_start, element_count);
+                for ec in 0..value.element_count {
+                    let target = self.targets[base + ec as usize];
+                    self.write_object(&value.get_pointer_element(ec), target, child_start)?;
+                    child_start += target.word_count;
+                }
+            }
+            _ => {
+                let bits = u64::from(value.element_count) * u64::from(value.step);
+                let bytes = value.into_raw_bytes();
+                let whole_byte_size = (bits / BITS_PER_BYTE as u64) as usize;
+                (self.sink)(&bytes[..whole_byte_size]);
+                let leftover_bits = bits % BITS_PER_BYTE as u64;
+                if leftover_bits > 0 {
+                    let mask: u8 = (1 << leftover_bits as u8) - 1;
+                    (self.sink)(&[mask & bytes[whole_byte_size]]);
+                }
+                self.write_padding(u64::from(wire_helpers::round_bits_up_to_bytes(bits)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> PointerReader<'a> {
+    /// Passes the canonical form of the message whose root is this pointer's target to `sink`,
+    /// in pieces. The concatenated pieces equal the words that `canonicalize()` would return.
+    pub fn write_canonical(&self, sink: &mut dyn FnMut(&[u8])) -> Result<()> {
+        let (mut writer, root) = CanonicalWriter::new(sink, |targets| {
+            CanonicalWriter::measure_pointer(targets, self)
+        })?;
+        writer.write_pointer(0, 1, root);
+        writer.write_object(self, root, 1)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> StructReader<'a> {
+    /// Passes the canonical form of a message whose root is this struct to `sink`, in pieces.
+    pub fn write_canonical(&self, sink: &mut dyn FnMut(&[u8])) -> Result<()> {
+        let (mut writer, root) = CanonicalWriter::new(sink, |targets| {
+            CanonicalWriter::measure_struct(targets, self)
+        })?;
+        writer.write_pointer(0, 1, root);
+        if root.word_count == 0 {
+            Ok(())
+        } else {
+            writer.write_struct(self, 1)
+        }
+    }
+}
+
 pub struct ListBuilder<'a> {
     arena: &'a mut dyn BuilderArena,
     cap_table: CapTableBuilder,
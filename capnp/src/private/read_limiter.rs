@@ -19,11 +19,27 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
+use crate::Result;
+
+/// Operations common to the read limiters, so that an arena can be generic over which one it uses.
+pub trait ReadLimit {
+    fn new(limit: Option<usize>) -> Self;
+    fn can_read(&self, amount: usize) -> Result<()>;
+}
+
 #[cfg(feature = "sync_reader")]
 pub use sync::ReadLimiter;
 
-#[cfg(feature = "sync_reader")]
+/// A read limiter that can be shared between threads, regardless of the `sync_reader` feature.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use sync::ReadLimiter as SyncReadLimiter;
+
+#[cfg(any(
+    feature = "sync_reader",
+    all(feature = "alloc", target_has_atomic = "ptr")
+))]
 mod sync {
+    use super::ReadLimit;
     use crate::{Error, ErrorKind, Result};
     use core::sync::atomic::{AtomicUsize, Ordering};
 
@@ -32,8 +48,8 @@ mod sync {
         error_on_limit_exceeded: bool,
     }
 
-    impl ReadLimiter {
-        pub fn new(limit: Option<usize>) -> Self {
+    impl ReadLimit for ReadLimiter {
+        fn new(limit: Option<usize>) -> Self {
             match limit {
                 Some(value) => Self {
                     limit: AtomicUsize::new(value),
@@ -47,7 +63,7 @@ mod sync {
         }
 
         #[inline]
-        pub fn can_read(&self, amount: usize) -> Result<()> {
+        fn can_read(&self, amount: usize) -> Result<()> {
             // We use separate AtomicUsize::load() and AtomicUsize::store() steps, which may
             // result in undercounting reads if multiple threads are reading at the same.
             // That's okay -- a denial of service attack will eventually hit the limit anyway.
@@ -74,6 +90,7 @@ pub use unsync::ReadLimiter;
 
 #[cfg(not(feature = "sync_reader"))]
 mod unsync {
+    use super::ReadLimit;
     use crate::{Error, ErrorKind, Result};
     use core::cell::Cell;
 
@@ -82,8 +99,8 @@ mod unsync {
         error_on_limit_exceeded: bool,
     }
 
-    impl ReadLimiter {
-        pub fn new(limit: Option<usize>) -> Self {
+    impl ReadLimit for ReadLimiter {
+        fn new(limit: Option<usize>) -> Self {
             match limit {
                 Some(value) => Self {
                     limit: Cell::new(value),
@@ -97,7 +114,7 @@ mod unsync {
         }
 
         #[inline]
-        pub fn can_read(&self, amount: usize) -> Result<()> {
+        fn can_read(&self, amount: usize) -> Result<()> {
             let current = self.limit.get();
             if amount > current && self.error_on_limit_exceeded {
                 Err(Error::from_kind(ErrorKind::ReadLimitExceeded))
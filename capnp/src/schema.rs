@@ -1,13 +1,24 @@
 //! Convenience wrappers of the datatypes defined in schema.capnp.
 
 use crate::dynamic_value;
-use crate::introspect::{self, RawBrandedStructSchema, RawEnumSchema};
+use crate::introspect::{
+    self, AnnotationTypes, InterfaceTypes, RawBrandedStructSchema, RawEnumSchema,
+    RawInterfaceSchema, StructTypes,
+};
 use crate::private::layout;
-use crate::schema_capnp::{annotation, enumerant, field, node};
+use crate::schema_capnp::{annotation, enumerant, field, method, node, superclass};
 use crate::struct_list;
 use crate::traits::{IndexMove, ListIter, ShortListIter};
 use crate::Result;
 
+#[cfg(feature = "alloc")]
+mod loader;
+
+#[cfg(feature = "alloc")]
+pub(crate) use loader::NodeTable;
+#[cfg(feature = "alloc")]
+pub use loader::SchemaLoader;
+
 /// A struct node, with generics applied.
 #[derive(Clone, Copy)]
 pub struct StructSchema {
@@ -104,7 +115,7 @@ impl StructSchema {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: None,
-            get_annotation_type: self.raw.annotation_types,
+            annotation_types: self.raw.types.annotation_types(),
         })
     }
 }
@@ -129,7 +140,11 @@ impl Field {
     }
 
     pub fn get_type(&self) -> introspect::Type {
-        (self.parent.raw.field_types)(self.index)
+        match self.parent.raw.types {
+            StructTypes::Generated { field_types, .. } => field_types(self.index),
+            #[cfg(feature = "alloc")]
+            StructTypes::Loaded(table) => table.field_type(self.proto),
+        }
     }
 
     pub fn get_index(&self) -> u16 {
@@ -140,7 +155,7 @@ impl Field {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: Some(self.index),
-            get_annotation_type: self.parent.raw.annotation_types,
+            annotation_types: self.parent.raw.types.annotation_types(),
         })
     }
 }
@@ -271,7 +286,7 @@ impl EnumSchema {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: None,
-            get_annotation_type: self.raw.annotation_types,
+            annotation_types: self.raw.annotation_types,
         })
     }
 }
@@ -307,7 +322,7 @@ impl Enumerant {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: Some(self.ordinal),
-            get_annotation_type: self.parent.raw.annotation_types,
+            annotation_types: self.parent.raw.annotation_types,
         })
     }
 }
@@ -380,12 +395,320 @@ impl Annotation {
     }
 }
 
+/// An interface node, with generics applied.
+#[derive(Clone, Copy)]
+pub struct InterfaceSchema {
+    pub(crate) raw: RawInterfaceSchema,
+    pub(crate) proto: node::Reader<'static>,
+}
+
+impl InterfaceSchema {
+    pub fn new(raw: RawInterfaceSchema) -> Self {
+        let proto = crate::any_pointer::Reader::new(unsafe {
+            layout::PointerReader::get_root_unchecked(raw.encoded_node.as_ptr() as *const u8)
+        })
+        .get_as()
+        .unwrap();
+        Self { raw, proto }
+    }
+
+    pub fn get_proto(self) -> node::Reader<'static> {
+        self.proto
+    }
+
+    pub fn get_methods(self) -> Result<MethodList> {
+        if let node::Interface(i) = self.proto.which()? {
+            Ok(MethodList {
+                methods: i.get_methods()?,
+                parent: self,
+            })
+        } else {
+            panic!()
+        }
+    }
+
+    /// Gets the interfaces that this interface directly extends.
+    pub fn get_superclasses(self) -> Result<SuperclassList> {
+        if let node::Interface(i) = self.proto.which()? {
+            Ok(SuperclassList {
+                superclasses: i.get_superclasses()?,
+                parent: self,
+            })
+        } else {
+            panic!()
+        }
+    }
+
+    /// Looks up a method by name, searching superclasses too. Returns `None` if no
+    /// matching method is found.
+    pub fn find_method_by_name(self, name: &str) -> Result<Option<Method>> {
+        self.find_in_hierarchy(&mut |schema| {
+            for method in schema.get_methods()? {
+                if method.get_proto().get_name()? == name {
+                    return Ok(Some(method));
+                }
+            }
+            Ok(None)
+        })
+    }
+
+    /// Calls `f` on this interface and then on its superclasses, transitively, in depth-first
+    /// order, until `f` returns `Some`.
+    ///
+    /// Each interface is visited once, even if it is inherited along several paths. Cycles,
+    /// which can only occur in malformed schemas, are cut.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn find_in_hierarchy<T>(
+        self,
+        f: &mut dyn FnMut(InterfaceSchema) -> Result<Option<T>>,
+    ) -> Result<Option<T>> {
+        let mut visited = alloc::collections::BTreeSet::new();
+        let mut stack = alloc::vec![self];
+        while let Some(schema) = stack.pop() {
+            if !visited.insert(schema.proto.get_id()) {
+                continue;
+            }
+            if let Some(found) = f(schema)? {
+                return Ok(Some(found));
+            }
+            let superclasses = schema.get_superclasses()?;
+            stack.extend((0..superclasses.len()).rev().map(|i| superclasses.get(i)));
+        }
+        Ok(None)
+    }
+
+    /// Calls `f` on this interface and then on its superclasses, transitively, in depth-first
+    /// order, until `f` returns `Some`.
+    ///
+    /// Without an allocator to remember which interfaces have been visited, only cycles, which
+    /// can only occur in malformed schemas, are cut. An interface that is inherited along
+    /// several paths is visited once per path.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) fn find_in_hierarchy<T>(
+        self,
+        f: &mut dyn FnMut(InterfaceSchema) -> Result<Option<T>>,
+    ) -> Result<Option<T>> {
+        self.find_in_subhierarchy(None, f)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn find_in_subhierarchy<T>(
+        self,
+        path: Option<&HierarchyPath>,
+        f: &mut dyn FnMut(InterfaceSchema) -> Result<Option<T>>,
+    ) -> Result<Option<T>> {
+        let id = self.proto.get_id();
+        let mut ancestor = path;
+        while let Some(a) = ancestor {
+            if a.id == id {
+                return Ok(None);
+            }
+            ancestor = a.parent;
+        }
+        if let Some(found) = f(self)? {
+            return Ok(Some(found));
+        }
+        let path = HierarchyPath { id, parent: path };
+        for superclass in self.get_superclasses()? {
+            if let Some(found) = superclass.find_in_subhierarchy(Some(&path), f)? {
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like `find_method_by_name()`, but returns an error if the method is not found.
+    pub fn get_method_by_name(self, name: &str) -> Result<Method> {
+        if let Some(method) = self.find_method_by_name(name)? {
+            Ok(method)
+        } else {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::MethodNotFound);
+            write!(error, "{}", name);
+            Err(error)
+        }
+    }
+
+    pub fn get_annotations(self) -> Result<AnnotationList> {
+        Ok(AnnotationList {
+            annotations: self.proto.get_annotations()?,
+            child_index: None,
+            annotation_types: self.raw.types.annotation_types(),
+        })
+    }
+}
+
+impl From<RawInterfaceSchema> for InterfaceSchema {
+    fn from(ri: RawInterfaceSchema) -> InterfaceSchema {
+        InterfaceSchema::new(ri)
+    }
+}
+
+/// A method of an interface, with generics applied.
+#[derive(Clone, Copy)]
+pub struct Method {
+    proto: method::Reader<'static>,
+    ordinal: u16,
+    parent: InterfaceSchema,
+}
+
+impl Method {
+    pub fn get_proto(self) -> method::Reader<'static> {
+        self.proto
+    }
+
+    /// The method's ordinal, which together with the ID of its containing interface
+    /// identifies the method in a call.
+    pub fn get_ordinal(self) -> u16 {
+        self.ordinal
+    }
+
+    pub fn get_containing_interface(self) -> InterfaceSchema {
+        self.parent
+    }
+
+    fn get_types(self) -> (introspect::Type, introspect::Type) {
+        match self.parent.raw.types {
+            InterfaceTypes::Generated { method_types, .. } => method_types(self.ordinal),
+            #[cfg(feature = "alloc")]
+            InterfaceTypes::Loaded(table) => table.method_types(self.proto),
+        }
+    }
+
+    /// Gets the schema of the struct that holds the method's parameters.
+    pub fn get_param_type(self) -> StructSchema {
+        match self.get_types().0.which() {
+            introspect::TypeVariant::Struct(rs) => rs.into(),
+            _ => panic!("method params must be a struct"),
+        }
+    }
+
+    /// Gets the schema of the struct that holds the method's results.
+    pub fn get_result_type(self) -> StructSchema {
+        match self.get_types().1.which() {
+            introspect::TypeVariant::Struct(rs) => rs.into(),
+            _ => panic!("method results must be a struct"),
+        }
+    }
+
+    pub fn get_annotations(self) -> Result<AnnotationList> {
+        Ok(AnnotationList {
+            annotations: self.proto.get_annotations()?,
+            child_index: Some(self.ordinal),
+            annotation_types: self.parent.raw.types.annotation_types(),
+        })
+    }
+}
+
+/// A list of methods of an interface, with generics applied.
+#[derive(Clone, Copy)]
+pub struct MethodList {
+    methods: struct_list::Reader<'static, method::Owned>,
+    parent: InterfaceSchema,
+}
+
+impl MethodList {
+    pub fn len(&self) -> u16 {
+        self.methods.len() as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(self, ordinal: u16) -> Method {
+        Method {
+            proto: self.methods.get(ordinal as u32),
+            ordinal,
+            parent: self.parent,
+        }
+    }
+
+    pub fn iter(self) -> ShortListIter<Self, Method> {
+        ShortListIter::new(self, self.len())
+    }
+}
+
+impl IndexMove<u16, Method> for MethodList {
+    fn index_move(&self, index: u16) -> Method {
+        self.get(index)
+    }
+}
+
+impl ::core::iter::IntoIterator for MethodList {
+    type Item = Method;
+    type IntoIter = ShortListIter<MethodList, Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The interfaces on the way from where a search of a superclass hierarchy started to where it
+/// currently is.
+#[cfg(not(feature = "alloc"))]
+struct HierarchyPath<'a> {
+    id: u64,
+    parent: Option<&'a HierarchyPath<'a>>,
+}
+
+/// A list of the superclasses of an interface, with generics applied.
+#[derive(Clone, Copy)]
+pub struct SuperclassList {
+    superclasses: struct_list::Reader<'static, superclass::Owned>,
+    parent: InterfaceSchema,
+}
+
+impl SuperclassList {
+    pub fn len(&self) -> u16 {
+        self.superclasses.len() as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(self, index: u16) -> InterfaceSchema {
+        let ty = match self.parent.raw.types {
+            InterfaceTypes::Generated {
+                superclass_types, ..
+            } => superclass_types(index),
+            #[cfg(feature = "alloc")]
+            InterfaceTypes::Loaded(table) => {
+                table.superclass_type(self.superclasses.get(index as u32))
+            }
+        };
+        match ty.which() {
+            introspect::TypeVariant::Capability(ri) => ri.into(),
+            _ => panic!("superclass must be an interface"),
+        }
+    }
+
+    pub fn iter(self) -> ShortListIter<Self, InterfaceSchema> {
+        ShortListIter::new(self, self.len())
+    }
+}
+
+impl IndexMove<u16, InterfaceSchema> for SuperclassList {
+    fn index_move(&self, index: u16) -> InterfaceSchema {
+        self.get(index)
+    }
+}
+
+impl ::core::iter::IntoIterator for SuperclassList {
+    type Item = InterfaceSchema;
+    type IntoIter = ShortListIter<SuperclassList, Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// A list of annotations.
 #[derive(Clone, Copy)]
 pub struct AnnotationList {
     annotations: struct_list::Reader<'static, annotation::Owned>,
     child_index: Option<u16>,
-    get_annotation_type: fn(Option<u16>, u32) -> introspect::Type,
+    annotation_types: AnnotationTypes,
 }
 
 impl AnnotationList {
@@ -399,7 +722,13 @@ impl AnnotationList {
 
     pub fn get(self, index: u32) -> Annotation {
         let proto = self.annotations.get(index);
-        let ty = (self.get_annotation_type)(self.child_index, index);
+        let ty = match self.annotation_types {
+            AnnotationTypes::Generated(get_annotation_type) => {
+                get_annotation_type(self.child_index, index)
+            }
+            #[cfg(feature = "alloc")]
+            AnnotationTypes::Loaded(table) => table.annotation_type(proto),
+        };
         Annotation { proto, ty }
     }
 
@@ -0,0 +1,575 @@
+//! Loading of schema nodes at run time, e.g. from the output of `capnp compile -o-`.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::introspect::{
+    AnnotationTypes, InterfaceTypes, RawBrandedStructSchema, RawEnumSchema, RawInterfaceSchema,
+    RawStructSchema, StructTypes, Type, TypeVariant,
+};
+use crate::message::{self, HeapAllocator};
+use crate::private::layout;
+use crate::private::units::BYTES_PER_WORD;
+use crate::schema::{EnumSchema, InterfaceSchema, StructSchema};
+use crate::schema_capnp::{
+    annotation, code_generator_request, field, method, node, superclass, type_,
+};
+use crate::{Error, ErrorKind, Result, Word};
+
+/// Builds `StructSchema`s and `EnumSchema`s from schema nodes that are only known at run time,
+/// so that messages can be inspected with `dynamic_struct` without any generated code.
+///
+/// Nodes are loaded in batches. Every struct, enum, interface or annotation referenced by a
+/// batch must either be part of that batch or have been loaded previously. Nodes are validated
+/// when they are loaded, so a malformed schema is reported as an error rather than causing
+/// out-of-bounds accesses later.
+///
+/// Generic parameters are not bound: a field whose type is a type parameter is
+/// presented as an `AnyPointer`.
+///
+/// Loaded schemas have `'static` lifetime, just like the schemas in generated code, so the
+/// memory backing them is never freed. A `SchemaLoader` is therefore intended for loading a
+/// bounded set of schemas, typically once at startup. Each batch is kept separately, so
+/// looking up a node takes time proportional to the number of batches: prefer loading a
+/// whole `CodeGeneratorRequest` at once over loading nodes one at a time.
+#[derive(Default)]
+pub struct SchemaLoader {
+    table: Option<&'static NodeTable>,
+}
+
+impl SchemaLoader {
+    pub fn new() -> Self {
+        Self { table: None }
+    }
+
+    /// Loads every node of a `CodeGeneratorRequest`, as produced by `capnp compile -o-`.
+    pub fn load_request(&mut self, request: code_generator_request::Reader) -> Result<()> {
+        self.load_nodes(request.get_nodes()?)
+    }
+
+    /// Loads a batch of nodes. Loading a node that is identical to one that was already
+    /// loaded is a no-op; loading a different node with the same ID is an error.
+    pub fn load_nodes<'a, I>(&mut self, nodes: I) -> Result<()>
+    where
+        I: IntoIterator<Item = node::Reader<'a>>,
+    {
+        let mut batch: BTreeMap<u64, (node::Reader<'a>, Vec<Word>)> = BTreeMap::new();
+        for node in nodes {
+            let id = node.get_id();
+            let encoded = encode_node(node)?;
+            let previous = match self.table.and_then(|t| t.get(id)) {
+                Some(loaded) => Some(loaded.encoded_node),
+                None => batch.get(&id).map(|(_, words)| &words[..]),
+            };
+            match previous {
+                Some(words) if words == &encoded[..] => {}
+                Some(_) => return Err(Error::from_kind(ErrorKind::ConflictingSchemaNode(id))),
+                None => {
+                    batch.insert(id, (node, encoded));
+                }
+            }
+        }
+
+        let node_of = |id: u64| -> Option<node::Reader<'a>> {
+            match self.table.and_then(|t| t.get(id)) {
+                Some(loaded) => Some(decode_node(loaded.encoded_node)),
+                None => Some(batch.get(&id)?.0),
+            }
+        };
+        let kind_of = |id: u64| -> Option<Kind> { Kind::of(node_of(id)?).ok() };
+        for (node, _) in batch.values() {
+            validate_node(*node, &kind_of, &node_of)?;
+        }
+
+        let mut nodes = BTreeMap::new();
+        for (id, (node, encoded)) in batch {
+            let encoded_node: &'static [Word] = Box::leak(encoded.into_boxed_slice());
+            let struct_schema = match node.which()? {
+                node::Struct(st) => Some(&*Box::leak(Box::new(raw_struct_schema(
+                    encoded_node,
+                    st.get_fields()?,
+                )))),
+                _ => None,
+            };
+            nodes.insert(
+                id,
+                LoadedNode {
+                    encoded_node,
+                    struct_schema,
+                },
+            );
+        }
+        self.table = Some(Box::leak(Box::new(NodeTable {
+            nodes,
+            parent: self.table,
+        })));
+        Ok(())
+    }
+
+    /// Gets a loaded node by ID.
+    pub fn get_node(&self, id: u64) -> Option<node::Reader<'static>> {
+        self.table?
+            .get(id)
+            .map(|loaded| decode_node(loaded.encoded_node))
+    }
+
+    /// Gets the schema of a loaded struct node.
+    pub fn get_struct(&self, id: u64) -> Result<StructSchema> {
+        match self.table {
+            Some(table) if table.get(id).is_some() => match table.struct_schema(id) {
+                Some(raw) => Ok(raw.into()),
+                None => Err(Error::from_kind(ErrorKind::NotAStruct)),
+            },
+            _ => Err(Error::from_kind(ErrorKind::SchemaNodeNotFound(id))),
+        }
+    }
+
+    /// Gets the schema of a loaded enum node.
+    pub fn get_enum(&self, id: u64) -> Result<EnumSchema> {
+        match self.table {
+            Some(table) if table.get(id).is_some() => match table.enum_schema(id) {
+                Some(raw) => Ok(raw.into()),
+                None => Err(Error::from_kind(ErrorKind::NotAnEnum)),
+            },
+            _ => Err(Error::from_kind(ErrorKind::SchemaNodeNotFound(id))),
+        }
+    }
+
+    /// Gets the schema of a loaded interface node.
+    pub fn get_interface(&self, id: u64) -> Result<InterfaceSchema> {
+        match self.table {
+            Some(table) if table.get(id).is_some() => match table.interface_schema(id) {
+                Some(raw) => Ok(raw.into()),
+                None => Err(Error::from_kind(ErrorKind::NotAnInterface)),
+            },
+            _ => Err(Error::from_kind(ErrorKind::SchemaNodeNotFound(id))),
+        }
+    }
+}
+
+/// The nodes known to a `SchemaLoader` at some point in time. Each call to
+/// `SchemaLoader::load_nodes()` produces a new table that holds only the newly loaded nodes
+/// and links to the previous table, so loading is linear in the number of nodes. Previously
+/// handed-out schemas keep referring to the table that was current when they were created.
+pub(crate) struct NodeTable {
+    nodes: BTreeMap<u64, LoadedNode>,
+    parent: Option<&'static NodeTable>,
+}
+
+#[derive(Clone, Copy)]
+struct LoadedNode {
+    /// The node in canonical form, as a single segment message.
+    encoded_node: &'static [Word],
+
+    /// Present iff the node is a struct.
+    struct_schema: Option<&'static RawStructSchema>,
+}
+
+impl NodeTable {
+    fn get(&self, id: u64) -> Option<&LoadedNode> {
+        let mut table = self;
+        loop {
+            if let Some(loaded) = table.nodes.get(&id) {
+                return Some(loaded);
+            }
+            table = table.parent?;
+        }
+    }
+
+    fn struct_schema(&'static self, id: u64) -> Option<RawBrandedStructSchema> {
+        Some(RawBrandedStructSchema {
+            generic: self.get(id)?.struct_schema?,
+            types: StructTypes::Loaded(self),
+        })
+    }
+
+    fn enum_schema(&'static self, id: u64) -> Option<RawEnumSchema> {
+        let encoded_node = self.get(id)?.encoded_node;
+        match decode_node(encoded_node).which() {
+            Ok(node::Enum(_)) => Some(RawEnumSchema {
+                encoded_node,
+                annotation_types: AnnotationTypes::Loaded(self),
+            }),
+            _ => None,
+        }
+    }
+
+    fn interface_schema(&'static self, id: u64) -> Option<RawInterfaceSchema> {
+        let encoded_node = self.get(id)?.encoded_node;
+        match decode_node(encoded_node).which() {
+            Ok(node::Interface(_)) => Some(RawInterfaceSchema {
+                encoded_node,
+                types: InterfaceTypes::Loaded(self),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Gets the types of the params and results structs of a method of a loaded interface.
+    pub(crate) fn method_types(&'static self, method: method::Reader<'static>) -> (Type, Type) {
+        let struct_type = |id| -> Type {
+            TypeVariant::Struct(
+                self.struct_schema(id)
+                    .expect("method was validated when loaded"),
+            )
+            .into()
+        };
+        (
+            struct_type(method.get_param_struct_type()),
+            struct_type(method.get_result_struct_type()),
+        )
+    }
+
+    /// Gets the type of a superclass of a loaded interface.
+    pub(crate) fn superclass_type(&'static self, superclass: superclass::Reader<'static>) -> Type {
+        TypeVariant::Capability(
+            self.interface_schema(superclass.get_id())
+                .expect("superclass was validated when loaded"),
+        )
+        .into()
+    }
+
+    /// Gets the type of a field of a loaded struct.
+    pub(crate) fn field_type(&'static self, field: field::Reader<'static>) -> Type {
+        match field.which().expect("field was validated when loaded") {
+            field::Slot(slot) => {
+                self.resolve(slot.get_type().expect("field was validated when loaded"))
+            }
+            field::Group(group) => TypeVariant::Struct(
+                self.struct_schema(group.get_type_id())
+                    .expect("group was validated when loaded"),
+            )
+            .into(),
+        }
+    }
+
+    /// Gets the type of the value held by an annotation of a loaded node.
+    pub(crate) fn annotation_type(&'static self, annotation: annotation::Reader<'static>) -> Type {
+        let node = decode_node(
+            self.get(annotation.get_id())
+                .expect("annotation was validated when loaded")
+                .encoded_node,
+        );
+        match node.which() {
+            Ok(node::Annotation(a)) => {
+                self.resolve(a.get_type().expect("annotation was validated when loaded"))
+            }
+            _ => panic!("annotation was validated when loaded"),
+        }
+    }
+
+    fn resolve(&'static self, ty: type_::Reader<'static>) -> Type {
+        match ty.which().expect("type was validated when loaded") {
+            type_::Void(()) => TypeVariant::Void.into(),
+            type_::Bool(()) => TypeVariant::Bool.into(),
+            type_::Int8(()) => TypeVariant::Int8.into(),
+            type_::Int16(()) => TypeVariant::Int16.into(),
+            type_::Int32(()) => TypeVariant::Int32.into(),
+            type_::Int64(()) => TypeVariant::Int64.into(),
+            type_::Uint8(()) => TypeVariant::UInt8.into(),
+            type_::Uint16(()) => TypeVariant::UInt16.into(),
+            type_::Uint32(()) => TypeVariant::UInt32.into(),
+            type_::Uint64(()) => TypeVariant::UInt64.into(),
+            type_::Float32(()) => TypeVariant::Float32.into(),
+            type_::Float64(()) => TypeVariant::Float64.into(),
+            type_::Text(()) => TypeVariant::Text.into(),
+            type_::Data(()) => TypeVariant::Data.into(),
+            type_::List(l) => Type::list_of(
+                self.resolve(
+                    l.get_element_type()
+                        .expect("type was validated when loaded"),
+                ),
+            ),
+            type_::Enum(e) => TypeVariant::Enum(
+                self.enum_schema(e.get_type_id())
+                    .expect("type was validated when loaded"),
+            )
+            .into(),
+            type_::Struct(s) => TypeVariant::Struct(
+                self.struct_schema(s.get_type_id())
+                    .expect("type was validated when loaded"),
+            )
+            .into(),
+            type_::Interface(i) => TypeVariant::Capability(
+                self.interface_schema(i.get_type_id())
+                    .expect("type was validated when loaded"),
+            )
+            .into(),
+            type_::AnyPointer(_) => TypeVariant::AnyPointer.into(),
+        }
+    }
+}
+
+/// The kind of a node, as far as validation of references to it is concerned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    File,
+    Struct,
+    Enum,
+    Interface,
+    Const,
+    Annotation,
+}
+
+impl Kind {
+    fn of(node: node::Reader) -> Result<Self> {
+        Ok(match node.which()? {
+            node::File(()) => Self::File,
+            node::Struct(_) => Self::Struct,
+            node::Enum(_) => Self::Enum,
+            node::Interface(_) => Self::Interface,
+            node::Const(_) => Self::Const,
+            node::Annotation(_) => Self::Annotation,
+        })
+    }
+}
+
+fn invalid_node(node: node::Reader, reason: core::fmt::Arguments) -> Error {
+    let mut error = Error::from_kind(ErrorKind::InvalidSchemaNode(node.get_id()));
+    if let Ok(Ok(name)) = node.get_display_name().map(|n| n.to_str()) {
+        write!(error, "{name}: ");
+    }
+    error.write_fmt(reason);
+    error
+}
+
+fn require(
+    node: node::Reader,
+    id: u64,
+    expected: Kind,
+    kind_of: &dyn Fn(u64) -> Option<Kind>,
+) -> Result<()> {
+    match kind_of(id) {
+        None => {
+            let mut error = Error::from_kind(ErrorKind::SchemaNodeNotFound(id));
+            if let Ok(Ok(name)) = node.get_display_name().map(|n| n.to_str()) {
+                write!(error, "referenced by {name}");
+            }
+            Err(error)
+        }
+        Some(kind) if kind == expected => Ok(()),
+        Some(_) => Err(invalid_node(
+            node,
+            format_args!("node {id:#018x} does not have the expected kind"),
+        )),
+    }
+}
+
+fn validate_type(
+    node: node::Reader,
+    ty: type_::Reader,
+    kind_of: &dyn Fn(u64) -> Option<Kind>,
+) -> Result<()> {
+    match ty.which()? {
+        type_::List(l) => validate_type(node, l.get_element_type()?, kind_of),
+        type_::Enum(e) => require(node, e.get_type_id(), Kind::Enum, kind_of),
+        type_::Struct(s) => require(node, s.get_type_id(), Kind::Struct, kind_of),
+        type_::Interface(i) => require(node, i.get_type_id(), Kind::Interface, kind_of),
+        type_::AnyPointer(a) => {
+            a.which()?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_annotations(
+    node: node::Reader,
+    annotations: crate::struct_list::Reader<annotation::Owned>,
+    kind_of: &dyn Fn(u64) -> Option<Kind>,
+) -> Result<()> {
+    for annotation in annotations {
+        require(node, annotation.get_id(), Kind::Annotation, kind_of)?;
+        annotation.get_value()?.which()?;
+    }
+    Ok(())
+}
+
+/// Number of bits that a value of this type occupies in a struct's data section,
+/// or `None` if the type is stored in the pointer section.
+fn data_bits(ty: type_::Reader) -> Result<Option<u64>> {
+    Ok(Some(match ty.which()? {
+        type_::Void(()) => 0,
+        type_::Bool(()) => 1,
+        type_::Int8(()) | type_::Uint8(()) => 8,
+        type_::Int16(()) | type_::Uint16(()) | type_::Enum(_) => 16,
+        type_::Int32(()) | type_::Uint32(()) | type_::Float32(()) => 32,
+        type_::Int64(()) | type_::Uint64(()) | type_::Float64(()) => 64,
+        type_::Text(())
+        | type_::Data(())
+        | type_::List(_)
+        | type_::Struct(_)
+        | type_::Interface(_)
+        | type_::AnyPointer(_) => return Ok(None),
+    }))
+}
+
+/// Checks that everything `node` refers to is known, and that the layout it describes
+/// stays within the bounds of the struct it describes, since dynamic builders trust it.
+fn validate_node<'a>(
+    node: node::Reader,
+    kind_of: &dyn Fn(u64) -> Option<Kind>,
+    node_of: &dyn Fn(u64) -> Option<node::Reader<'a>>,
+) -> Result<()> {
+    validate_annotations(node, node.get_annotations()?, kind_of)?;
+    match node.which()? {
+        node::File(()) => {}
+        node::Struct(st) => {
+            let data_bits_available = u64::from(st.get_data_word_count()) * 64;
+            let pointer_count = u64::from(st.get_pointer_count());
+            let discriminant_count = st.get_discriminant_count();
+            if discriminant_count > 0
+                && (u64::from(st.get_discriminant_offset()) + 1) * 16 > data_bits_available
+            {
+                return Err(invalid_node(
+                    node,
+                    format_args!("discriminant is outside of the data section"),
+                ));
+            }
+            for field in st.get_fields()? {
+                let name = field.get_name()?.to_str()?;
+                validate_annotations(node, field.get_annotations()?, kind_of)?;
+                let discriminant = field.get_discriminant_value();
+                if discriminant != field::NO_DISCRIMINANT && discriminant >= discriminant_count {
+                    return Err(invalid_node(
+                        node,
+                        format_args!("field {name} has an out-of-range discriminant"),
+                    ));
+                }
+                match field.which()? {
+                    field::Slot(slot) => {
+                        let ty = slot.get_type()?;
+                        validate_type(node, ty, kind_of)?;
+                        slot.get_default_value()?.which()?;
+                        let offset = u64::from(slot.get_offset());
+                        let in_bounds = match data_bits(ty)? {
+                            Some(bits) => (offset + 1) * bits <= data_bits_available,
+                            None => offset < pointer_count,
+                        };
+                        if !in_bounds {
+                            return Err(invalid_node(
+                                node,
+                                format_args!("field {name} is outside of the struct"),
+                            ));
+                        }
+                    }
+                    field::Group(group) => {
+                        require(node, group.get_type_id(), Kind::Struct, kind_of)?;
+                        validate_group(node, st, name, group.get_type_id(), node_of)?;
+                    }
+                }
+            }
+        }
+        node::Enum(e) => {
+            for enumerant in e.get_enumerants()? {
+                validate_annotations(node, enumerant.get_annotations()?, kind_of)?;
+            }
+        }
+        node::Interface(interface) => {
+            for method in interface.get_methods()? {
+                validate_annotations(node, method.get_annotations()?, kind_of)?;
+                require(node, method.get_param_struct_type(), Kind::Struct, kind_of)?;
+                require(node, method.get_result_struct_type(), Kind::Struct, kind_of)?;
+            }
+            for superclass in interface.get_superclasses()? {
+                require(node, superclass.get_id(), Kind::Interface, kind_of)?;
+            }
+        }
+        node::Const(c) => {
+            validate_type(node, c.get_type()?, kind_of)?;
+            c.get_value()?.which()?;
+        }
+        node::Annotation(a) => validate_type(node, a.get_type()?, kind_of)?,
+    }
+    Ok(())
+}
+
+/// Checks that the node with ID `group_id` is a group of the struct `parent`, as the field
+/// `name`. Dynamic builders of a group share the struct builder of its parent, so the group
+/// must have exactly the same layout as its parent.
+fn validate_group<'a>(
+    parent: node::Reader,
+    parent_struct: node::struct_::Reader,
+    name: &str,
+    group_id: u64,
+    node_of: &dyn Fn(u64) -> Option<node::Reader<'a>>,
+) -> Result<()> {
+    let Some(group) = node_of(group_id) else {
+        return Err(Error::from_kind(ErrorKind::SchemaNodeNotFound(group_id)));
+    };
+    let node::Struct(group_struct) = group.which()? else {
+        return Err(invalid_node(
+            parent,
+            format_args!("group {name} is not a struct"),
+        ));
+    };
+    if !group_struct.get_is_group() || group.get_scope_id() != parent.get_id() {
+        return Err(invalid_node(
+            parent,
+            format_args!("node {group_id:#018x} is not a group of this struct"),
+        ));
+    }
+    if group_struct.get_data_word_count() != parent_struct.get_data_word_count()
+        || group_struct.get_pointer_count() != parent_struct.get_pointer_count()
+    {
+        return Err(invalid_node(
+            parent,
+            format_args!("group {name} does not have the same size as this struct"),
+        ));
+    }
+    if group_struct.get_discriminant_count() > 0
+        && (u64::from(group_struct.get_discriminant_offset()) + 1) * 16
+            > u64::from(parent_struct.get_data_word_count()) * 64
+    {
+        return Err(invalid_node(
+            parent,
+            format_args!("discriminant of group {name} is outside of the data section"),
+        ));
+    }
+    Ok(())
+}
+
+fn raw_struct_schema(
+    encoded_node: &'static [Word],
+    fields: crate::struct_list::Reader<field::Owned>,
+) -> RawStructSchema {
+    let mut nonunion_members = Vec::new();
+    let mut members_by_discriminant = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let discriminant = field.get_discriminant_value();
+        if discriminant == field::NO_DISCRIMINANT {
+            nonunion_members.push(index as u16);
+        } else {
+            let discriminant = discriminant as usize;
+            if members_by_discriminant.len() <= discriminant {
+                members_by_discriminant.resize(discriminant + 1, 0);
+            }
+            members_by_discriminant[discriminant] = index as u16;
+        }
+    }
+    RawStructSchema {
+        encoded_node,
+        nonunion_members: Box::leak(nonunion_members.into_boxed_slice()),
+        members_by_discriminant: Box::leak(members_by_discriminant.into_boxed_slice()),
+    }
+}
+
+/// Copies `node` into a single segment in canonical form.
+fn encode_node(node: node::Reader) -> Result<Vec<Word>> {
+    let size = node.total_size()?.word_count + 1;
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(size as u32));
+    message.set_root_canonical(node)?;
+    let output = message.get_segments_for_output()[0];
+    let mut result = Word::allocate_zeroed_vec(output.len() / BYTES_PER_WORD);
+    Word::words_to_bytes_mut(&mut result[..]).copy_from_slice(output);
+    Ok(result)
+}
+
+fn decode_node(encoded_node: &'static [Word]) -> node::Reader<'static> {
+    crate::any_pointer::Reader::new(unsafe {
+        layout::PointerReader::get_root_unchecked(encoded_node.as_ptr() as *const u8)
+    })
+    .get_as()
+    .unwrap()
+}
@@ -9,11 +9,11 @@ pub mod node {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -52,11 +52,11 @@ pub mod node {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -211,11 +211,11 @@ pub mod node {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -233,6 +233,16 @@ pub mod node {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -291,10 +301,37 @@ pub mod node {
             self.builder.reborrow().get_pointer_field(0).set_text(value);
         }
         #[inline]
+        pub fn try_set_display_name(
+            &mut self,
+            value: crate::text::Reader<'_>,
+        ) -> crate::Result<()> {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_display_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_display_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_display_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_display_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_display_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -345,6 +382,40 @@ pub mod node {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_nested_nodes(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::nested_node::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_nested_nodes(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_nested_nodes(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned>,
+        > {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_nested_nodes(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -377,6 +448,38 @@ pub mod node {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(2),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(2)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(2)
         }
@@ -464,6 +567,40 @@ pub mod node {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
         }
         #[inline]
+        pub fn try_init_parameters(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(5),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_parameters(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(5)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_parameters(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(5).disown())
+        }
+        #[inline]
         pub fn has_parameters(&self) -> bool {
             !self.builder.is_pointer_field_null(5)
         }
@@ -791,11 +928,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -834,11 +973,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -921,11 +1062,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -946,6 +1089,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -996,10 +1149,34 @@ pub mod node {
                 self.builder.reborrow().get_pointer_field(0).set_text(value);
             }
             #[inline]
+            pub fn try_set_name(&mut self, value: crate::text::Reader<'_>) -> crate::Result<()> {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .try_set_text(value)
+            }
+            #[inline]
             pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_name(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -1081,11 +1258,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -1124,11 +1303,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -1215,11 +1396,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -1240,6 +1423,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -1290,10 +1483,34 @@ pub mod node {
                 self.builder.reborrow().get_pointer_field(0).set_text(value);
             }
             #[inline]
+            pub fn try_set_name(&mut self, value: crate::text::Reader<'_>) -> crate::Result<()> {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .try_set_text(value)
+            }
+            #[inline]
             pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_name(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -1399,11 +1616,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -1442,11 +1661,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -1551,11 +1772,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -1576,6 +1799,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -1634,10 +1867,40 @@ pub mod node {
                 self.builder.reborrow().get_pointer_field(0).set_text(value);
             }
             #[inline]
+            pub fn try_set_doc_comment(
+                &mut self,
+                value: crate::text::Reader<'_>,
+            ) -> crate::Result<()> {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .try_set_text(value)
+            }
+            #[inline]
             pub fn init_doc_comment(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_doc_comment(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_doc_comment(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_doc_comment(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_doc_comment(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -1683,6 +1946,45 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_members(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<
+                    'a,
+                    crate::schema_capnp::node::source_info::member::Owned,
+                >,
+            > {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(1),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_members(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<
+                        crate::schema_capnp::node::source_info::member::Owned,
+                    >,
+                >,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(1)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_members(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::source_info::member::Owned>,
+            > {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+            }
+            #[inline]
             pub fn has_members(&self) -> bool {
                 !self.builder.is_pointer_field_null(1)
             }
@@ -1806,11 +2108,11 @@ pub mod node {
             impl crate::introspect::Introspect for Owned {
                 fn introspect() -> crate::introspect::Type {
                     crate::introspect::TypeVariant::Struct(
-                        crate::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     )
                     .into()
                 }
@@ -1851,11 +2153,11 @@ pub mod node {
                     Self::Struct(crate::dynamic_struct::Reader::new(
                         reader.reader,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -1940,11 +2242,11 @@ pub mod node {
                     Self::Struct(crate::dynamic_struct::Builder::new(
                         builder.builder,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -1966,6 +2268,16 @@ pub mod node {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -2019,10 +2331,45 @@ pub mod node {
                     self.builder.reborrow().get_pointer_field(0).set_text(value);
                 }
                 #[inline]
+                pub fn try_set_doc_comment(
+                    &mut self,
+                    value: crate::text::Reader<'_>,
+                ) -> crate::Result<()> {
+                    self.builder
+                        .reborrow()
+                        .get_pointer_field(0)
+                        .try_set_text(value)
+                }
+                #[inline]
                 pub fn init_doc_comment(self, size: u32) -> crate::text::Builder<'a> {
                     self.builder.get_pointer_field(0).init_text(size)
                 }
                 #[inline]
+                pub fn try_init_doc_comment(
+                    self,
+                    size: u32,
+                ) -> crate::Result<crate::text::Builder<'a>> {
+                    self.builder.get_pointer_field(0).try_init_text(size)
+                }
+                #[cfg(target_has_atomic = "ptr")]
+                #[inline]
+                pub fn adopt_doc_comment(
+                    &mut self,
+                    orphan: crate::orphan::Orphan<crate::text::Owned>,
+                ) {
+                    self.builder
+                        .reborrow()
+                        .get_pointer_field(0)
+                        .adopt(orphan.into_internal());
+                }
+                #[cfg(target_has_atomic = "ptr")]
+                #[inline]
+                pub fn disown_doc_comment(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                    crate::orphan::Orphan::new(
+                        self.builder.reborrow().get_pointer_field(0).disown(),
+                    )
+                }
+                #[inline]
                 pub fn has_doc_comment(&self) -> bool {
                     !self.builder.is_pointer_field_null(0)
                 }
@@ -2107,11 +2454,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -2150,11 +2499,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -2267,11 +2618,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -2292,6 +2645,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -2413,6 +2776,38 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_fields(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::field::Owned>>
+            {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_fields(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::field::Owned>,
+                >,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(3)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_fields(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::field::Owned>>
+            {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+            }
+            #[inline]
             pub fn has_fields(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -2601,11 +2996,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -2644,11 +3041,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -2734,11 +3133,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -2759,6 +3160,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -2830,6 +3241,39 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_enumerants(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::enumerant::Owned>>
+            {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_enumerants(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned>,
+                >,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(3)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_enumerants(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned>,
+            > {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+            }
+            #[inline]
             pub fn has_enumerants(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -2914,11 +3358,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -2957,11 +3403,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -3061,11 +3509,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -3086,6 +3536,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -3156,6 +3616,38 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_methods(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::method::Owned>>
+            {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_methods(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::method::Owned>,
+                >,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(3)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_methods(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::method::Owned>>
+            {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+            }
+            #[inline]
             pub fn has_methods(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -3193,6 +3685,40 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_superclasses(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<'a, crate::schema_capnp::superclass::Owned>,
+            > {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(4),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_superclasses(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::superclass::Owned>,
+                >,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(4)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_superclasses(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::superclass::Owned>,
+            > {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(4).disown())
+            }
+            #[inline]
             pub fn has_superclasses(&self) -> bool {
                 !self.builder.is_pointer_field_null(4)
             }
@@ -3298,11 +3824,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -3341,11 +3869,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -3439,11 +3969,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -3464,6 +3996,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -3528,6 +4070,31 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(3)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -3557,6 +4124,31 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(4),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_value(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::value::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(4)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_value(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::value::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(4).disown())
+            }
+            #[inline]
             pub fn has_value(&self) -> bool {
                 !self.builder.is_pointer_field_null(4)
             }
@@ -3659,11 +4251,13 @@ pub mod node {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -3702,11 +4296,13 @@ pub mod node {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -3837,11 +4433,13 @@ pub mod node {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -3862,6 +4460,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -3926,6 +4534,31 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(3)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -4316,11 +4949,11 @@ pub mod field {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -4359,11 +4992,11 @@ pub mod field {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -4480,11 +5113,11 @@ pub mod field {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -4502,6 +5135,16 @@ pub mod field {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -4552,10 +5195,34 @@ pub mod field {
             self.builder.reborrow().get_pointer_field(0).set_text(value);
         }
         #[inline]
+        pub fn try_set_name(&mut self, value: crate::text::Reader<'_>) -> crate::Result<()> {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -4596,6 +5263,38 @@ pub mod field {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -4820,11 +5519,13 @@ pub mod field {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -4863,11 +5564,13 @@ pub mod field {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -4971,11 +5674,13 @@ pub mod field {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -4996,6 +5701,16 @@ pub mod field {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -5068,6 +5783,31 @@ pub mod field {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(2),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(2)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(2)
             }
@@ -5099,6 +5839,33 @@ pub mod field {
                 )
             }
             #[inline]
+            pub fn try_init_default_value(
+                self,
+            ) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_default_value(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::value::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(3)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_default_value(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::value::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+            }
+            #[inline]
             pub fn has_default_value(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -5244,11 +6011,13 @@ pub mod field {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -5287,11 +6056,13 @@ pub mod field {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -5367,11 +6138,13 @@ pub mod field {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -5392,6 +6165,16 @@ pub mod field {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -5517,11 +6300,13 @@ pub mod field {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -5560,11 +6345,13 @@ pub mod field {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -5644,11 +6431,13 @@ pub mod field {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -5669,6 +6458,16 @@ pub mod field {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -5828,11 +6627,11 @@ pub mod enumerant {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -5871,11 +6670,11 @@ pub mod enumerant {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -5976,11 +6775,11 @@ pub mod enumerant {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -5998,6 +6797,16 @@ pub mod enumerant {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -6048,10 +6857,34 @@ pub mod enumerant {
             self.builder.reborrow().get_pointer_field(0).set_text(value);
         }
         #[inline]
+        pub fn try_set_name(&mut self, value: crate::text::Reader<'_>) -> crate::Result<()> {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6092,6 +6925,38 @@ pub mod enumerant {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -6210,11 +7075,11 @@ pub mod superclass {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -6253,11 +7118,11 @@ pub mod superclass {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -6344,11 +7209,11 @@ pub mod superclass {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -6366,6 +7231,16 @@ pub mod superclass {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -6435,6 +7310,29 @@ pub mod superclass {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_brand(&mut self) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6538,11 +7436,11 @@ pub mod method {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -6581,11 +7479,11 @@ pub mod method {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -6731,11 +7629,11 @@ pub mod method {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -6753,6 +7651,16 @@ pub mod method {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -6803,10 +7711,34 @@ pub mod method {
             self.builder.reborrow().get_pointer_field(0).set_text(value);
         }
         #[inline]
+        pub fn try_set_name(&mut self, value: crate::text::Reader<'_>) -> crate::Result<()> {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6863,6 +7795,38 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -6889,6 +7853,33 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
         }
         #[inline]
+        pub fn try_init_param_brand(
+            self,
+        ) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(2),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_param_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(2)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_param_brand(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+        }
+        #[inline]
         pub fn has_param_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(2)
         }
@@ -6915,6 +7906,33 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
         }
         #[inline]
+        pub fn try_init_result_brand(
+            self,
+        ) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(3),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_result_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(3)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_result_brand(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+        }
+        #[inline]
         pub fn has_result_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(3)
         }
@@ -6941,11 +7959,45 @@ pub mod method {
             )
         }
         #[inline]
-        pub fn init_implicit_parameters(
-            self,
-            size: u32,
-        ) -> crate::struct_list::Builder<'a, crate::schema_capnp::node::parameter::Owned> {
-            crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+        pub fn init_implicit_parameters(
+            self,
+            size: u32,
+        ) -> crate::struct_list::Builder<'a, crate::schema_capnp::node::parameter::Owned> {
+            crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+        }
+        #[inline]
+        pub fn try_init_implicit_parameters(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(4),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_implicit_parameters(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(4)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_implicit_parameters(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(4).disown())
         }
         #[inline]
         pub fn has_implicit_parameters(&self) -> bool {
@@ -7169,11 +8221,11 @@ pub mod type_ {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -7212,11 +8264,11 @@ pub mod type_ {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -7313,11 +8365,11 @@ pub mod type_ {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -7335,6 +8387,16 @@ pub mod type_ {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -7859,11 +8921,13 @@ pub mod type_ {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -7902,11 +8966,13 @@ pub mod type_ {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -7989,11 +9055,13 @@ pub mod type_ {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8014,6 +9082,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -8080,6 +9158,33 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_element_type(
+                self,
+            ) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_element_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_element_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_element_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -8164,11 +9269,13 @@ pub mod type_ {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -8207,11 +9314,13 @@ pub mod type_ {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8298,11 +9407,13 @@ pub mod type_ {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8323,6 +9434,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -8395,6 +9516,31 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_brand(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_brand(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_brand(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -8494,11 +9640,13 @@ pub mod type_ {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -8537,11 +9685,13 @@ pub mod type_ {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8628,11 +9778,13 @@ pub mod type_ {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8653,6 +9805,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -8725,6 +9887,31 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_brand(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_brand(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_brand(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -8825,11 +10012,13 @@ pub mod type_ {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -8868,11 +10057,13 @@ pub mod type_ {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8959,11 +10150,13 @@ pub mod type_ {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -8984,6 +10177,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -9056,6 +10259,31 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_brand(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_brand(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_brand(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -9158,11 +10386,13 @@ pub mod type_ {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -9201,11 +10431,13 @@ pub mod type_ {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -9286,11 +10518,13 @@ pub mod type_ {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -9311,6 +10545,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -9493,11 +10737,11 @@ pub mod type_ {
             impl crate::introspect::Introspect for Owned {
                 fn introspect() -> crate::introspect::Type {
                     crate::introspect::TypeVariant::Struct(
-                        crate::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     )
                     .into()
                 }
@@ -9538,11 +10782,11 @@ pub mod type_ {
                     Self::Struct(crate::dynamic_struct::Reader::new(
                         reader.reader,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -9626,11 +10870,11 @@ pub mod type_ {
                     Self::Struct(crate::dynamic_struct::Builder::new(
                         builder.builder,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -9652,6 +10896,16 @@ pub mod type_ {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -9856,11 +11110,11 @@ pub mod type_ {
             impl crate::introspect::Introspect for Owned {
                 fn introspect() -> crate::introspect::Type {
                     crate::introspect::TypeVariant::Struct(
-                        crate::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     )
                     .into()
                 }
@@ -9901,11 +11155,11 @@ pub mod type_ {
                     Self::Struct(crate::dynamic_struct::Reader::new(
                         reader.reader,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -9987,11 +11241,11 @@ pub mod type_ {
                     Self::Struct(crate::dynamic_struct::Builder::new(
                         builder.builder,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -10013,6 +11267,16 @@ pub mod type_ {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -10166,11 +11430,11 @@ pub mod type_ {
             impl crate::introspect::Introspect for Owned {
                 fn introspect() -> crate::introspect::Type {
                     crate::introspect::TypeVariant::Struct(
-                        crate::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     )
                     .into()
                 }
@@ -10211,11 +11475,11 @@ pub mod type_ {
                     Self::Struct(crate::dynamic_struct::Reader::new(
                         reader.reader,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -10293,11 +11557,11 @@ pub mod type_ {
                     Self::Struct(crate::dynamic_struct::Builder::new(
                         builder.builder,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -10319,6 +11583,16 @@ pub mod type_ {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -10451,11 +11725,11 @@ pub mod brand {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -10494,11 +11768,11 @@ pub mod brand {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -10584,11 +11858,11 @@ pub mod brand {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -10606,6 +11880,16 @@ pub mod brand {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -10673,6 +11957,39 @@ pub mod brand {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
         }
         #[inline]
+        pub fn try_init_scopes(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::brand::scope::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_scopes(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_scopes(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned>,
+        > {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_scopes(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -10764,11 +12081,13 @@ pub mod brand {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -10807,11 +12126,13 @@ pub mod brand {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -10907,11 +12228,13 @@ pub mod brand {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -10932,6 +12255,16 @@ pub mod brand {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -11003,6 +12336,46 @@ pub mod brand {
                 )
             }
             #[inline]
+            pub fn try_init_bind(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<'a, crate::schema_capnp::brand::binding::Owned>,
+            > {
+                self.builder.set_data_field::<u16>(4, 0);
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_bind(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned>,
+                >,
+            ) {
+                self.builder.set_data_field::<u16>(4, 0);
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_bind(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned>,
+            > {
+                assert!(
+                    self.builder.get_data_field::<u16>(4) == 0,
+                    "disown_bind() called when bind is not the active union member"
+                );
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_bind(&self) -> bool {
                 if self.builder.get_data_field::<u16>(4) != 0 {
                     return false;
@@ -11156,11 +12529,13 @@ pub mod brand {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -11199,11 +12574,13 @@ pub mod brand {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -11295,11 +12672,13 @@ pub mod brand {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -11320,6 +12699,16 @@ pub mod brand {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -11383,6 +12772,37 @@ pub mod brand {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                self.builder.set_data_field::<u16>(0, 1);
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) {
+                self.builder.set_data_field::<u16>(0, 1);
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                assert!(
+                    self.builder.get_data_field::<u16>(0) == 1,
+                    "disown_type() called when type is not the active union member"
+                );
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 if self.builder.get_data_field::<u16>(0) != 1 {
                     return false;
@@ -11508,11 +12928,11 @@ pub mod value {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -11551,11 +12971,11 @@ pub mod value {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -11703,11 +13123,11 @@ pub mod value {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -11725,6 +13145,16 @@ pub mod value {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -11828,11 +13258,42 @@ pub mod value {
             self.builder.reborrow().get_pointer_field(0).set_text(value);
         }
         #[inline]
+        pub fn try_set_text(&mut self, value: crate::text::Reader<'_>) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 12);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_text(value)
+        }
+        #[inline]
         pub fn init_text(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.set_data_field::<u16>(0, 12);
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_text(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 12);
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_text(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+            self.builder.set_data_field::<u16>(0, 12);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_text(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 12,
+                "disown_text() called when text is not the active union member"
+            );
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_text(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 12 {
                 return false;
@@ -11845,11 +13306,42 @@ pub mod value {
             self.builder.reborrow().get_pointer_field(0).set_data(value);
         }
         #[inline]
+        pub fn try_set_data(&mut self, value: crate::data::Reader<'_>) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 13);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_data(value)
+        }
+        #[inline]
         pub fn init_data(self, size: u32) -> crate::data::Builder<'a> {
             self.builder.set_data_field::<u16>(0, 13);
             self.builder.get_pointer_field(0).init_data(size)
         }
         #[inline]
+        pub fn try_init_data(self, size: u32) -> crate::Result<crate::data::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 13);
+            self.builder.get_pointer_field(0).try_init_data(size)
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_data(&mut self, orphan: crate::orphan::Orphan<crate::data::Owned>) {
+            self.builder.set_data_field::<u16>(0, 13);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_data(&mut self) -> crate::orphan::Orphan<crate::data::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 13,
+                "disown_data() called when data is not the active union member"
+            );
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_data(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 13 {
                 return false;
@@ -11863,6 +13355,24 @@ pub mod value {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_list(&mut self, orphan: crate::orphan::Orphan<crate::any_pointer::Owned>) {
+            self.builder.set_data_field::<u16>(0, 14);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_list(&mut self) -> crate::orphan::Orphan<crate::any_pointer::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 14,
+                "disown_list() called when list is not the active union member"
+            );
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_list(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 14 {
@@ -11882,6 +13392,24 @@ pub mod value {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_struct(&mut self, orphan: crate::orphan::Orphan<crate::any_pointer::Owned>) {
+            self.builder.set_data_field::<u16>(0, 16);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_struct(&mut self) -> crate::orphan::Orphan<crate::any_pointer::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 16,
+                "disown_struct() called when struct is not the active union member"
+            );
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_struct(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 16 {
@@ -11900,6 +13428,27 @@ pub mod value {
             result.clear();
             result
         }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_any_pointer(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::any_pointer::Owned>,
+        ) {
+            self.builder.set_data_field::<u16>(0, 18);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_any_pointer(&mut self) -> crate::orphan::Orphan<crate::any_pointer::Owned> {
+            assert!(
+                self.builder.get_data_field::<u16>(0) == 18,
+                "disown_any_pointer() called when any_pointer is not the active union member"
+            );
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
         #[inline]
         pub fn has_any_pointer(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 18 {
@@ -12352,11 +13901,11 @@ pub mod annotation {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -12395,11 +13944,11 @@ pub mod annotation {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -12497,11 +14046,11 @@ pub mod annotation {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -12519,6 +14068,16 @@ pub mod annotation {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -12588,6 +14147,29 @@ pub mod annotation {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_value(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::value::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_value(&mut self) -> crate::orphan::Orphan<crate::schema_capnp::value::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_value(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -12614,6 +14196,29 @@ pub mod annotation {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
         }
         #[inline]
+        pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_brand(&mut self) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -12749,10 +14354,10 @@ pub enum ElementSize {
 
 impl crate::introspect::Introspect for ElementSize {
     fn introspect() -> crate::introspect::Type {
-        crate::introspect::TypeVariant::Enum(crate::introspect::RawEnumSchema {
-            encoded_node: &element_size::ENCODED_NODE,
-            annotation_types: element_size::get_annotation_types,
-        })
+        crate::introspect::TypeVariant::Enum(crate::introspect::RawEnumSchema::new(
+            &element_size::ENCODED_NODE,
+            element_size::get_annotation_types,
+        ))
         .into()
     }
 }
@@ -12760,10 +14365,10 @@ impl<'a> ::core::convert::From<ElementSize> for crate::dynamic_value::Reader<'a>
     fn from(e: ElementSize) -> Self {
         crate::dynamic_value::Enum::new(
             e.into(),
-            crate::introspect::RawEnumSchema {
-                encoded_node: &element_size::ENCODED_NODE,
-                annotation_types: element_size::get_annotation_types,
-            }
+            crate::introspect::RawEnumSchema::new(
+                &element_size::ENCODED_NODE,
+                element_size::get_annotation_types,
+            )
             .into(),
         )
         .into()
@@ -12863,11 +14468,11 @@ pub mod capnp_version {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -12906,11 +14511,11 @@ pub mod capnp_version {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -12994,11 +14599,11 @@ pub mod capnp_version {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -13016,6 +14621,16 @@ pub mod capnp_version {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -13188,11 +14803,11 @@ pub mod code_generator_request {
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
         fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema::new(
+                &_private::RAW_SCHEMA,
+                _private::get_field_types,
+                _private::get_annotation_types,
+            ))
             .into()
         }
     }
@@ -13231,11 +14846,11 @@ pub mod code_generator_request {
         fn from(reader: Reader<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Reader::new(
                 reader.reader,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -13367,11 +14982,11 @@ pub mod code_generator_request {
         fn from(builder: Builder<'a>) -> Self {
             Self::Struct(crate::dynamic_struct::Builder::new(
                 builder.builder,
-                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                }),
+                crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema::new(
+                    &_private::RAW_SCHEMA,
+                    _private::get_field_types,
+                    _private::get_annotation_types,
+                )),
             ))
         }
     }
@@ -13389,6 +15004,16 @@ pub mod code_generator_request {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -13456,6 +15081,38 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
         }
         #[inline]
+        pub fn try_init_nodes(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::node::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_nodes(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_nodes(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::Owned>>
+        {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
         pub fn has_nodes(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -13498,6 +15155,47 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_requested_files(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<
+                'a,
+                crate::schema_capnp::code_generator_request::requested_file::Owned,
+            >,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_requested_files(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<
+                    crate::schema_capnp::code_generator_request::requested_file::Owned,
+                >,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(1)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_requested_files(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<
+                crate::schema_capnp::code_generator_request::requested_file::Owned,
+            >,
+        > {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+        }
+        #[inline]
         pub fn has_requested_files(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -13526,6 +15224,33 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
         }
         #[inline]
+        pub fn try_init_capnp_version(
+            self,
+        ) -> crate::Result<crate::schema_capnp::capnp_version::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(2),
+                0,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_capnp_version(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::capnp_version::Owned>,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(2)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_capnp_version(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::schema_capnp::capnp_version::Owned> {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+        }
+        #[inline]
         pub fn has_capnp_version(&self) -> bool {
             !self.builder.is_pointer_field_null(2)
         }
@@ -13560,6 +15285,40 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
         }
         #[inline]
+        pub fn try_init_source_info(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::source_info::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(3),
+                size,
+            )
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn adopt_source_info(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned>,
+            >,
+        ) {
+            self.builder
+                .reborrow()
+                .get_pointer_field(3)
+                .adopt(orphan.into_internal());
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        pub fn disown_source_info(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned>,
+        > {
+            crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+        }
+        #[inline]
         pub fn has_source_info(&self) -> bool {
             !self.builder.is_pointer_field_null(3)
         }
@@ -13712,11 +15471,13 @@ pub mod code_generator_request {
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
             fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema::new(
+                        &_private::RAW_SCHEMA,
+                        _private::get_field_types,
+                        _private::get_annotation_types,
+                    ),
+                )
                 .into()
             }
         }
@@ -13755,11 +15516,13 @@ pub mod code_generator_request {
             fn from(reader: Reader<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Reader::new(
                     reader.reader,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -13864,11 +15627,13 @@ pub mod code_generator_request {
             fn from(builder: Builder<'a>) -> Self {
                 Self::Struct(crate::dynamic_struct::Builder::new(
                     builder.builder,
-                    crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema {
-                        generic: &_private::RAW_SCHEMA,
-                        field_types: _private::get_field_types,
-                        annotation_types: _private::get_annotation_types,
-                    }),
+                    crate::schema::StructSchema::new(
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
+                    ),
                 ))
             }
         }
@@ -13889,6 +15654,16 @@ pub mod code_generator_request {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -13947,10 +15722,37 @@ pub mod code_generator_request {
                 self.builder.reborrow().get_pointer_field(0).set_text(value);
             }
             #[inline]
+            pub fn try_set_filename(
+                &mut self,
+                value: crate::text::Reader<'_>,
+            ) -> crate::Result<()> {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .try_set_text(value)
+            }
+            #[inline]
             pub fn init_filename(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_filename(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_filename(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(0)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_filename(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+            }
+            #[inline]
             pub fn has_filename(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -13996,6 +15798,47 @@ pub mod code_generator_request {
                 )
             }
             #[inline]
+            pub fn try_init_imports(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<
+                    'a,
+                    crate::schema_capnp::code_generator_request::requested_file::import::Owned,
+                >,
+            > {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(1),
+                    size,
+                )
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn adopt_imports(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<
+                        crate::schema_capnp::code_generator_request::requested_file::import::Owned,
+                    >,
+                >,
+            ) {
+                self.builder
+                    .reborrow()
+                    .get_pointer_field(1)
+                    .adopt(orphan.into_internal());
+            }
+            #[cfg(target_has_atomic = "ptr")]
+            #[inline]
+            pub fn disown_imports(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<
+                    crate::schema_capnp::code_generator_request::requested_file::import::Owned,
+                >,
+            > {
+                crate::orphan::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+            }
+            #[inline]
             pub fn has_imports(&self) -> bool {
                 !self.builder.is_pointer_field_null(1)
             }
@@ -14121,11 +15964,11 @@ pub mod code_generator_request {
             impl crate::introspect::Introspect for Owned {
                 fn introspect() -> crate::introspect::Type {
                     crate::introspect::TypeVariant::Struct(
-                        crate::introspect::RawBrandedStructSchema {
-                            generic: &_private::RAW_SCHEMA,
-                            field_types: _private::get_field_types,
-                            annotation_types: _private::get_annotation_types,
-                        },
+                        crate::introspect::RawBrandedStructSchema::new(
+                            &_private::RAW_SCHEMA,
+                            _private::get_field_types,
+                            _private::get_annotation_types,
+                        ),
                     )
                     .into()
                 }
@@ -14166,11 +16009,11 @@ pub mod code_generator_request {
                     Self::Struct(crate::dynamic_struct::Reader::new(
                         reader.reader,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -14259,11 +16102,11 @@ pub mod code_generator_request {
                     Self::Struct(crate::dynamic_struct::Builder::new(
                         builder.builder,
                         crate::schema::StructSchema::new(
-                            crate::introspect::RawBrandedStructSchema {
-                                generic: &_private::RAW_SCHEMA,
-                                field_types: _private::get_field_types,
-                                annotation_types: _private::get_annotation_types,
-                            },
+                            crate::introspect::RawBrandedStructSchema::new(
+                                &_private::RAW_SCHEMA,
+                                _private::get_field_types,
+                                _private::get_annotation_types,
+                            ),
                         ),
                     ))
                 }
@@ -14285,6 +16128,16 @@ pub mod code_generator_request {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -14346,10 +16199,39 @@ pub mod code_generator_request {
                     self.builder.reborrow().get_pointer_field(0).set_text(value);
                 }
                 #[inline]
+                pub fn try_set_name(
+                    &mut self,
+                    value: crate::text::Reader<'_>,
+                ) -> crate::Result<()> {
+                    self.builder
+                        .reborrow()
+                        .get_pointer_field(0)
+                        .try_set_text(value)
+                }
+                #[inline]
                 pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
                     self.builder.get_pointer_field(0).init_text(size)
                 }
                 #[inline]
+                pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                    self.builder.get_pointer_field(0).try_init_text(size)
+                }
+                #[cfg(target_has_atomic = "ptr")]
+                #[inline]
+                pub fn adopt_name(&mut self, orphan: crate::orphan::Orphan<crate::text::Owned>) {
+                    self.builder
+                        .reborrow()
+                        .get_pointer_field(0)
+                        .adopt(orphan.into_internal());
+                }
+                #[cfg(target_has_atomic = "ptr")]
+                #[inline]
+                pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                    crate::orphan::Orphan::new(
+                        self.builder.reborrow().get_pointer_field(0).disown(),
+                    )
+                }
+                #[inline]
                 pub fn has_name(&self) -> bool {
                     !self.builder.is_pointer_field_null(0)
                 }
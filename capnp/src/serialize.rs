@@ -26,6 +26,18 @@
 mod no_alloc_buffer_segments;
 pub use no_alloc_buffer_segments::{NoAllocBufferSegments, NoAllocSliceSegments};
 
+#[cfg(feature = "alloc")]
+mod message_log;
+#[cfg(feature = "alloc")]
+pub use message_log::{MessageFile, MessageIndex, MessageLogAppender};
+#[cfg(feature = "std")]
+pub use message_log::{MessageLog, MessageLogIter};
+
+#[cfg(feature = "mmap")]
+mod mmap_segments;
+#[cfg(feature = "mmap")]
+pub use mmap_segments::MmapSegments;
+
 #[cfg(feature = "alloc")]
 use crate::io::{Read, Write};
 #[cfg(feature = "alloc")]
@@ -46,7 +58,7 @@ pub const SEGMENTS_COUNT_LIMIT: usize = 512;
 
 /// Segments read from a single flat slice of words.
 #[cfg(feature = "alloc")]
-type SliceSegments<'a> = BufferSegments<&'a [u8]>;
+pub type SliceSegments<'a> = BufferSegments<&'a [u8]>;
 
 /// Reads a serialized message (including a segment table) from a flat slice of bytes, without copying.
 /// The slice is allowed to extend beyond the end of the message. On success, updates `slice` to point
@@ -83,6 +95,85 @@ pub fn read_message_from_flat_slice<'a>(
     }
 }
 
+/// Iterates over the serialized messages stored back to back in a flat slice of bytes, yielding
+/// a reader for each one without copying.
+///
+/// After an error, the iterator is exhausted.
+///
+/// ALIGNMENT: If the "unaligned" feature is enabled, then there are no alignment requirements on the
+/// slice. Otherwise, it must be 8-byte aligned (attempts to read the messages will trigger errors).
+#[cfg(feature = "alloc")]
+pub struct SliceMessageIter<'a> {
+    slice: &'a [u8],
+    options: message::ReaderOptions,
+    stop_at_truncated_message: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> SliceMessageIter<'a> {
+    pub fn new(slice: &'a [u8], options: message::ReaderOptions) -> Self {
+        Self {
+            slice,
+            options,
+            stop_at_truncated_message: false,
+        }
+    }
+
+    /// If true, a message at the end of the slice that is cut off partway through ends the
+    /// iteration instead of producing an error. This is useful for reading a log that is still
+    /// being written to: the incomplete message is left in [`remainder()`](Self::remainder),
+    /// and iteration can be resumed from there once more data is available.
+    pub fn stop_at_truncated_message(mut self, value: bool) -> Self {
+        self.stop_at_truncated_message = value;
+        self
+    }
+
+    /// Returns the bytes that have not been consumed yet.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.slice
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for SliceMessageIter<'a> {
+    type Item = Result<message::Reader<SliceSegments<'a>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty()
+            || (self.stop_at_truncated_message && is_truncated_message(self.slice))
+        {
+            return None;
+        }
+        let result = read_message_from_flat_slice(&mut self.slice, self.options);
+        if result.is_err() {
+            self.slice = &[];
+        }
+        Some(result)
+    }
+}
+
+/// Returns true if `slice` is too short to hold the message whose segment table it starts with.
+#[cfg(feature = "alloc")]
+fn is_truncated_message(slice: &[u8]) -> bool {
+    if slice.len() < BYTES_PER_WORD {
+        return true;
+    }
+    let segment_count = u32::from_le_bytes(slice[0..4].try_into().unwrap()) as usize + 1;
+    if segment_count >= SEGMENTS_COUNT_LIMIT {
+        // Not truncated, just invalid.
+        return false;
+    }
+    let table_len = (segment_count / 2 + 1) * BYTES_PER_WORD;
+    if slice.len() < table_len {
+        return true;
+    }
+    let body_words: u64 = slice[4..(segment_count + 1) * 4]
+        .chunks_exact(4)
+        .map(|len| u64::from(u32::from_le_bytes(len.try_into().unwrap())))
+        .sum();
+    (((slice.len() - table_len) / BYTES_PER_WORD) as u64) < body_words
+}
+
 /// Reads a serialized message (including a segment table) from a flat slice of bytes, without copying.
 /// The slice is allowed to extend beyond the end of the message. On success, updates `slice` to point
 /// to the remaining bytes beyond the end of the message.
@@ -396,6 +487,123 @@ where
     Ok(crate::message::Reader::new(owned_segments, options))
 }
 
+/// Decodes messages from bytes that are pushed into it in arbitrarily sized chunks, for use with
+/// non-blocking IO where there is no `Read` to pull from.
+///
+/// ```
+/// use capnp::{message, serialize};
+///
+/// let mut builder = message::Builder::new_default();
+/// builder.set_root("hello").unwrap();
+/// let bytes = serialize::write_message_to_words(&builder);
+///
+/// let mut decoder = serialize::MessageDecoder::new(message::ReaderOptions::new());
+/// let (first, second) = bytes.split_at(5);
+/// assert!(decoder.decode(&mut &first[..]).unwrap().is_none());
+/// assert_eq!(decoder.bytes_needed(), 3);
+/// let message = decoder.decode(&mut &second[..]).unwrap().unwrap();
+/// assert_eq!(message.get_root::<capnp::text::Reader>().unwrap(), "hello");
+/// ```
+///
+/// After an error, the decoder is left in an unspecified state and should be discarded.
+#[cfg(feature = "alloc")]
+pub struct MessageDecoder {
+    options: message::ReaderOptions,
+
+    // The segment table, as far as it has been received.
+    table: Vec<u8>,
+
+    // Length of the segment table in bytes, once its first word has been received.
+    table_len: usize,
+
+    // Once the segment table is complete, the segments being received and the number of
+    // bytes of them received so far.
+    body: Option<(OwnedSegments, usize)>,
+}
+
+#[cfg(feature = "alloc")]
+impl MessageDecoder {
+    pub fn new(options: message::ReaderOptions) -> Self {
+        Self {
+            options,
+            table: Vec::new(),
+            table_len: 0,
+            body: None,
+        }
+    }
+
+    /// Consumes bytes from the front of `input` until either `input` is empty or a message is
+    /// complete, in which case the message is returned. Any bytes beyond the end of the message
+    /// are left in `input`, so callers should keep calling this until `input` is empty.
+    pub fn decode(&mut self, input: &mut &[u8]) -> Result<Option<message::Reader<OwnedSegments>>> {
+        if self.body.is_none() {
+            if self.table.len() < BYTES_PER_WORD {
+                take_bytes(&mut self.table, BYTES_PER_WORD, input);
+                if self.table.len() < BYTES_PER_WORD {
+                    return Ok(None);
+                }
+                let segment_count = u32::from_le_bytes(self.table[0..4].try_into().unwrap())
+                    .wrapping_add(1) as usize;
+                if segment_count >= SEGMENTS_COUNT_LIMIT || segment_count == 0 {
+                    return Err(Error::from_kind(ErrorKind::InvalidNumberOfSegments(
+                        segment_count,
+                    )));
+                }
+                self.table_len = (segment_count / 2 + 1) * BYTES_PER_WORD;
+            }
+            take_bytes(&mut self.table, self.table_len, input);
+            if self.table.len() < self.table_len {
+                return Ok(None);
+            }
+            let Some(segment_lengths_builder) =
+                read_segment_table(&mut &self.table[..], self.options)?
+            else {
+                unreachable!("segment table is not empty")
+            };
+            self.table.clear();
+            self.body = Some((segment_lengths_builder.into_owned_segments(), 0));
+        }
+
+        let Some((segments, filled)) = &mut self.body else {
+            unreachable!()
+        };
+        let n = core::cmp::min(segments.len() - *filled, input.len());
+        segments[*filled..*filled + n].copy_from_slice(&input[..n]);
+        *filled += n;
+        *input = &input[n..];
+        if *filled < segments.len() {
+            return Ok(None);
+        }
+        let (segments, _) = self.body.take().unwrap();
+        Ok(Some(message::Reader::new(segments, self.options)))
+    }
+
+    /// Returns the number of bytes that need to be passed to `decode()` before it can make
+    /// progress to the next stage: the end of the first word of the segment table, the end of
+    /// the segment table, or the end of the message.
+    pub fn bytes_needed(&self) -> usize {
+        match &self.body {
+            Some((segments, filled)) => segments.len() - filled,
+            None if self.table.len() < BYTES_PER_WORD => BYTES_PER_WORD - self.table.len(),
+            None => self.table_len - self.table.len(),
+        }
+    }
+
+    /// Returns true if part of a message has been received. This can be used to tell whether
+    /// the end of a stream falls on a message boundary.
+    pub fn has_partial_message(&self) -> bool {
+        self.body.is_some() || !self.table.is_empty()
+    }
+}
+
+/// Moves bytes from the front of `input` to `buf` until `buf` is `len` bytes long.
+#[cfg(feature = "alloc")]
+fn take_bytes(buf: &mut Vec<u8>, len: usize, input: &mut &[u8]) {
+    let n = core::cmp::min(len - buf.len(), input.len());
+    buf.extend_from_slice(&input[..n]);
+    *input = &input[n..];
+}
+
 /// Constructs a flat vector containing the entire message, including a segment header.
 #[cfg(feature = "alloc")]
 pub fn write_message_to_words<A>(message: &message::Builder<A>) -> Vec<u8>
@@ -463,6 +671,75 @@ where
     write_segments(&mut write, segments)
 }
 
+/// Like `write_message()`, but writes the segment table and all of the segments with
+/// `std::io::Write::write_vectored()`, so that a multi-segment message can go out in a single
+/// system call on writers that support it.
+///
+/// `flush()` will not be called on the writer.
+#[cfg(feature = "std")]
+pub fn write_message_vectored<W, A>(write: W, message: &message::Builder<A>) -> Result<()>
+where
+    W: std::io::Write,
+    A: message::Allocator,
+{
+    write_message_segments_vectored(write, &*message.get_segments_for_output())
+}
+
+/// Like `write_message_vectored()`, but takes a `ReaderSegments`, allowing it to be
+/// used on `message::Reader` objects (via `into_segments()`).
+#[cfg(feature = "std")]
+pub fn write_message_segments_vectored<W, R>(mut write: W, segments: &R) -> Result<()>
+where
+    W: std::io::Write,
+    R: message::ReaderSegments + ?Sized,
+{
+    let mut table = Vec::new();
+    write_segment_table_internal(&mut table, segments)?;
+    let mut bufs = Vec::with_capacity(segments.len() + 1);
+    bufs.push(&table[..]);
+    for i in 0..segments.len() {
+        bufs.push(segments.get_segment(i as u32).unwrap());
+    }
+    write_all_vectored(&mut write, &mut bufs)
+}
+
+/// Calls `write_vectored()` until all of `bufs` has been written.
+#[cfg(feature = "std")]
+fn write_all_vectored<W>(write: &mut W, bufs: &mut [&[u8]]) -> Result<()>
+where
+    W: std::io::Write,
+{
+    let mut start = 0;
+    loop {
+        while start < bufs.len() && bufs[start].is_empty() {
+            start += 1;
+        }
+        if start == bufs.len() {
+            return Ok(());
+        }
+        let io_slices: Vec<_> = bufs[start..]
+            .iter()
+            .map(|buf| std::io::IoSlice::new(buf))
+            .collect();
+        let mut n = match write.write_vectored(&io_slices) {
+            Ok(0) => return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into()),
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        while n > 0 {
+            let len = bufs[start].len();
+            if n >= len {
+                n -= len;
+                start += 1;
+            } else {
+                bufs[start] = &bufs[start][n..];
+                n = 0;
+            }
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn write_segment_table<W>(write: &mut W, segments: &[&[u8]]) -> Result<()>
 where
@@ -576,7 +853,7 @@ pub mod test {
 
     use super::{
         flatten_segments, read_message, read_message_from_flat_slice, read_segment_table,
-        try_read_message, write_segment_table, write_segments,
+        try_read_message, write_segment_table, write_segments, MessageDecoder, SliceMessageIter,
     };
     use crate::message;
     use crate::message::ReaderSegments;
@@ -941,6 +1218,164 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn slice_message_iter() {
+        let segments: [&[u8]; 2] = [
+            &[1, 0, 0, 0, 0, 0, 0, 0],
+            &[2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0],
+        ];
+        let message_bytes = flatten_segments(&segments[..]);
+        let mut bytes = message_bytes.repeat(3);
+
+        let messages = SliceMessageIter::new(&bytes, message::ReaderOptions::new())
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(messages.len(), 3);
+        for message in messages {
+            assert_eq!(message.into_segments().get_segment(1).unwrap(), segments[1]);
+        }
+
+        // Cut the last message off at every possible point.
+        let full_len = bytes.len();
+        for len in ((2 * message_bytes.len() + 1)..full_len).rev() {
+            bytes.truncate(len);
+            let mut iter = SliceMessageIter::new(&bytes, message::ReaderOptions::new())
+                .stop_at_truncated_message(true);
+            assert!(iter.next().unwrap().is_ok());
+            assert!(iter.next().unwrap().is_ok());
+            assert!(iter.next().is_none());
+            assert_eq!(
+                iter.remainder(),
+                &message_bytes[..len - 2 * message_bytes.len()]
+            );
+
+            let mut iter = SliceMessageIter::new(&bytes, message::ReaderOptions::new());
+            assert!(iter.nth(2).unwrap().is_err());
+            assert!(iter.next().is_none());
+        }
+    }
+
+    quickcheck! {
+        #[cfg_attr(miri, ignore)] // miri takes a long time with quickcheck
+        fn test_decode_incrementally(segments: Vec<Vec<crate::Word>>, chunk_size: u8) -> TestResult {
+            if segments.is_empty() || chunk_size == 0 { return TestResult::discard(); }
+            let mut buf = vec![];
+            write_message_segments(&mut buf, &segments);
+            write_message_segments(&mut buf, &segments);
+
+            let mut decoder = MessageDecoder::new(message::ReaderOptions::new());
+            let mut messages = Vec::new();
+            for mut chunk in buf.chunks(chunk_size as usize) {
+                while !chunk.is_empty() {
+                    assert!(decoder.bytes_needed() > 0);
+                    if let Some(message) = decoder.decode(&mut chunk).unwrap() {
+                        messages.push(message);
+                    }
+                }
+            }
+
+            TestResult::from_bool(!decoder.has_partial_message() && messages.len() == 2 &&
+                messages.into_iter().all(|message| {
+                    let result_segments = message.into_segments();
+                    segments.iter().enumerate().all(|(i, segment)| {
+                        crate::Word::words_to_bytes(&segment[..]) == result_segments.get_segment(i as u32).unwrap()
+                    })
+                }))
+        }
+    }
+
+    /// A writer that accepts at most `limit` bytes per call.
+    #[cfg(feature = "std")]
+    struct VectoredWriter {
+        bytes: Vec<u8>,
+        limit: usize,
+        calls: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl std::io::Write for VectoredWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.write_vectored(&[std::io::IoSlice::new(buf)])
+        }
+
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            self.calls += 1;
+            let start = self.bytes.len();
+            for buf in bufs {
+                let n = core::cmp::min(buf.len(), self.limit - (self.bytes.len() - start));
+                self.bytes.extend_from_slice(&buf[..n]);
+            }
+            Ok(self.bytes.len() - start)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    quickcheck! {
+        #[cfg_attr(miri, ignore)] // miri takes a long time with quickcheck
+        fn test_write_message_vectored(segments: Vec<Vec<crate::Word>>, limit: u8) -> TestResult {
+            if segments.is_empty() || limit == 0 { return TestResult::discard(); }
+            let borrowed_segments: &[&[u8]] = &segments
+                .iter()
+                .map(|segment| crate::Word::words_to_bytes(&segment[..]))
+                .collect::<Vec<_>>()[..];
+            let mut writer = VectoredWriter { bytes: Vec::new(), limit: limit as usize, calls: 0 };
+            super::write_message_segments_vectored(&mut writer, borrowed_segments).unwrap();
+            TestResult::from_bool(writer.bytes == flatten_segments(borrowed_segments))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_message_vectored_single_call() {
+        let mut m = message::Builder::new(
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        );
+        let mut list: crate::primitive_list::Builder<u64> =
+            m.init_root::<crate::any_pointer::Builder>().initn_as(3);
+        list.set(2, 7);
+        assert!(m.get_segments_for_output().len() > 1);
+
+        let mut writer = VectoredWriter {
+            bytes: Vec::new(),
+            limit: usize::MAX,
+            calls: 0,
+        };
+        super::write_message_vectored(&mut writer, &m).unwrap();
+        assert_eq!(writer.calls, 1);
+        assert_eq!(writer.bytes, super::write_message_to_words(&m));
+    }
+
+    #[test]
+    fn message_decoder_errors() {
+        let mut decoder = MessageDecoder::new(message::ReaderOptions::new());
+        assert_eq!(decoder.bytes_needed(), 8);
+        assert!(decoder.decode(&mut &[0, 2, 0, 0][..]).unwrap().is_none());
+        assert!(decoder.has_partial_message());
+        assert_eq!(decoder.bytes_needed(), 4);
+        assert_eq!(
+            decoder.decode(&mut &[0, 0, 0, 0][..]).err().unwrap().kind,
+            crate::ErrorKind::InvalidNumberOfSegments(513)
+        );
+
+        let mut options = message::ReaderOptions::new();
+        options.traversal_limit_in_words(Some(2));
+        let mut decoder = MessageDecoder::new(options);
+        assert_eq!(
+            decoder
+                .decode(&mut &[0, 0, 0, 0, 3, 0, 0, 0][..])
+                .err()
+                .unwrap()
+                .kind,
+            crate::ErrorKind::MessageTooLarge(3)
+        );
+    }
+
     #[test]
     fn compute_serialized_size() {
         const LIST_LENGTH_IN_WORDS: u32 = 5;
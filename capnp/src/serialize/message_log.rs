@@ -0,0 +1,485 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use super::{
+    compute_serialized_size, read_message_from_flat_slice, read_segment_table,
+    write_segment_table_internal, write_segments, BufferSegments,
+};
+use crate::io::{Read, Write};
+use crate::message::{self, ReaderOptions, ReaderSegments};
+use crate::private::units::BYTES_PER_WORD;
+use crate::{Error, ErrorKind, Result};
+
+/// Byte offsets of the message boundaries in a log of framed messages.
+///
+/// Message `n` occupies the bytes from the end of message `n - 1` (or the start of the log)
+/// up to `ends()[n]`. The index can be persisted with [`MessageIndex::write_to()`] as a
+/// sequence of little-endian `u64` end offsets, which is also the format produced
+/// incrementally by [`MessageLogAppender`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageIndex {
+    ends: Vec<u64>,
+}
+
+impl MessageIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scans a buffer holding zero or more messages, reading only their segment tables.
+    pub fn scan_buffer(mut bytes: &[u8], options: ReaderOptions) -> Result<Self> {
+        let len = bytes.len();
+        let mut index = Self::new();
+        while let Some(segment_lengths) = read_segment_table(&mut bytes, options)? {
+            let num_words = segment_lengths.total_words();
+            if num_words > bytes.len() / BYTES_PER_WORD {
+                return Err(Error::from_kind(ErrorKind::MessageEndsPrematurely(
+                    num_words,
+                    bytes.len() / BYTES_PER_WORD,
+                )));
+            }
+            bytes = &bytes[num_words * BYTES_PER_WORD..];
+            index.ends.push((len - bytes.len()) as u64);
+        }
+        Ok(index)
+    }
+
+    /// Scans a seekable stream holding zero or more messages, seeking over the message bodies.
+    /// Leaves the stream positioned at its end.
+    #[cfg(feature = "std")]
+    pub fn scan<R>(read: &mut R, options: ReaderOptions) -> Result<Self>
+    where
+        R: std::io::Read + std::io::Seek,
+    {
+        use std::io::SeekFrom;
+
+        let len = read.seek(SeekFrom::End(0))?;
+        let mut offset = read.seek(SeekFrom::Start(0))?;
+        let mut index = Self::new();
+        while let Some(segment_lengths) = read_segment_table(read, options)? {
+            let table_bytes = (segment_lengths.segment_indices.len() / 2 + 1) * BYTES_PER_WORD;
+            let body_start = offset + table_bytes as u64;
+            let num_words = segment_lengths.total_words();
+            let available_words = (len.saturating_sub(body_start) / BYTES_PER_WORD as u64) as usize;
+            if num_words > available_words {
+                return Err(Error::from_kind(ErrorKind::MessageEndsPrematurely(
+                    num_words,
+                    available_words,
+                )));
+            }
+            offset = read.seek(SeekFrom::Start(
+                body_start + (num_words * BYTES_PER_WORD) as u64,
+            ))?;
+            index.ends.push(offset);
+        }
+        Ok(index)
+    }
+
+    /// Reads an index previously written by [`MessageIndex::write_to()`] or
+    /// [`MessageLogAppender`].
+    pub fn read_from<R: Read>(mut read: R) -> Result<Self> {
+        let mut index = Self::new();
+        let mut buf = [0; 8];
+        loop {
+            let n = read.read(&mut buf)?;
+            if n == 0 {
+                break;
+            } else if n < 8 {
+                read.read_exact(&mut buf[n..])?;
+            }
+            index.push(u64::from_le_bytes(buf))?;
+        }
+        Ok(index)
+    }
+
+    pub fn write_to<W: Write>(&self, mut write: W) -> Result<()> {
+        for end in &self.ends {
+            write.write_all(&end.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, end: u64) -> Result<()> {
+        if end <= self.end() || end % BYTES_PER_WORD as u64 != 0 {
+            return Err(Error::from_kind(ErrorKind::InvalidMessageIndex));
+        }
+        self.ends.push(end);
+        Ok(())
+    }
+
+    /// Returns the number of messages in the index.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the end offset of every message.
+    pub fn ends(&self) -> &[u64] {
+        &self.ends
+    }
+
+    /// Returns the offset just past the last message, i.e. where the next message gets appended.
+    pub fn end(&self) -> u64 {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    /// Returns the start and end offsets of message `index`, or `None` if it is out of bounds.
+    pub fn bounds(&self, index: usize) -> Option<(u64, u64)> {
+        let end = *self.ends.get(index)?;
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        Some((start, end))
+    }
+}
+
+/// A buffer, such as a `Vec<u8>` or a memory-mapped file, holding many messages written back
+/// to back with [`write_message()`](super::write_message). Messages are read out of the buffer
+/// without copying.
+///
+/// ALIGNMENT: If the "unaligned" feature is enabled, then there are no alignment requirements on
+/// the buffer. Otherwise, it must be 8-byte aligned (attempts to read messages will trigger errors).
+pub struct MessageFile<T> {
+    buffer: T,
+    index: MessageIndex,
+    options: ReaderOptions,
+}
+
+impl<T: Deref<Target = [u8]>> MessageFile<T> {
+    /// Builds the index by scanning the segment tables of all messages in `buffer`.
+    pub fn new(buffer: T, options: ReaderOptions) -> Result<Self> {
+        let index = MessageIndex::scan_buffer(&buffer, options)?;
+        Ok(Self {
+            buffer,
+            index,
+            options,
+        })
+    }
+
+    /// Uses a previously built index instead of scanning `buffer`. Bytes past the end of the
+    /// last indexed message are ignored.
+    pub fn with_index(buffer: T, index: MessageIndex, options: ReaderOptions) -> Result<Self> {
+        if index.end() > buffer.len() as u64 {
+            return Err(Error::from_kind(ErrorKind::InvalidMessageIndex));
+        }
+        Ok(Self {
+            buffer,
+            index,
+            options,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn index(&self) -> &MessageIndex {
+        &self.index
+    }
+
+    /// Reads message number `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Result<message::Reader<BufferSegments<&[u8]>>> {
+        let (start, end) = self
+            .index
+            .bounds(index)
+            .expect("message index out of bounds");
+        let mut slice = &self.buffer[start as usize..end as usize];
+        read_message_from_flat_slice(&mut slice, self.options)
+    }
+
+    /// Iterates over the messages in order.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = Result<message::Reader<BufferSegments<&[u8]>>>> + '_ {
+        (0..self.len()).map(move |index| self.get(index))
+    }
+
+    pub fn into_buffer(self) -> T {
+        self.buffer
+    }
+}
+
+/// A seekable stream, typically a `File`, holding many messages written back to back with
+/// [`write_message()`](super::write_message). Messages are copied out of the stream as they
+/// are read.
+#[cfg(feature = "std")]
+pub struct MessageLog<R> {
+    inner: R,
+    index: MessageIndex,
+    options: ReaderOptions,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read + std::io::Seek> MessageLog<R> {
+    /// Builds the index by reading the segment tables of all messages in `inner`, seeking over
+    /// their bodies.
+    pub fn open(mut inner: R, options: ReaderOptions) -> Result<Self> {
+        let index = MessageIndex::scan(&mut inner, options)?;
+        Ok(Self {
+            inner,
+            index,
+            options,
+        })
+    }
+
+    /// Uses a previously built index, such as one maintained by [`MessageLogAppender`], instead
+    /// of scanning `inner`. Bytes past the end of the last indexed message are ignored.
+    pub fn with_index(mut inner: R, index: MessageIndex, options: ReaderOptions) -> Result<Self> {
+        if index.end() > inner.seek(std::io::SeekFrom::End(0))? {
+            return Err(Error::from_kind(ErrorKind::InvalidMessageIndex));
+        }
+        Ok(Self {
+            inner,
+            index,
+            options,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn index(&self) -> &MessageIndex {
+        &self.index
+    }
+
+    /// Seeks to and reads message number `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&mut self, index: usize) -> Result<message::Reader<super::OwnedSegments>> {
+        let (start, _) = self
+            .index
+            .bounds(index)
+            .expect("message index out of bounds");
+        self.inner.seek(std::io::SeekFrom::Start(start))?;
+        super::read_message(&mut self.inner, self.options)
+    }
+
+    /// Iterates over the messages in order, starting at message number `start`. Seeks only
+    /// once, so reading `inner` sequentially should be cheap.
+    pub fn iter_from(&mut self, start: usize) -> MessageLogIter<'_, R> {
+        MessageLogIter {
+            log: self,
+            next: start,
+            positioned: false,
+        }
+    }
+
+    pub fn iter(&mut self) -> MessageLogIter<'_, R> {
+        self.iter_from(0)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Iterator returned by [`MessageLog::iter_from()`].
+#[cfg(feature = "std")]
+pub struct MessageLogIter<'a, R> {
+    log: &'a mut MessageLog<R>,
+    next: usize,
+    positioned: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: std::io::Read + std::io::Seek> Iterator for MessageLogIter<'a, R> {
+    type Item = Result<message::Reader<super::OwnedSegments>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, _) = self.log.index.bounds(self.next)?;
+        self.next += 1;
+        if !self.positioned {
+            if let Err(e) = self.log.inner.seek(std::io::SeekFrom::Start(start)) {
+                return Some(Err(e.into()));
+            }
+            self.positioned = true;
+        }
+        let result = super::read_message(&mut self.log.inner, self.log.options);
+        if result.is_err() {
+            self.positioned = false;
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.log.len().saturating_sub(self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+/// Appends messages to a log while recording their end offsets in a sidecar index, which can
+/// later be loaded with [`MessageIndex::read_from()`] to open the log without scanning it.
+///
+/// Neither writer is flushed.
+pub struct MessageLogAppender<W, I> {
+    log: W,
+    index: I,
+    end: u64,
+}
+
+impl<W: Write, I: Write> MessageLogAppender<W, I> {
+    /// `end` is the current length of the log. When reopening an existing log, this is the
+    /// [`end()`](MessageIndex::end) of its index.
+    pub fn new(log: W, index: I, end: u64) -> Self {
+        Self { log, index, end }
+    }
+
+    /// Appends `message` to the log and its end offset to the index.
+    pub fn append<A>(&mut self, message: &message::Builder<A>) -> Result<()>
+    where
+        A: message::Allocator,
+    {
+        self.append_segments(&*message.get_segments_for_output())
+    }
+
+    /// Like `append()`, but takes a `ReaderSegments`, allowing it to be used on
+    /// `message::Reader` objects (via `into_segments()`).
+    pub fn append_segments<R>(&mut self, segments: &R) -> Result<()>
+    where
+        R: ReaderSegments + ?Sized,
+    {
+        write_segment_table_internal(&mut self.log, segments)?;
+        write_segments(&mut self.log, segments)?;
+        self.end += (compute_serialized_size(segments) * BYTES_PER_WORD) as u64;
+        self.index.write_all(&self.end.to_le_bytes())
+    }
+
+    /// Returns the offset at which the next message will be written.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn into_inner(self) -> (W, I) {
+        (self.log, self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use std::io::Cursor;
+
+    use super::{MessageFile, MessageIndex, MessageLog, MessageLogAppender};
+    use crate::message::{self, ReaderOptions};
+    use crate::{primitive_list, serialize, ErrorKind};
+
+    fn build_message(n: u32) -> message::Builder<message::HeapAllocator> {
+        let mut builder = message::Builder::new(
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        );
+        let mut list: primitive_list::Builder<u32> = builder
+            .init_root::<crate::any_pointer::Builder>()
+            .initn_as(n);
+        for i in 0..n {
+            list.set(i, n * 100 + i);
+        }
+        builder
+    }
+
+    fn check_message<S: message::ReaderSegments>(reader: message::Reader<S>, n: u32) {
+        let list: primitive_list::Reader<u32> = reader.get_root().unwrap();
+        assert_eq!(list.len(), n);
+        for i in 0..n {
+            assert_eq!(list.get(i), n * 100 + i);
+        }
+    }
+
+    #[test]
+    fn scan_and_read() {
+        let mut log = Vec::new();
+        for n in 0..10 {
+            serialize::write_message(&mut log, &build_message(n)).unwrap();
+        }
+        let mut words = crate::Word::allocate_zeroed_vec(log.len() / 8);
+        crate::Word::words_to_bytes_mut(&mut words).copy_from_slice(&log);
+
+        let file =
+            MessageFile::new(crate::Word::words_to_bytes(&words), ReaderOptions::new()).unwrap();
+        assert_eq!(file.len(), 10);
+        check_message(file.get(7).unwrap(), 7);
+        for (n, reader) in file.iter().enumerate() {
+            check_message(reader.unwrap(), n as u32);
+        }
+
+        let mut stream = MessageLog::open(Cursor::new(&log), ReaderOptions::new()).unwrap();
+        assert_eq!(stream.index(), file.index());
+        check_message(stream.get(3).unwrap(), 3);
+        check_message(stream.get(0).unwrap(), 0);
+        for (n, reader) in stream.iter_from(4).enumerate() {
+            check_message(reader.unwrap(), n as u32 + 4);
+        }
+        assert_eq!(stream.iter_from(4).count(), 6);
+
+        let e = MessageIndex::scan_buffer(&log[..log.len() - 8], ReaderOptions::new()).unwrap_err();
+        assert!(matches!(e.kind, ErrorKind::MessageEndsPrematurely(..)));
+        let e = MessageLog::open(Cursor::new(&log[..log.len() - 8]), ReaderOptions::new())
+            .err()
+            .unwrap();
+        assert!(matches!(e.kind, ErrorKind::MessageEndsPrematurely(..)));
+    }
+
+    #[test]
+    fn appender_sidecar_index() {
+        let mut appender = MessageLogAppender::new(Vec::new(), Vec::new(), 0);
+        for n in 0..5 {
+            appender.append(&build_message(n)).unwrap();
+        }
+        let (log, index) = appender.into_inner();
+        let index = MessageIndex::read_from(&index[..]).unwrap();
+        assert_eq!(index.end(), log.len() as u64);
+        assert_eq!(
+            index,
+            MessageIndex::scan_buffer(&log, ReaderOptions::new()).unwrap()
+        );
+
+        // Reopen and keep appending.
+        let mut sidecar = Vec::new();
+        index.write_to(&mut sidecar).unwrap();
+        let mut appender = MessageLogAppender::new(log, sidecar, index.end());
+        let reader = serialize::read_message(
+            &mut &serialize::write_message_to_words(&build_message(5))[..],
+            ReaderOptions::new(),
+        )
+        .unwrap();
+        appender.append_segments(&reader.into_segments()).unwrap();
+        let (log, sidecar) = appender.into_inner();
+        let index = MessageIndex::read_from(&sidecar[..]).unwrap();
+        assert_eq!(index.len(), 6);
+
+        let mut stream =
+            MessageLog::with_index(Cursor::new(&log), index.clone(), ReaderOptions::new()).unwrap();
+        check_message(stream.get(5).unwrap(), 5);
+        assert_eq!(
+            MessageLog::with_index(
+                Cursor::new(&log[..log.len() - 8]),
+                index,
+                ReaderOptions::new()
+            )
+            .err()
+            .unwrap()
+            .kind,
+            ErrorKind::InvalidMessageIndex
+        );
+        assert_eq!(
+            MessageIndex::read_from(&[8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0][..])
+                .unwrap_err()
+                .kind,
+            ErrorKind::InvalidMessageIndex
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::fs::File;
+
+use memmap2::Mmap;
+
+use super::{read_segment_table, BufferSegments};
+use crate::message::{ReaderOptions, ReaderSegments};
+use crate::private::units::BYTES_PER_WORD;
+use crate::{Error, ErrorKind, Result};
+
+/// Segments of a message stored in a memory-mapped file.
+///
+/// Opening a file only reads and validates its segment table; the segments themselves are
+/// handed to the reader directly out of the mapping, so pages are only faulted in as the
+/// message is traversed. The file is expected to start with the segment table and is allowed
+/// to extend beyond the end of the message.
+///
+/// Messages this large usually need a higher (or no) traversal limit in the `ReaderOptions`
+/// passed to `message::Reader::new()`.
+pub struct MmapSegments {
+    segments: BufferSegments<Mmap>,
+}
+
+impl MmapSegments {
+    /// Maps `file` into memory and validates its segment table.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while the
+    /// mapping is alive. See [`memmap2::Mmap::map`].
+    pub unsafe fn open(file: &File, options: ReaderOptions) -> Result<Self> {
+        Self::from_mmap(Mmap::map(file)?, options)
+    }
+
+    /// Validates the segment table at the start of an existing mapping and takes ownership of it.
+    pub fn from_mmap(mmap: Mmap, options: ReaderOptions) -> Result<Self> {
+        let mut bytes = &mmap[..];
+        let Some(segment_lengths_builder) = read_segment_table(&mut bytes, options)? else {
+            return Err(Error::from_kind(ErrorKind::EmptyBuffer));
+        };
+        let segment_table_bytes_len = mmap.len() - bytes.len();
+        let num_words = segment_lengths_builder.total_words();
+        if num_words > bytes.len() / BYTES_PER_WORD {
+            return Err(Error::from_kind(ErrorKind::MessageEndsPrematurely(
+                num_words,
+                bytes.len() / BYTES_PER_WORD,
+            )));
+        }
+        Ok(Self {
+            segments: BufferSegments {
+                buffer: mmap,
+                segment_table_bytes_len,
+                segment_indices: segment_lengths_builder.to_segment_indices(),
+            },
+        })
+    }
+
+    /// Returns the number of bytes of the mapping taken up by the message, including its
+    /// segment table.
+    pub fn message_len(&self) -> usize {
+        let words = self
+            .segments
+            .segment_indices
+            .last()
+            .map_or(0, |&(_, end)| end);
+        self.segments.segment_table_bytes_len + words * BYTES_PER_WORD
+    }
+
+    pub fn into_mmap(self) -> Mmap {
+        self.segments.into_buffer()
+    }
+}
+
+impl ReaderSegments for MmapSegments {
+    fn get_segment(&self, id: u32) -> Option<&[u8]> {
+        self.segments.get_segment(id)
+    }
+
+    fn len(&self) -> usize {
+        self.segments.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use super::MmapSegments;
+    use crate::message::{self, ReaderSegments};
+    use crate::{serialize, ErrorKind};
+
+    fn write_temp_file(name: &str, bytes: &[u8]) -> (std::path::PathBuf, File) {
+        let path = std::env::temp_dir().join(format!("capnp-{}-{name}", std::process::id()));
+        File::create(&path).unwrap().write_all(bytes).unwrap();
+        let file = File::open(&path).unwrap();
+        (path, file)
+    }
+
+    #[test]
+    fn open_multi_segment() {
+        let mut builder = message::Builder::new(
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        );
+        let mut root: crate::text_list::Builder = builder
+            .init_root::<crate::any_pointer::Builder>()
+            .initn_as(3);
+        root.set(0, "foo".into());
+        root.set(1, "bar".into());
+        root.set(2, "a somewhat longer piece of text".into());
+        let mut bytes = serialize::write_message_to_words(&builder);
+        let message_len = bytes.len();
+        bytes.extend_from_slice(&[0; 16]);
+        let (path, file) = write_temp_file("open_multi_segment", &bytes);
+
+        let segments = unsafe { MmapSegments::open(&file, message::ReaderOptions::new()) }.unwrap();
+        assert!(segments.len() > 1);
+        assert_eq!(segments.message_len(), message_len);
+        let reader = message::Reader::new(segments, message::ReaderOptions::new());
+        let root: crate::text_list::Reader = reader.get_root().unwrap();
+        assert_eq!(root.get(2).unwrap(), "a somewhat longer piece of text");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn open_invalid() {
+        let mut builder = message::Builder::new_default();
+        builder.set_root("hello").unwrap();
+        let bytes = serialize::write_message_to_words(&builder);
+
+        let (path, file) = write_temp_file("open_truncated", &bytes[..bytes.len() - 8]);
+        let Err(e) = (unsafe { MmapSegments::open(&file, message::ReaderOptions::new()) }) else {
+            panic!("expected an error");
+        };
+        assert!(matches!(e.kind, ErrorKind::MessageEndsPrematurely(..)));
+        fs::remove_file(path).unwrap();
+
+        let (path, file) = write_temp_file("open_empty", &[]);
+        let Err(e) = (unsafe { MmapSegments::open(&file, message::ReaderOptions::new()) }) else {
+            panic!("expected an error");
+        };
+        assert_eq!(e.kind, ErrorKind::EmptyBuffer);
+        fs::remove_file(path).unwrap();
+    }
+}
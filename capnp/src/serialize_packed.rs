@@ -30,6 +30,8 @@ use crate::message;
 #[cfg(feature = "alloc")]
 use crate::serialize;
 use crate::{Error, ErrorKind, Result};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// A `BufRead` wrapper that unpacks packed data. Returns an error on any `read()`
 /// call that would end within an all-zero (tag 0x00) or uncompressed (tag 0xff)
@@ -256,6 +258,155 @@ where
     serialize::try_read_message(packed_read, options)
 }
 
+/// Like [`serialize::MessageDecoder`], but decodes packed messages.
+///
+/// After an error, the decoder is left in an unspecified state and should be discarded.
+#[cfg(feature = "alloc")]
+pub struct MessageDecoder {
+    inner: serialize::MessageDecoder,
+
+    // The bytes of a packed word whose tag has been received but whose remaining bytes have not.
+    pending: Vec<u8>,
+
+    // Number of bytes still to be copied verbatim following a 0xff tag.
+    raw_remaining: usize,
+
+    // Unpacked bytes that have not been passed to `inner` yet.
+    unpacked: Vec<u8>,
+    unpacked_pos: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl MessageDecoder {
+    pub fn new(options: message::ReaderOptions) -> Self {
+        Self {
+            inner: serialize::MessageDecoder::new(options),
+            pending: Vec::new(),
+            raw_remaining: 0,
+            unpacked: Vec::new(),
+            unpacked_pos: 0,
+        }
+    }
+
+    /// Consumes bytes from the front of `input` until either `input` is empty or a message is
+    /// complete, in which case the message is returned. Any bytes beyond the end of the message
+    /// are left in `input`, so callers should keep calling this until `input` is empty.
+    pub fn decode(
+        &mut self,
+        input: &mut &[u8],
+    ) -> Result<Option<message::Reader<serialize::OwnedSegments>>> {
+        loop {
+            if self.unpacked_pos < self.unpacked.len() {
+                let mut unpacked = &self.unpacked[self.unpacked_pos..];
+                let result = self.inner.decode(&mut unpacked);
+                self.unpacked_pos = self.unpacked.len() - unpacked.len();
+                if let Some(message) = result? {
+                    return Ok(Some(message));
+                }
+            }
+            self.unpacked.clear();
+            self.unpacked_pos = 0;
+
+            if input.is_empty() {
+                return Ok(None);
+            } else if self.raw_remaining > 0 {
+                // Uncompressed words can go straight to `inner`.
+                let n = core::cmp::min(self.raw_remaining, input.len());
+                let mut raw = &input[..n];
+                let result = self.inner.decode(&mut raw);
+                let consumed = n - raw.len();
+                self.raw_remaining -= consumed;
+                *input = &input[consumed..];
+                if let Some(message) = result? {
+                    return Ok(Some(message));
+                }
+            } else {
+                self.unpack_word(input);
+            }
+        }
+    }
+
+    /// Moves bytes of the next packed word from `input` to `pending`, and if that completes the
+    /// word, unpacks it into `unpacked`.
+    fn unpack_word(&mut self, input: &mut &[u8]) {
+        if self.pending.is_empty() {
+            self.pending.push(input[0]);
+            *input = &input[1..];
+        }
+        let tag = self.pending[0];
+        let has_count = tag == 0 || tag == 0xff;
+        let word_len = 1 + tag.count_ones() as usize + usize::from(has_count);
+        let n = core::cmp::min(word_len - self.pending.len(), input.len());
+        self.pending.extend_from_slice(&input[..n]);
+        *input = &input[n..];
+        if self.pending.len() < word_len {
+            return;
+        }
+
+        let mut bytes = self.pending[1..].iter();
+        for i in 0..8 {
+            let byte = if tag & (1 << i) != 0 {
+                *bytes.next().unwrap()
+            } else {
+                0
+            };
+            self.unpacked.push(byte);
+        }
+        if tag == 0 {
+            let count = *bytes.next().unwrap() as usize;
+            self.unpacked.resize(self.unpacked.len() + count * 8, 0);
+        } else if tag == 0xff {
+            self.raw_remaining = *bytes.next().unwrap() as usize * 8;
+        }
+        self.pending.clear();
+    }
+
+    /// Returns true if part of a message has been received. This can be used to tell whether
+    /// the end of a stream falls on a message boundary.
+    pub fn has_partial_message(&self) -> bool {
+        self.inner.has_partial_message()
+            || !self.pending.is_empty()
+            || self.raw_remaining > 0
+            || self.unpacked_pos < self.unpacked.len()
+    }
+
+    /// Returns a lower bound on the number of bytes that need to be passed to `decode()` before
+    /// it can make progress to the next stage, in the sense of
+    /// [`serialize::MessageDecoder::bytes_needed()`].
+    ///
+    /// Unlike for unpacked messages, the exact number cannot be known in advance, because how
+    /// many bytes the rest of a message packs into depends on its contents. Passing exactly this
+    /// many bytes never consumes anything past the end of the current message, so it is safe for
+    /// a caller that must not read ahead in its stream. Returns 0 if `decode()` can make progress
+    /// without any more input, which can happen when the last call ended in a run of zero words.
+    pub fn bytes_needed(&self) -> usize {
+        // A tag of 0 and a count byte are the most that two packed bytes can unpack into.
+        const MAX_ZERO_RUN: usize = 256 * 8;
+
+        let mut needed = self
+            .inner
+            .bytes_needed()
+            .saturating_sub(self.unpacked.len() - self.unpacked_pos);
+        if needed == 0 {
+            return 0;
+        }
+
+        let mut bytes = 0;
+        if self.raw_remaining > 0 {
+            let n = core::cmp::min(self.raw_remaining, needed);
+            bytes += n;
+            needed -= n;
+        } else if let Some(&tag) = self.pending.first() {
+            let has_count = tag == 0 || tag == 0xff;
+            let word_len = 1 + tag.count_ones() as usize + usize::from(has_count);
+            bytes += word_len - self.pending.len();
+            let max_unpacked = if tag == 0 { MAX_ZERO_RUN } else { 8 };
+            needed = needed.saturating_sub(max_unpacked);
+        }
+        bytes + 2 * ((needed + MAX_ZERO_RUN - 1) / MAX_ZERO_RUN)
+    }
+}
+
 struct PackedWrite<W>
 where
     W: Write,
@@ -427,7 +578,7 @@ mod tests {
 
     use quickcheck::{quickcheck, TestResult};
 
-    use super::read_message;
+    use super::{read_message, MessageDecoder};
     use crate::message::ReaderOptions;
     use crate::serialize::test::write_message_segments;
     use crate::serialize_packed::{PackedRead, PackedWrite};
@@ -547,6 +698,60 @@ mod tests {
             }))
         }
 
+        #[cfg_attr(miri, ignore)] // miri takes a long time with quickcheck
+        fn test_decode_incrementally(segments: Vec<Vec<crate::Word>>, chunk_size: u8) -> TestResult {
+            use crate::message::ReaderSegments;
+            if segments.is_empty() || chunk_size == 0 { return TestResult::discard(); }
+            let mut buf: Vec<u8> = Vec::new();
+            write_message_segments(&mut PackedWrite { inner: &mut buf }, &segments);
+            write_message_segments(&mut PackedWrite { inner: &mut buf }, &segments);
+
+            let mut decoder = MessageDecoder::new(ReaderOptions::new());
+            let mut messages = Vec::new();
+            for mut chunk in buf.chunks(chunk_size as usize) {
+                while !chunk.is_empty() {
+                    if let Some(message) = decoder.decode(&mut chunk).unwrap() {
+                        messages.push(message);
+                    }
+                }
+            }
+
+            TestResult::from_bool(!decoder.has_partial_message() && messages.len() == 2 &&
+                messages.into_iter().all(|message| {
+                    let result_segments = message.into_segments();
+                    segments.iter().enumerate().all(|(i, segment)| {
+                        crate::Word::words_to_bytes(&segment[..]) == result_segments.get_segment(i as u32).unwrap()
+                    })
+                }))
+        }
+
+        #[cfg_attr(miri, ignore)] // miri takes a long time with quickcheck
+        fn test_decode_bytes_needed(segments: Vec<Vec<crate::Word>>) -> TestResult {
+            if segments.is_empty() { return TestResult::discard(); }
+            let mut buf: Vec<u8> = Vec::new();
+            write_message_segments(&mut PackedWrite { inner: &mut buf }, &segments);
+            let message_len = buf.len();
+            write_message_segments(&mut PackedWrite { inner: &mut buf }, &segments);
+
+            // Passing exactly bytes_needed() at a time should never read past a message.
+            let mut decoder = MessageDecoder::new(ReaderOptions::new());
+            let mut input = &buf[..];
+            let mut ends = Vec::new();
+            while !input.is_empty() {
+                let n = decoder.bytes_needed();
+                assert!(n <= input.len());
+                let mut chunk = &input[..n];
+                let message = decoder.decode(&mut chunk).unwrap();
+                assert!(chunk.is_empty());
+                input = &input[n..];
+                if message.is_some() {
+                    ends.push(buf.len() - input.len());
+                }
+            }
+
+            TestResult::from_bool(!decoder.has_partial_message() && ends == [message_len, buf.len()])
+        }
+
         #[cfg_attr(miri, ignore)] // miri takes a long time with quickcheck
         fn test_unpack(packed: Vec<u8>) -> TestResult {
             let len = packed.len();
@@ -559,6 +764,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn message_decoder_bytes_needed() {
+        let mut decoder = MessageDecoder::new(ReaderOptions::new());
+        assert_eq!(decoder.bytes_needed(), 2);
+
+        // Segment table: one segment, two words long.
+        assert!(decoder.decode(&mut &[0x10][..]).unwrap().is_none());
+        assert_eq!(decoder.bytes_needed(), 1);
+        assert!(decoder.decode(&mut &[2][..]).unwrap().is_none());
+        assert_eq!(decoder.bytes_needed(), 2);
+
+        // One uncompressed word, followed by zero more.
+        assert!(decoder.decode(&mut &[0xff][..]).unwrap().is_none());
+        assert_eq!(decoder.bytes_needed(), 11);
+        assert!(decoder
+            .decode(&mut &[1, 2, 3, 4, 5, 6, 7, 8, 0][..])
+            .unwrap()
+            .is_none());
+        assert_eq!(decoder.bytes_needed(), 2);
+
+        // A run of zero words long enough to hold the next message's segment table too.
+        assert!(decoder.decode(&mut &[0][..]).unwrap().is_none());
+        assert_eq!(decoder.bytes_needed(), 1);
+        assert!(decoder.decode(&mut &[1][..]).unwrap().is_some());
+        assert!(decoder.has_partial_message());
+        assert_eq!(decoder.bytes_needed(), 0);
+    }
+
     #[test]
     fn did_not_end_cleanly_on_a_segment_boundary() {
         let packed = &[0xff, 1, 2, 3, 4, 5, 6, 7, 8, 37, 1, 2];
@@ -1,8 +1,26 @@
-use crate::dynamic_value;
-use core::fmt::{self, Formatter};
+//! Printing of dynamically-typed values in the Cap'n Proto text format.
+//!
+//! The `Debug` impl of [`dynamic_value::Reader`] prints a value on a single line, or over
+//! multiple indented lines when formatted with `{:#?}`. A [`Printer`] gives finer control over
+//! the output, which is useful for keeping log lines short and sensitive data out of logs:
+//!
+//! ```ignore
+//! let mut printer = capnp::stringify::Printer::new();
+//! printer
+//!     .max_depth(4)
+//!     .max_list_len(16)
+//!     .max_data_len(32)
+//!     .redact_annotation(SECRET_ANNOTATION_ID);
+//! log::info!("received {}", printer.display(request.into_reader()));
+//! ```
+
+use crate::schema::Field;
+use crate::schema_capnp::{field, value};
+use crate::{dynamic_struct, dynamic_value};
+use core::fmt::{self, Formatter, Write};
 
 #[derive(Clone, Copy, Debug, Default)]
-pub(crate) struct Indent {
+struct Indent {
     /// None means everything gets printed on a single line.
     indent: Option<usize>,
 }
@@ -25,23 +43,23 @@ impl Indent {
         }
     }
 
-    fn maybe_newline(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+    fn maybe_newline(&self, out: &mut dyn Write) -> fmt::Result {
         match self.indent {
             None => Ok(()),
             Some(indent) => {
-                formatter.write_str("\n")?;
+                out.write_str("\n")?;
                 for _ in 0..indent {
-                    formatter.write_str("  ")?;
+                    out.write_str("  ")?;
                 }
                 Ok(())
             }
         }
     }
 
-    fn comma(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+    fn comma(&self, out: &mut dyn Write) -> fmt::Result {
         match self.indent {
-            None => formatter.write_str(", "),
-            Some(_) => formatter.write_str(","),
+            None => out.write_str(", "),
+            Some(_) => out.write_str(","),
         }
     }
 }
@@ -53,129 +71,362 @@ fn cvt<T, E>(r: core::result::Result<T, E>) -> Result<T, fmt::Error> {
     }
 }
 
-pub(crate) fn print(
-    value: dynamic_value::Reader,
-    formatter: &mut Formatter,
-    indent: Indent,
-) -> Result<(), fmt::Error> {
-    match value {
-        dynamic_value::Reader::Void => formatter.write_str("()"),
-        dynamic_value::Reader::Bool(b) => formatter.write_fmt(format_args!("{b}")),
-        dynamic_value::Reader::Int8(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::Int16(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::Int32(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::Int64(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::UInt8(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::UInt16(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::UInt32(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::UInt64(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::Float32(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::Float64(x) => formatter.write_fmt(format_args!("{x}")),
-        dynamic_value::Reader::Enum(e) => match cvt(e.get_enumerant())? {
-            Some(enumerant) => {
-                formatter.write_str(cvt(cvt(enumerant.get_proto().get_name())?.to_str())?)
-            }
-            None => formatter.write_fmt(format_args!("{}", e.get_value())),
-        },
-        dynamic_value::Reader::Text(t) => formatter.write_fmt(format_args!("{t:?}")),
-        dynamic_value::Reader::Data(d) => {
-            formatter.write_str("0x\"")?;
-            for b in d {
-                formatter.write_fmt(format_args!("{:02x}", *b))?;
-            }
-            formatter.write_str("\"")
+/// How a [`Printer`] writes Data values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataFormat {
+    /// A hex string, like `0x"0a0b0c"`. This is the form that
+    /// [`text_format::parse`](crate::text_format::parse) accepts.
+    #[default]
+    Hex,
+
+    /// A list of byte values, like `[10, 11, 12]`. Always printed on a single line.
+    Decimal,
+}
+
+/// Options controlling how a dynamic value is printed.
+///
+/// With the default options, the output is the same as that of the `Debug` impl of
+/// [`dynamic_value::Reader`].
+#[derive(Clone, Copy, Debug)]
+pub struct Printer {
+    pretty: bool,
+    max_depth: Option<usize>,
+    max_list_len: Option<usize>,
+    max_data_len: Option<usize>,
+    data_format: DataFormat,
+    hide_default_fields: bool,
+    redact_annotation: Option<u64>,
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Printer {
+    pub fn new() -> Self {
+        Self {
+            pretty: false,
+            max_depth: None,
+            max_list_len: None,
+            max_data_len: None,
+            data_format: DataFormat::Hex,
+            hide_default_fields: false,
+            redact_annotation: None,
         }
-        dynamic_value::Reader::List(list) => {
-            if list.is_empty() {
-                formatter.write_str("[]")
-            } else {
-                formatter.write_str("[")?;
+    }
+
+    /// If true, each field and list element goes on its own line, indented by two spaces per
+    /// level of nesting, like `{:#?}`. Defaults to false.
+    pub fn pretty(&mut self, value: bool) -> &mut Self {
+        self.pretty = value;
+        self
+    }
+
+    /// Limits how many levels of structs and lists are expanded. Anything nested deeper is
+    /// printed as `(...)` or `[...]`. A limit of zero elides even the top-level value.
+    /// Defaults to no limit.
+    pub fn max_depth(&mut self, value: usize) -> &mut Self {
+        self.max_depth = Some(value);
+        self
+    }
+
+    /// Limits how many elements of a list are printed. The remaining elements are replaced
+    /// by a `... N more` marker. Defaults to no limit.
+    pub fn max_list_len(&mut self, value: usize) -> &mut Self {
+        self.max_list_len = Some(value);
+        self
+    }
+
+    /// Limits how many bytes of a Data value are printed. The remaining bytes are replaced
+    /// by a `... N more` marker. Defaults to no limit.
+    pub fn max_data_len(&mut self, value: usize) -> &mut Self {
+        self.max_data_len = Some(value);
+        self
+    }
+
+    /// Sets how Data values are written. Defaults to [`DataFormat::Hex`].
+    pub fn data_format(&mut self, value: DataFormat) -> &mut Self {
+        self.data_format = value;
+        self
+    }
+
+    /// If true, fields whose value equals the default from the schema are omitted, as are
+    /// groups all of whose fields are omitted. Unset pointer fields are always omitted.
+    /// Defaults to false.
+    pub fn hide_default_fields(&mut self, value: bool) -> &mut Self {
+        self.hide_default_fields = value;
+        self
+    }
+
+    /// Fields carrying the annotation with this ID have their values printed as
+    /// `<redacted>`. Defaults to none.
+    pub fn redact_annotation(&mut self, id: u64) -> &mut Self {
+        self.redact_annotation = Some(id);
+        self
+    }
+
+    /// Writes `value` to `out`.
+    pub fn print<'a, W: Write>(
+        &self,
+        value: impl Into<dynamic_value::Reader<'a>>,
+        out: &mut W,
+    ) -> fmt::Result {
+        let indent = if self.pretty {
+            Indent::enabled()
+        } else {
+            Indent::no_indent()
+        };
+        self.print_value(value.into(), out, indent, 0)
+    }
+
+    /// Returns an object that implements [`fmt::Display`] by printing `value`.
+    pub fn display<'a>(&self, value: impl Into<dynamic_value::Reader<'a>>) -> Display<'_, 'a> {
+        Display {
+            printer: self,
+            value: value.into(),
+        }
+    }
+
+    fn print_value(
+        &self,
+        value: dynamic_value::Reader,
+        out: &mut dyn Write,
+        indent: Indent,
+        depth: usize,
+    ) -> fmt::Result {
+        let elided = self.max_depth.map_or(false, |max_depth| depth >= max_depth);
+        match value {
+            dynamic_value::Reader::Void => out.write_str("()"),
+            dynamic_value::Reader::Bool(b) => write!(out, "{b}"),
+            dynamic_value::Reader::Int8(x) => write!(out, "{x}"),
+            dynamic_value::Reader::Int16(x) => write!(out, "{x}"),
+            dynamic_value::Reader::Int32(x) => write!(out, "{x}"),
+            dynamic_value::Reader::Int64(x) => write!(out, "{x}"),
+            dynamic_value::Reader::UInt8(x) => write!(out, "{x}"),
+            dynamic_value::Reader::UInt16(x) => write!(out, "{x}"),
+            dynamic_value::Reader::UInt32(x) => write!(out, "{x}"),
+            dynamic_value::Reader::UInt64(x) => write!(out, "{x}"),
+            dynamic_value::Reader::Float32(x) => write!(out, "{x}"),
+            dynamic_value::Reader::Float64(x) => write!(out, "{x}"),
+            dynamic_value::Reader::Enum(e) => match cvt(e.get_enumerant())? {
+                Some(enumerant) => {
+                    out.write_str(cvt(cvt(enumerant.get_proto().get_name())?.to_str())?)
+                }
+                None => write!(out, "{}", e.get_value()),
+            },
+            dynamic_value::Reader::Text(t) => write!(out, "{t:?}"),
+            dynamic_value::Reader::Data(d) => self.print_data(d, out),
+            dynamic_value::Reader::List(list) => {
+                if list.is_empty() {
+                    return out.write_str("[]");
+                }
+                if elided {
+                    return out.write_str("[...]");
+                }
+                let len = list.len() as usize;
+                let shown = self.max_list_len.map_or(len, |max| max.min(len));
+                out.write_str("[")?;
                 let indent2 = indent.next();
-                for (idx, value) in list.iter().enumerate() {
-                    indent2.maybe_newline(formatter)?;
-                    print(cvt(value)?, formatter, indent2)?;
-                    if idx + 1 < list.len() as usize {
-                        indent2.comma(formatter)?;
+                for (idx, value) in list.iter().take(shown).enumerate() {
+                    indent2.maybe_newline(out)?;
+                    self.print_value(cvt(value)?, out, indent2, depth + 1)?;
+                    if idx + 1 < len {
+                        indent2.comma(out)?;
                     }
                 }
-                indent.maybe_newline(formatter)?;
-                formatter.write_str("]")
-            }
-        }
-        dynamic_value::Reader::Struct(st) => {
-            let schema = st.get_schema();
-            let union_fields = cvt(schema.get_union_fields())?;
-            let non_union_fields = cvt(schema.get_non_union_fields())?;
-            if union_fields.len() + non_union_fields.len() == 0 {
-                return formatter.write_str("()");
+                if shown < len {
+                    indent2.maybe_newline(out)?;
+                    write!(out, "... {} more", len - shown)?;
+                }
+                indent.maybe_newline(out)?;
+                out.write_str("]")
             }
-            formatter.write_str("(")?;
-            let indent2 = indent.next();
-            let mut union_field = match cvt(st.which())? {
-                None => None,
-                Some(field) => {
-                    // If it's not the default descriminant, then we always need to print it.
-                    if field.get_proto().get_discriminant_value() != 0 || cvt(st.has(field))? {
-                        Some(field)
-                    } else {
-                        None
-                    }
+            dynamic_value::Reader::Struct(st) => {
+                let schema = st.get_schema();
+                let union_fields = cvt(schema.get_union_fields())?;
+                let non_union_fields = cvt(schema.get_non_union_fields())?;
+                if union_fields.len() + non_union_fields.len() == 0 {
+                    return out.write_str("()");
                 }
-            };
-            let mut first = true;
-            for field in non_union_fields {
-                if let Some(ff) = union_field {
-                    if ff.get_index() < field.get_index() {
-                        // It's time to print the union field.
-                        if first {
-                            first = false
+                if elided {
+                    return out.write_str("(...)");
+                }
+                out.write_str("(")?;
+                let indent2 = indent.next();
+                let mut union_field = match cvt(st.which())? {
+                    None => None,
+                    Some(field) => {
+                        // If it's not the default descriminant, then we always need to print it.
+                        if field.get_proto().get_discriminant_value() != 0
+                            || (cvt(st.has(field))? && !self.is_hidden(st, field)?)
+                        {
+                            Some(field)
                         } else {
-                            indent2.comma(formatter)?;
+                            None
                         }
-                        indent2.maybe_newline(formatter)?;
-                        formatter.write_str(cvt(cvt(ff.get_proto().get_name())?.to_str())?)?;
-                        formatter.write_str(" = ")?;
-                        print(cvt(st.get(ff))?, formatter, indent2)?;
-                        union_field = None;
                     }
-                }
-                if cvt(st.has(field))? {
-                    if first {
-                        first = false
-                    } else {
-                        indent2.comma(formatter)?;
+                };
+                let mut first = true;
+                for field in non_union_fields {
+                    if let Some(ff) = union_field {
+                        if ff.get_index() < field.get_index() {
+                            // It's time to print the union field.
+                            self.print_field(st, ff, out, indent2, depth, &mut first)?;
+                            union_field = None;
+                        }
                     }
-                    indent2.maybe_newline(formatter)?;
-                    formatter.write_str(cvt(cvt(field.get_proto().get_name())?.to_str())?)?;
-                    formatter.write_str(" = ")?;
-                    print(cvt(st.get(field))?, formatter, indent2)?;
+                    if cvt(st.has(field))? && !self.is_hidden(st, field)? {
+                        self.print_field(st, field, out, indent2, depth, &mut first)?;
+                    }
+                }
+                if let Some(ff) = union_field {
+                    // Union field comes last.
+                    self.print_field(st, ff, out, indent2, depth, &mut first)?;
+                }
+                indent.maybe_newline(out)?;
+                out.write_str(")")
+            }
+            dynamic_value::Reader::AnyPointer(_) => out.write_str("<opaque pointer>"),
+            dynamic_value::Reader::Capability(_) => out.write_str("<external capability>"),
+        }
+    }
+
+    fn print_field(
+        &self,
+        st: dynamic_struct::Reader,
+        field: Field,
+        out: &mut dyn Write,
+        indent: Indent,
+        depth: usize,
+        first: &mut bool,
+    ) -> fmt::Result {
+        if *first {
+            *first = false
+        } else {
+            indent.comma(out)?;
+        }
+        indent.maybe_newline(out)?;
+        out.write_str(cvt(cvt(field.get_proto().get_name())?.to_str())?)?;
+        out.write_str(" = ")?;
+        if self.is_redacted(field)? {
+            out.write_str("<redacted>")
+        } else {
+            self.print_value(cvt(st.get(field))?, out, indent, depth + 1)
+        }
+    }
+
+    fn print_data(&self, data: &[u8], out: &mut dyn Write) -> fmt::Result {
+        let shown = self
+            .max_data_len
+            .map_or(data.len(), |max| max.min(data.len()));
+        match self.data_format {
+            DataFormat::Hex => {
+                out.write_str("0x\"")?;
+                for b in &data[..shown] {
+                    write!(out, "{b:02x}")?;
+                }
+                out.write_str("\"")?;
+                if shown < data.len() {
+                    write!(out, " ... {} more", data.len() - shown)?;
                 }
+                Ok(())
             }
-            if let Some(ff) = union_field {
-                // Union field comes last.
-                if !first {
-                    indent2.comma(formatter)?;
+            DataFormat::Decimal => {
+                out.write_str("[")?;
+                for (idx, b) in data[..shown].iter().enumerate() {
+                    if idx > 0 {
+                        out.write_str(", ")?;
+                    }
+                    write!(out, "{b}")?;
+                }
+                if shown < data.len() {
+                    if shown > 0 {
+                        out.write_str(", ")?;
+                    }
+                    write!(out, "... {} more", data.len() - shown)?;
                 }
-                indent2.maybe_newline(formatter)?;
-                formatter.write_str(cvt(cvt(ff.get_proto().get_name())?.to_str())?)?;
-                formatter.write_str(" = ")?;
-                print(cvt(st.get(ff))?, formatter, indent2)?;
+                out.write_str("]")
             }
-            indent.maybe_newline(formatter)?;
-            formatter.write_str(")")
         }
-        dynamic_value::Reader::AnyPointer(_) => formatter.write_str("<opaque pointer>"),
-        dynamic_value::Reader::Capability(_) => formatter.write_str("<external capability>"),
+    }
+
+    fn is_redacted(&self, field: Field) -> Result<bool, fmt::Error> {
+        let Some(id) = self.redact_annotation else {
+            return Ok(false);
+        };
+        let annotations = cvt(field.get_proto().get_annotations())?;
+        Ok(annotations.iter().any(|a| a.get_id() == id))
+    }
+
+    fn is_hidden(&self, st: dynamic_struct::Reader, field: Field) -> Result<bool, fmt::Error> {
+        Ok(self.hide_default_fields && cvt(is_default(st, field))?)
+    }
+}
+
+/// Helper struct for printing a dynamic value with [`format!`] and `{}`. Created by
+/// [`Printer::display`].
+pub struct Display<'p, 'a> {
+    printer: &'p Printer,
+    value: dynamic_value::Reader<'a>,
+}
+
+impl<'p, 'a> fmt::Display for Display<'p, 'a> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.printer.print(self.value, f)
     }
 }
 
+/// Returns true if `field`, which must be a non-union field or the active union member,
+/// holds the default value from the schema.
+fn is_default(st: dynamic_struct::Reader, field: Field) -> crate::Result<bool> {
+    let slot = match field.get_proto().which()? {
+        field::Group(_) => {
+            let dynamic_value::Reader::Struct(group) = st.get(field)? else {
+                return Ok(false);
+            };
+            return is_all_default(group);
+        }
+        field::Slot(slot) => slot,
+    };
+    if field.get_type().is_pointer_type() {
+        return Ok(!st.has(field)?);
+    }
+    Ok(match (st.get(field)?, slot.get_default_value()?.which()?) {
+        (dynamic_value::Reader::Void, _) => true,
+        (dynamic_value::Reader::Bool(x), value::Bool(d)) => x == d,
+        (dynamic_value::Reader::Int8(x), value::Int8(d)) => x == d,
+        (dynamic_value::Reader::Int16(x), value::Int16(d)) => x == d,
+        (dynamic_value::Reader::Int32(x), value::Int32(d)) => x == d,
+        (dynamic_value::Reader::Int64(x), value::Int64(d)) => x == d,
+        (dynamic_value::Reader::UInt8(x), value::Uint8(d)) => x == d,
+        (dynamic_value::Reader::UInt16(x), value::Uint16(d)) => x == d,
+        (dynamic_value::Reader::UInt32(x), value::Uint32(d)) => x == d,
+        (dynamic_value::Reader::UInt64(x), value::Uint64(d)) => x == d,
+        (dynamic_value::Reader::Float32(x), value::Float32(d)) => x.to_bits() == d.to_bits(),
+        (dynamic_value::Reader::Float64(x), value::Float64(d)) => x.to_bits() == d.to_bits(),
+        (dynamic_value::Reader::Enum(e), value::Enum(d)) => e.get_value() == d,
+        _ => false,
+    })
+}
+
+fn is_all_default(st: dynamic_struct::Reader) -> crate::Result<bool> {
+    if let Some(field) = st.which()? {
+        if field.get_proto().get_discriminant_value() != 0 || !is_default(st, field)? {
+            return Ok(false);
+        }
+    }
+    for field in st.get_schema().get_non_union_fields()? {
+        if !is_default(st, field)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
 impl<'a> fmt::Debug for dynamic_value::Reader<'a> {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        let indent = if f.alternate() {
-            Indent::enabled()
-        } else {
-            Indent::no_indent()
-        };
-        print(*self, f, indent)
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Printer::new().pretty(f.alternate()).print(*self, f)
     }
 }
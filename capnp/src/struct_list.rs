@@ -223,6 +223,12 @@ where
             builder: builder.init_struct_list(size, T::Builder::STRUCT_SIZE),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            marker: PhantomData,
+            builder: builder.try_init_struct_list(size, T::Builder::STRUCT_SIZE)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -263,6 +263,12 @@ impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, size: u32) -> Builder<'a> {
         builder.init_text(size)
     }
+    fn try_init_pointer(
+        builder: crate::private::layout::PointerBuilder<'a>,
+        size: u32,
+    ) -> Result<Builder<'a>> {
+        builder.try_init_text(size)
+    }
     fn get_from_pointer(
         builder: crate::private::layout::PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -277,8 +283,7 @@ impl<'a> crate::traits::SetPointerBuilder for Reader<'a> {
         value: Reader<'a>,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_text(value);
-        Ok(())
+        pointer.try_set_text(value)
     }
 }
 
@@ -290,8 +295,7 @@ impl<'a> crate::traits::SetPointerBuilder for &'a str {
         value: &'a str,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_text(value.into());
-        Ok(())
+        pointer.try_set_text(value.into())
     }
 }
 
@@ -0,0 +1,513 @@
+//! Parsing of the Cap'n Proto text format.
+//!
+//! This is the inverse of the `Debug` implementation for `dynamic_value::Reader`: text printed
+//! by `{:?}` or `{:#?}` can be parsed back into a `dynamic_struct::Builder` of the same schema.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::introspect::{Type, TypeVariant};
+use crate::schema::Field;
+use crate::{dynamic_list, dynamic_struct, dynamic_value};
+use crate::{Error, ErrorKind, Result};
+
+/// Values nested deeper than this are rejected, so that hostile input cannot overflow the stack.
+const NESTING_LIMIT: usize = 64;
+
+/// Parses `input` as a struct in Cap'n Proto text format and writes its fields into `builder`.
+///
+/// The input is a parenthesized list of `name = value` assignments, for example
+/// `(name = "x", list = [1, 2], inner = (a = 3))`. The outer parentheses may be omitted.
+/// Fields that do not appear in the input are left untouched. A `#` starts a comment that runs
+/// to the end of the line.
+///
+/// Values are written as follows:
+///
+/// * Void is `()`, and booleans are `true` or `false`.
+/// * Integers are decimal or `0x`-prefixed hexadecimal, optionally negative.
+/// * Floats additionally accept `inf`, `-inf` and `nan`.
+/// * Enums are enumerant names, or their numeric value.
+/// * Text is a double-quoted string with Rust-style escapes.
+/// * Data is `0x"..."` with pairs of hex digits, or a double-quoted string.
+/// * Lists are `[a, b, c]`, and structs and groups are `(field = value, ...)`.
+///
+/// `AnyPointer` and capability fields cannot be represented and are rejected.
+pub fn parse(input: &str, builder: dynamic_struct::Builder) -> Result<()> {
+    let mut parser = Parser { input, pos: 0 };
+    parser.skip_whitespace();
+    let fields = if parser.peek() == Some(b'(') {
+        parser.pos += 1;
+        parser.parse_fields(Some(b')'), 0)?
+    } else {
+        parser.parse_fields(None, 0)?
+    };
+    parser.skip_whitespace();
+    if parser.pos < input.len() {
+        return Err(parser.error("expected end of input"));
+    }
+    apply_struct(input, builder, &fields)
+}
+
+struct Value<'i> {
+    pos: usize,
+    kind: ValueKind<'i>,
+}
+
+enum ValueKind<'i> {
+    Ident(&'i str),
+    Number(&'i str),
+    String(String),
+    Data(Vec<u8>),
+    List(Vec<Value<'i>>),
+    Struct(Vec<FieldValue<'i>>),
+}
+
+struct FieldValue<'i> {
+    pos: usize,
+    name: &'i str,
+    value: Value<'i>,
+}
+
+fn line_and_column(input: &str, pos: usize) -> (usize, usize) {
+    let before = &input[..pos];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+pub(crate) fn error_at(kind: ErrorKind, input: &str, pos: usize, message: fmt::Arguments) -> Error {
+    let (line, column) = line_and_column(input, pos);
+    let mut error = Error::from_kind(kind);
+    write!(error, "{message} at line {line}, column {column}");
+    error
+}
+
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_'
+}
+
+fn is_ident_continue(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn hex_value(c: char) -> Option<u8> {
+    c.to_digit(16).map(|d| d as u8)
+}
+
+struct Parser<'i> {
+    input: &'i str,
+    pos: usize,
+}
+
+impl<'i> Parser<'i> {
+    fn error(&self, message: &str) -> Error {
+        error_at(
+            ErrorKind::InvalidTextFormat,
+            self.input,
+            self.pos,
+            format_args!("{message}"),
+        )
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.input[self.pos..].chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            match self.peek() {
+                Some(b) if b.is_ascii_whitespace() => self.pos += 1,
+                Some(b'#') => {
+                    while !matches!(self.peek(), None | Some(b'\n')) {
+                        self.pos += 1;
+                    }
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'i str {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b) if predicate(b)) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    /// Parses `name = value` pairs separated by commas, up to and including `close`, or up to
+    /// the end of input if `close` is `None`.
+    fn parse_fields(&mut self, close: Option<u8>, depth: usize) -> Result<Vec<FieldValue<'i>>> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b) if Some(b) == close => {
+                    self.pos += 1;
+                    return Ok(fields);
+                }
+                None if close.is_none() => return Ok(fields),
+                Some(b) if is_ident_start(b) => (),
+                _ => return Err(self.error("expected a field name")),
+            }
+            let pos = self.pos;
+            let name = self.take_while(is_ident_continue);
+            self.skip_whitespace();
+            if self.peek() != Some(b'=') {
+                return Err(self.error("expected `=`"));
+            }
+            self.pos += 1;
+            let value = self.parse_value(depth)?;
+            fields.push(FieldValue { pos, name, value });
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b) if Some(b) == close => {
+                    self.pos += 1;
+                    return Ok(fields);
+                }
+                None if close.is_none() => return Ok(fields),
+                _ => return Err(self.error("expected `,` or the end of the struct")),
+            }
+        }
+    }
+
+    fn parse_list(&mut self, depth: usize) -> Result<Vec<Value<'i>>> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(b']') {
+                self.pos += 1;
+                return Ok(items);
+            }
+            items.push(self.parse_value(depth)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(items);
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<Value<'i>> {
+        if depth >= NESTING_LIMIT {
+            return Err(self.error("values are nested too deeply"));
+        }
+        self.skip_whitespace();
+        let pos = self.pos;
+        let kind = match self.peek() {
+            None => return Err(self.error("unexpected end of input")),
+            Some(b'(') => {
+                self.pos += 1;
+                ValueKind::Struct(self.parse_fields(Some(b')'), depth + 1)?)
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                ValueKind::List(self.parse_list(depth + 1)?)
+            }
+            Some(b'"') => ValueKind::String(self.parse_string()?),
+            Some(b'0') if self.input[self.pos..].starts_with("0x\"") => {
+                self.pos += 2;
+                ValueKind::Data(self.parse_hex_data()?)
+            }
+            Some(b) if b == b'-' || b.is_ascii_digit() => ValueKind::Number(self.parse_number()?),
+            Some(b) if is_ident_start(b) => ValueKind::Ident(self.take_while(is_ident_continue)),
+            Some(_) => return Err(self.error("expected a value")),
+        };
+        Ok(Value { pos, kind })
+    }
+
+    fn parse_number(&mut self) -> Result<&'i str> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b) if is_ident_start(b) => {
+                // `-inf`
+                self.take_while(is_ident_continue);
+            }
+            Some(b) if b.is_ascii_digit() => {
+                let is_hex = self.input[self.pos..].starts_with("0x")
+                    || self.input[self.pos..].starts_with("0X");
+                loop {
+                    match self.peek() {
+                        Some(b) if is_ident_continue(b) || b == b'.' => self.pos += 1,
+                        Some(b'+' | b'-')
+                            if !is_hex
+                                && matches!(self.input.as_bytes()[self.pos - 1], b'e' | b'E') =>
+                        {
+                            self.pos += 1
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            _ => return Err(self.error("expected a number")),
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        // Skip the opening quote.
+        self.pos += 1;
+        let mut result = String::new();
+        loop {
+            let escape_pos = self.pos;
+            match self.next_char() {
+                None => return Err(self.error("unterminated string")),
+                Some('"') => return Ok(result),
+                Some('\\') => {
+                    let c = match self.next_char() {
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('0') => '\0',
+                        Some('a') => '\x07',
+                        Some('b') => '\x08',
+                        Some('f') => '\x0c',
+                        Some('v') => '\x0b',
+                        Some(c @ ('\\' | '"' | '\'')) => c,
+                        Some('x') => {
+                            let hi = self.next_char().and_then(hex_value);
+                            let lo = self.next_char().and_then(hex_value);
+                            match (hi, lo) {
+                                (Some(hi), Some(lo)) if hi < 8 => char::from(hi << 4 | lo),
+                                _ => {
+                                    self.pos = escape_pos;
+                                    return Err(self.error("invalid `\\x` escape"));
+                                }
+                            }
+                        }
+                        Some('u') => {
+                            let code = if self.peek() == Some(b'{') {
+                                self.pos += 1;
+                                let digits = self.take_while(|b| b.is_ascii_hexdigit());
+                                if self.peek() == Some(b'}') {
+                                    self.pos += 1;
+                                    u32::from_str_radix(digits, 16).ok()
+                                } else {
+                                    None
+                                }
+                            } else {
+                                None
+                            };
+                            match code.and_then(char::from_u32) {
+                                Some(c) => c,
+                                None => {
+                                    self.pos = escape_pos;
+                                    return Err(self.error("invalid `\\u` escape"));
+                                }
+                            }
+                        }
+                        _ => {
+                            self.pos = escape_pos;
+                            return Err(self.error("invalid escape sequence"));
+                        }
+                    };
+                    result.push(c);
+                }
+                Some(c) => result.push(c),
+            }
+        }
+    }
+
+    fn parse_hex_data(&mut self) -> Result<Vec<u8>> {
+        // Skip the opening quote.
+        self.pos += 1;
+        let mut result = Vec::new();
+        let mut high_nibble = None;
+        loop {
+            match self.next_char() {
+                None => return Err(self.error("unterminated data literal")),
+                Some('"') if high_nibble.is_none() => return Ok(result),
+                Some(c) if c.is_ascii_whitespace() && high_nibble.is_none() => (),
+                Some(c) => match (hex_value(c), high_nibble) {
+                    (Some(lo), Some(hi)) => {
+                        result.push(hi << 4 | lo);
+                        high_nibble = None;
+                    }
+                    (Some(hi), None) => high_nibble = Some(hi),
+                    (None, _) => {
+                        self.pos -= c.len_utf8();
+                        return Err(self.error("expected a pair of hex digits"));
+                    }
+                },
+            }
+        }
+    }
+}
+
+pub(crate) fn type_name(ty: Type) -> &'static str {
+    match ty.which() {
+        TypeVariant::Void => "void",
+        TypeVariant::Bool => "a bool",
+        TypeVariant::Int8 => "an Int8",
+        TypeVariant::Int16 => "an Int16",
+        TypeVariant::Int32 => "an Int32",
+        TypeVariant::Int64 => "an Int64",
+        TypeVariant::UInt8 => "a UInt8",
+        TypeVariant::UInt16 => "a UInt16",
+        TypeVariant::UInt32 => "a UInt32",
+        TypeVariant::UInt64 => "a UInt64",
+        TypeVariant::Float32 => "a Float32",
+        TypeVariant::Float64 => "a Float64",
+        TypeVariant::Enum(_) => "an enumerant",
+        TypeVariant::Text => "text",
+        TypeVariant::Data => "data",
+        TypeVariant::Struct(_) => "a struct",
+        TypeVariant::List(_) => "a list",
+        TypeVariant::AnyPointer => "an AnyPointer, which has no text representation",
+        TypeVariant::Capability(_) => "a capability, which has no text representation",
+    }
+}
+
+fn parse_integer<T: TryFrom<i128>>(value: &Value) -> Option<T> {
+    let ValueKind::Number(text) = value.kind else {
+        return None;
+    };
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let magnitude = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u128::from_str_radix(hex, 16).ok()?,
+        None => digits.parse::<u128>().ok()?,
+    };
+    let magnitude = i128::try_from(magnitude).ok()?;
+    T::try_from(if negative { -magnitude } else { magnitude }).ok()
+}
+
+fn parse_float<T: core::str::FromStr>(value: &Value) -> Option<T> {
+    match value.kind {
+        ValueKind::Number(text) | ValueKind::Ident(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Converts a value that does not need to be built in place into a `dynamic_value::Reader`.
+fn scalar<'v>(input: &str, ty: Type, value: &'v Value) -> Result<dynamic_value::Reader<'v>> {
+    let result = match (ty.which(), &value.kind) {
+        (TypeVariant::Void, ValueKind::Struct(fields)) if fields.is_empty() => {
+            Some(dynamic_value::Reader::Void)
+        }
+        (TypeVariant::Bool, ValueKind::Ident("true")) => Some(true.into()),
+        (TypeVariant::Bool, ValueKind::Ident("false")) => Some(false.into()),
+        (TypeVariant::Int8, _) => parse_integer::<i8>(value).map(Into::into),
+        (TypeVariant::Int16, _) => parse_integer::<i16>(value).map(Into::into),
+        (TypeVariant::Int32, _) => parse_integer::<i32>(value).map(Into::into),
+        (TypeVariant::Int64, _) => parse_integer::<i64>(value).map(Into::into),
+        (TypeVariant::UInt8, _) => parse_integer::<u8>(value).map(Into::into),
+        (TypeVariant::UInt16, _) => parse_integer::<u16>(value).map(Into::into),
+        (TypeVariant::UInt32, _) => parse_integer::<u32>(value).map(Into::into),
+        (TypeVariant::UInt64, _) => parse_integer::<u64>(value).map(Into::into),
+        (TypeVariant::Float32, _) => parse_float::<f32>(value).map(Into::into),
+        (TypeVariant::Float64, _) => parse_float::<f64>(value).map(Into::into),
+        (TypeVariant::Enum(raw), ValueKind::Ident(name)) => {
+            let schema: crate::schema::EnumSchema = raw.into();
+            let mut found = None;
+            for enumerant in schema.get_enumerants()?.iter() {
+                if enumerant.get_proto().get_name()?.to_str()? == *name {
+                    found = Some(enumerant.get_ordinal());
+                    break;
+                }
+            }
+            found.map(|ordinal| dynamic_value::Enum::new(ordinal, schema).into())
+        }
+        (TypeVariant::Enum(raw), ValueKind::Number(_)) => parse_integer::<u16>(value)
+            .map(|ordinal| dynamic_value::Enum::new(ordinal, raw.into()).into()),
+        (TypeVariant::Text, ValueKind::String(s)) => Some(s.as_str().into()),
+        (TypeVariant::Data, ValueKind::Data(d)) => Some(dynamic_value::Reader::Data(d)),
+        (TypeVariant::Data, ValueKind::String(s)) => {
+            Some(dynamic_value::Reader::Data(s.as_bytes()))
+        }
+        _ => None,
+    };
+    result.ok_or_else(|| {
+        error_at(
+            ErrorKind::TypeMismatch,
+            input,
+            value.pos,
+            format_args!("expected {}", type_name(ty)),
+        )
+    })
+}
+
+fn apply_field(
+    input: &str,
+    mut builder: dynamic_struct::Builder,
+    field: Field,
+    value: &Value,
+) -> Result<()> {
+    match (field.get_type().which(), &value.kind) {
+        (TypeVariant::Struct(_), ValueKind::Struct(fields)) => {
+            // Merge into a struct or group that is already set, so that its fields that do not
+            // appear in the input are left untouched too.
+            let nested = if builder.has(field)? {
+                builder.get(field)?
+            } else {
+                builder.init(field)?
+            };
+            apply_struct(input, nested.downcast(), fields)
+        }
+        (TypeVariant::List(_), ValueKind::List(items)) => apply_list(
+            input,
+            builder.initn(field, items.len() as u32)?.downcast(),
+            items,
+        ),
+        _ => builder.set(field, scalar(input, field.get_type(), value)?),
+    }
+}
+
+fn apply_struct(
+    input: &str,
+    mut builder: dynamic_struct::Builder,
+    fields: &[FieldValue],
+) -> Result<()> {
+    let schema = builder.get_schema();
+    for field_value in fields {
+        let Some(field) = schema.find_field_by_name(field_value.name)? else {
+            return Err(error_at(
+                ErrorKind::FieldNotFound,
+                input,
+                field_value.pos,
+                format_args!("{}", field_value.name),
+            ));
+        };
+        apply_field(input, builder.reborrow(), field, &field_value.value)?;
+    }
+    Ok(())
+}
+
+fn apply_list(input: &str, mut list: dynamic_list::Builder, items: &[Value]) -> Result<()> {
+    let element_type = list.element_type();
+    for (index, item) in items.iter().enumerate() {
+        let index = index as u32;
+        match (element_type.which(), &item.kind) {
+            (TypeVariant::Struct(_), ValueKind::Struct(fields)) => {
+                apply_struct(input, list.reborrow().get(index)?.downcast(), fields)?
+            }
+            (TypeVariant::List(_), ValueKind::List(inner)) => apply_list(
+                input,
+                list.reborrow().init(index, inner.len() as u32)?.downcast(),
+                inner,
+            )?,
+            _ => list.set(index, scalar(input, element_type, item)?)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,385 @@
+//! Copying of values between different versions of a schema.
+//!
+//! Copying a struct with `SetPointerBuilder` copies its raw pointers, which is only meaningful
+//! when the source and destination have the same layout. A [`Transcoder`] instead walks the
+//! source value field by field, and writes each field to the destination field of the same
+//! name. This allows stored data to be migrated across schema changes that are not wire
+//! compatible, such as renaming a field, moving it into or out of a group, or widening its
+//! type.
+//!
+//! Source values that have nowhere to go in the destination are returned to the caller as
+//! [`Dropped`] values, so that a migration can decide whether losing them is acceptable.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::dynamic_value::{self, Enum};
+use crate::introspect::{Type, TypeVariant};
+use crate::schema::{EnumSchema, Field, StructSchema};
+use crate::schema_capnp::field;
+use crate::{dynamic_list, dynamic_struct};
+use crate::{Error, ErrorKind, Result};
+
+/// A source value that was not copied to the destination.
+#[derive(Clone)]
+pub struct Dropped<'a> {
+    /// Where the value is in the source, like `foo.bar[3].baz`.
+    pub path: String,
+
+    /// The value itself.
+    pub value: dynamic_value::Reader<'a>,
+}
+
+impl<'a> core::fmt::Display for Dropped<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}: {:?}", self.path, self.value)
+    }
+}
+
+/// Copies structs field by field between schemas that are compatible in their field names.
+///
+/// A source field is written to the destination field that has the same name, or that
+/// carries the [old name annotation](Self::old_name_annotation) naming it. If there is no
+/// such field at the same level, fields in the destination's groups are tried, so a field
+/// that has moved into a group is still found. Likewise, a source group with no counterpart
+/// has its fields copied to the enclosing destination struct.
+///
+/// Values are converted as follows:
+/// * numbers are copied to any numeric type that can represent every value of their own type,
+///   so an `Int16` may become an `Int32` or a `Float32`, but not a `UInt32`;
+/// * enums are matched by enumerant name;
+/// * structs and lists are copied recursively, and everything else is copied as is.
+///
+/// Any other combination of types fails with `ErrorKind::TypeMismatch`. Source fields without
+/// a destination, and enumerants missing from the destination enum, are reported as
+/// [`Dropped`] if they hold a value other than their default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Transcoder {
+    old_name_annotation: Option<u64>,
+}
+
+impl Transcoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the ID of a `Text` annotation, applied to fields, that gives the name a field
+    /// had in another version of the schema, such as
+    /// ```capnp
+    /// annotation oldName(field) :Text;
+    ///
+    /// struct Person {
+    ///   emailAddress @2 :Text $oldName("email");
+    /// }
+    /// ```
+    /// The annotation is honored on both source and destination fields.
+    pub fn old_name_annotation(mut self, id: u64) -> Self {
+        self.old_name_annotation = Some(id);
+        self
+    }
+
+    /// Copies `source` into `destination`, which should be freshly initialized. Returns the
+    /// source values that were not copied.
+    pub fn transcode<'a>(
+        &self,
+        source: dynamic_struct::Reader<'a>,
+        destination: dynamic_struct::Builder<'_>,
+    ) -> Result<Vec<Dropped<'a>>> {
+        let mut dropped = Vec::new();
+        self.transcode_struct(source, destination, &mut String::new(), &mut dropped)?;
+        Ok(dropped)
+    }
+
+    fn transcode_struct<'a>(
+        &self,
+        source: dynamic_struct::Reader<'a>,
+        mut destination: dynamic_struct::Builder<'_>,
+        path: &mut String,
+        dropped: &mut Vec<Dropped<'a>>,
+    ) -> Result<()> {
+        let fields = source.get_schema().get_non_union_fields()?;
+        for field in fields.iter().chain(source.which()?) {
+            let len = path.len();
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(field.get_proto().get_name()?.to_str()?);
+
+            match self.find_destination(field, destination.get_schema())? {
+                Some(route) => self.transcode_field(
+                    source,
+                    field,
+                    destination.reborrow(),
+                    &route,
+                    path,
+                    dropped,
+                )?,
+                None => match field.get_proto().which()? {
+                    field::Group(_) => {
+                        let group = source.get(field)?.downcast();
+                        self.transcode_struct(group, destination.reborrow(), path, dropped)?
+                    }
+                    field::Slot(slot) => {
+                        let value = source.get(field)?;
+                        let is_default = if field.get_type().is_pointer_type() {
+                            !source.has(field)?
+                        } else {
+                            let default = dynamic_value::Reader::new(
+                                slot.get_default_value()?,
+                                field.get_type(),
+                            )?;
+                            dynamic_value::diff(value, default)?.is_empty()
+                        };
+                        if !is_default {
+                            dropped.push(Dropped {
+                                path: path.clone(),
+                                value,
+                            });
+                        }
+                    }
+                },
+            }
+            path.truncate(len);
+        }
+        Ok(())
+    }
+
+    /// Copies `field` of `source` to the field at the end of `route`, a chain of groups
+    /// starting in `destination`.
+    fn transcode_field<'a>(
+        &self,
+        source: dynamic_struct::Reader<'a>,
+        field: Field,
+        mut destination: dynamic_struct::Builder<'_>,
+        route: &[Field],
+        path: &mut String,
+        dropped: &mut Vec<Dropped<'a>>,
+    ) -> Result<()> {
+        if let field::Slot(slot) = field.get_proto().which()? {
+            if field.get_type().is_pointer_type()
+                && !source.has(field)?
+                && !slot.get_had_explicit_default()
+            {
+                return Ok(());
+            }
+        }
+        let (target, groups) = route.split_last().expect("route is never empty");
+        for &group in groups {
+            destination = enter_group(destination, group)?;
+        }
+
+        let value = source.get(field)?;
+        match (value, target.get_type().which()) {
+            (dynamic_value::Reader::Struct(s), TypeVariant::Struct(_)) => {
+                let builder = match target.get_proto().which()? {
+                    field::Group(_) => enter_group(destination, *target)?,
+                    field::Slot(_) => destination.init(*target)?.downcast(),
+                };
+                self.transcode_struct(s, builder, path, dropped)
+            }
+            (dynamic_value::Reader::List(l), TypeVariant::List(_)) => {
+                let builder = destination.initn(*target, l.len())?.downcast();
+                self.transcode_list(l, builder, path, dropped)
+            }
+            _ => match convert(value, target.get_type(), path)? {
+                Some(value) => destination.set(*target, value),
+                None => {
+                    dropped.push(Dropped {
+                        path: path.clone(),
+                        value,
+                    });
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    fn transcode_list<'a>(
+        &self,
+        source: dynamic_list::Reader<'a>,
+        mut destination: dynamic_list::Builder<'_>,
+        path: &mut String,
+        dropped: &mut Vec<Dropped<'a>>,
+    ) -> Result<()> {
+        for index in 0..source.len() {
+            let len = path.len();
+            path.push_str(&alloc::format!("[{index}]"));
+            let value = source.get(index)?;
+            match (value, destination.element_type().which()) {
+                (dynamic_value::Reader::Struct(s), TypeVariant::Struct(_)) => {
+                    let builder = destination.reborrow().get(index)?.downcast();
+                    self.transcode_struct(s, builder, path, dropped)?
+                }
+                (dynamic_value::Reader::List(l), TypeVariant::List(_)) => {
+                    let builder = destination.reborrow().init(index, l.len())?.downcast();
+                    self.transcode_list(l, builder, path, dropped)?
+                }
+                _ => match convert(value, destination.element_type(), path)? {
+                    Some(converted) => destination.set(index, converted)?,
+                    None => dropped.push(Dropped {
+                        path: path.clone(),
+                        value,
+                    }),
+                },
+            }
+            path.truncate(len);
+        }
+        Ok(())
+    }
+
+    /// Finds the field of `schema`, possibly inside groups, that `field` should be copied to.
+    /// Returns the groups leading to it, followed by the field itself.
+    fn find_destination(&self, field: Field, schema: StructSchema) -> Result<Option<Vec<Field>>> {
+        let name = field.get_proto().get_name()?.to_str()?;
+        let old_name = self.old_name(field)?;
+        let fields = schema.get_fields()?;
+        for candidate in fields {
+            if candidate.get_proto().get_name()?.to_str()? == name {
+                return Ok(Some(alloc::vec![candidate]));
+            }
+        }
+        for candidate in fields {
+            let candidate_name = candidate.get_proto().get_name()?.to_str()?;
+            if self.old_name(candidate)? == Some(name) || old_name == Some(candidate_name) {
+                return Ok(Some(alloc::vec![candidate]));
+            }
+        }
+        for candidate in fields {
+            if let (field::Group(_), TypeVariant::Struct(group)) =
+                (candidate.get_proto().which()?, candidate.get_type().which())
+            {
+                if let Some(mut route) = self.find_destination(field, group.into())? {
+                    route.insert(0, candidate);
+                    return Ok(Some(route));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn old_name(&self, field: Field) -> Result<Option<&'static str>> {
+        let Some(id) = self.old_name_annotation else {
+            return Ok(None);
+        };
+        match field.get_annotations()?.find(id) {
+            None => Ok(None),
+            Some(annotation) => match annotation.get_value()? {
+                dynamic_value::Reader::Text(name) => Ok(Some(name.to_str()?)),
+                _ => Err(Error::from_kind(ErrorKind::TypeMismatch)),
+            },
+        }
+    }
+}
+
+/// Copies `source` into `destination` with a default [`Transcoder`].
+pub fn transcode<'a>(
+    source: dynamic_struct::Reader<'a>,
+    destination: dynamic_struct::Builder<'_>,
+) -> Result<Vec<Dropped<'a>>> {
+    Transcoder::new().transcode(source, destination)
+}
+
+/// Gets the builder for `group`, making it the active union member if it is not already.
+fn enter_group(
+    destination: dynamic_struct::Builder<'_>,
+    group: Field,
+) -> Result<dynamic_struct::Builder<'_>> {
+    let in_union = group.get_proto().get_discriminant_value() != field::NO_DISCRIMINANT;
+    let active = destination.which()?.map(|f| f.get_index()) == Some(group.get_index());
+    if in_union && !active {
+        Ok(destination.init(group)?.downcast())
+    } else {
+        Ok(destination.get(group)?.downcast())
+    }
+}
+
+/// Converts a value that is not a struct or list to `ty`. Returns `None` for an enumerant
+/// that `ty` does not have.
+fn convert<'a>(
+    value: dynamic_value::Reader<'a>,
+    ty: Type,
+    path: &str,
+) -> Result<Option<dynamic_value::Reader<'a>>> {
+    use dynamic_value::Reader;
+
+    let converted = match (value, ty.which()) {
+        (Reader::Void, TypeVariant::Void)
+        | (Reader::Bool(_), TypeVariant::Bool)
+        | (Reader::Text(_), TypeVariant::Text)
+        | (Reader::Data(_), TypeVariant::Data)
+        | (Reader::AnyPointer(_), TypeVariant::AnyPointer)
+        | (Reader::Capability(_), TypeVariant::Capability(_)) => Some(value),
+        (Reader::Enum(e), TypeVariant::Enum(schema)) => {
+            let schema: EnumSchema = schema.into();
+            let Some(enumerant) = e.get_enumerant()? else {
+                return Ok(None);
+            };
+            let name = enumerant.get_proto().get_name()?;
+            let mut found = None;
+            for candidate in schema.get_enumerants()? {
+                if candidate.get_proto().get_name()? == name {
+                    found = Some(Enum::new(candidate.get_ordinal(), schema).into());
+                    break;
+                }
+            }
+            return Ok(found);
+        }
+        _ => widen(value, ty),
+    };
+    match converted {
+        Some(value) => Ok(Some(value)),
+        None => {
+            let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+            write!(error, "{path}");
+            Err(error)
+        }
+    }
+}
+
+/// Converts a number to `ty`, if `ty` can represent every value of the number's type.
+fn widen<'a>(value: dynamic_value::Reader<'_>, ty: Type) -> Option<dynamic_value::Reader<'a>> {
+    use dynamic_value::Reader;
+
+    let (int, bits, signed) = match value {
+        Reader::Int8(x) => (x as i128, 8, true),
+        Reader::Int16(x) => (x as i128, 16, true),
+        Reader::Int32(x) => (x as i128, 32, true),
+        Reader::Int64(x) => (x as i128, 64, true),
+        Reader::UInt8(x) => (x as i128, 8, false),
+        Reader::UInt16(x) => (x as i128, 16, false),
+        Reader::UInt32(x) => (x as i128, 32, false),
+        Reader::UInt64(x) => (x as i128, 64, false),
+        Reader::Float32(x) => {
+            return match ty.which() {
+                TypeVariant::Float32 => Some(Reader::Float32(x)),
+                TypeVariant::Float64 => Some(Reader::Float64(x.into())),
+                _ => None,
+            }
+        }
+        Reader::Float64(x) => {
+            return matches!(ty.which(), TypeVariant::Float64).then_some(Reader::Float64(x))
+        }
+        _ => return None,
+    };
+    let fits = |to_bits: u32, to_signed: bool| {
+        if signed == to_signed {
+            to_bits >= bits
+        } else {
+            to_signed && to_bits > bits
+        }
+    };
+    match ty.which() {
+        TypeVariant::Int8 if fits(8, true) => Some(Reader::Int8(int as i8)),
+        TypeVariant::Int16 if fits(16, true) => Some(Reader::Int16(int as i16)),
+        TypeVariant::Int32 if fits(32, true) => Some(Reader::Int32(int as i32)),
+        TypeVariant::Int64 if fits(64, true) => Some(Reader::Int64(int as i64)),
+        TypeVariant::UInt8 if fits(8, false) => Some(Reader::UInt8(int as u8)),
+        TypeVariant::UInt16 if fits(16, false) => Some(Reader::UInt16(int as u16)),
+        TypeVariant::UInt32 if fits(32, false) => Some(Reader::UInt32(int as u32)),
+        TypeVariant::UInt64 if fits(64, false) => Some(Reader::UInt64(int as u64)),
+        // Floats have 24 and 53 bits of mantissa.
+        TypeVariant::Float32 if bits <= 16 => Some(Reader::Float32(int as f32)),
+        TypeVariant::Float64 if bits <= 32 => Some(Reader::Float64(int as f64)),
+        _ => None,
+    }
+}
@@ -21,6 +21,16 @@
 
 use capnp::message;
 
+/// Checks that streaming the canonical form of the root gives the same bytes as
+/// `canonicalize()`.
+#[cfg(feature = "alloc")]
+fn assert_streams_canonical<S: message::ReaderSegments>(message: &message::Reader<S>) {
+    let root: capnp::any_pointer::Reader = message.get_root().unwrap();
+    let streamed = capnp::canonical::to_bytes(&root).unwrap();
+    let canonical_words = message.canonicalize().unwrap();
+    assert_eq!(streamed, capnp::Word::words_to_bytes(&canonical_words[..]));
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn canonicalize_succeeds_on_null_message() {
@@ -32,6 +42,7 @@ fn canonicalize_succeeds_on_null_message() {
     assert!(message.is_canonical().unwrap());
 
     let canonical_bytes = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
     assert_eq!(&canonical_bytes[..], segment);
 }
 
@@ -55,6 +66,7 @@ fn dont_truncate_struct_too_far() {
     assert!(!message.is_canonical().unwrap());
 
     let canonicalized = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
 
     let canonical_segment: &[capnp::Word] = &[
         capnp::word(0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00),
@@ -89,6 +101,7 @@ fn dont_truncate_struct_list_too_far() {
     assert!(!message.is_canonical().unwrap());
 
     let canonicalized = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
 
     let canonical_segment: &[capnp::Word] = &[
         capnp::word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00),
@@ -202,6 +215,7 @@ fn simple_multisegment_message() {
     assert!(!message.is_canonical().unwrap());
 
     let canonicalized = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
     let canonical_segment: &[capnp::Word] = &[
         capnp::word(0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00),
         capnp::word(0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07),
@@ -230,6 +244,7 @@ fn multisegment_only_first_segment_used() {
     assert!(!message.is_canonical().unwrap());
 
     let canonicalized = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
     let canonical_segment: &[capnp::Word] = &[
         capnp::word(0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00),
         capnp::word(0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07),
@@ -288,6 +303,7 @@ fn empty_inline_composite_list_of_0_sized_structs() {
     assert!(message.is_canonical().unwrap());
 
     let canonical_words = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
     assert_eq!(segment, &canonical_words[..]);
 }
 
@@ -309,6 +325,7 @@ fn inline_composite_list_with_void_list() {
     assert!(message.is_canonical().unwrap());
 
     let canonical_words = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
     assert_eq!(segment, &canonical_words[..]);
 }
 
@@ -355,6 +372,7 @@ fn truncate_data_section_inline_composite() {
     assert!(!message.is_canonical().unwrap());
 
     let canonical_words = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
 
     let canonical_segments = &[capnp::Word::words_to_bytes(&canonical_words[..])];
     let canonical_segment_array = message::SegmentArray::new(canonical_segments);
@@ -380,6 +398,7 @@ fn truncate_pointer_section_inline_composite() {
     assert!(!message.is_canonical().unwrap());
 
     let canonical_words = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
 
     let canonical_segments = &[capnp::Word::words_to_bytes(&canonical_words[..])];
     let canonical_segment_array = message::SegmentArray::new(canonical_segments);
@@ -412,6 +431,7 @@ fn list_padding_must_be_zero() {
     assert!(!message.is_canonical().unwrap());
 
     let canonical_words = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
 
     let canonical_segments = &[capnp::Word::words_to_bytes(&canonical_words[..])];
     let canonical_segment_array = message::SegmentArray::new(canonical_segments);
@@ -442,6 +462,7 @@ fn bit_list_padding_must_be_zero() {
     assert!(!message.is_canonical().unwrap());
 
     let canonical_words = message.canonicalize().unwrap();
+    assert_streams_canonical(&message);
 
     let canonical_segments = &[capnp::Word::words_to_bytes(&canonical_words[..])];
     let canonical_segment_array = message::SegmentArray::new(canonical_segments);
@@ -498,3 +519,24 @@ fn far_pointer_to_same_segment() {
     let message = message::Reader::new(segment_array, Default::default());
     assert!(!message.is_canonical().unwrap());
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn canonical_stream_rejects_capability() {
+    let segment: &[capnp::Word] = &[
+        // Struct pointer, body immediately follows, one pointer
+        capnp::word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00),
+        // Capability pointer, index 0
+        capnp::word(0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+    ];
+
+    let segments = &[capnp::Word::words_to_bytes(segment)];
+    let segment_array = message::SegmentArray::new(segments);
+    let message = message::Reader::new(segment_array, Default::default());
+    let root: capnp::any_pointer::Reader = message.get_root().unwrap();
+    let error = capnp::canonical::to_bytes(&root).unwrap_err();
+    assert_eq!(
+        error.kind,
+        capnp::ErrorKind::CannotCreateACanonicalMessageWithACapability
+    );
+}
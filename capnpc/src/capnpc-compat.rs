@@ -0,0 +1,38 @@
+//! Checks a new version of a schema for changes that break wire compatibility with an old one.
+//!
+//! Usage: `capnpc-compat OLD NEW`, where `OLD` and `NEW` are files containing the serialized
+//! `CodeGeneratorRequest`s of the two versions, as written by `capnp compile -o-` or by
+//! `CompilerCommand::raw_code_generator_request_path()`.
+//!
+//! Prints one tab-separated line per incompatibility, and exits with status 1 if there are any.
+
+fn read_request(path: &str) -> capnp::message::Reader<capnp::serialize::OwnedSegments> {
+    let file = std::fs::File::open(path).unwrap_or_else(|e| panic!("failed to open {path}: {e}"));
+    let mut options = capnp::message::ReaderOptions::new();
+    options.traversal_limit_in_words(None);
+    capnp::serialize::read_message(std::io::BufReader::new(file), options)
+        .unwrap_or_else(|e| panic!("failed to read {path}: {e}"))
+}
+
+pub fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} OLD NEW", args[0]);
+        std::process::exit(2);
+    }
+
+    let old = read_request(&args[1]);
+    let new = read_request(&args[2]);
+    let incompatibilities = ::capnpc::compat::check(
+        old.get_root().expect("failed to read old request"),
+        new.get_root().expect("failed to read new request"),
+    )
+    .expect("failed to compare schemas");
+
+    for incompatibility in &incompatibilities {
+        println!("{incompatibility}");
+    }
+    if !incompatibilities.is_empty() {
+        std::process::exit(1);
+    }
+}
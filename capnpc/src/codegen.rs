@@ -1002,6 +1002,12 @@ fn generate_setter(
     let mut initn_interior = Vec::new();
     let mut initter_params = Vec::new();
 
+    // Fallible versions of the setter and initter, for fields whose plain versions panic
+    // when the message's allocator runs out of space.
+    let mut try_interior = Vec::new();
+    let mut try_setter = None;
+    let mut try_initter = None;
+
     let discriminant_value = field.get_discriminant_value();
     if discriminant_value != field::NO_DISCRIMINANT {
         setter_interior.push(Line(format!(
@@ -1013,11 +1019,13 @@ fn generate_setter(
             discriminant_offset as usize, discriminant_value as usize
         ));
         initter_interior.push(init_discrim.clone());
-        initn_interior.push(init_discrim);
+        initn_interior.push(init_discrim.clone());
+        try_interior.push(init_discrim);
     }
 
     let mut return_result = false;
     let mut result = Vec::new();
+    let mut maybe_orphan_field: Option<(usize, String)> = None;
 
     let (maybe_reader_type, maybe_builder_type): (Option<String>, Option<String>) = match field
         .which()?
@@ -1045,6 +1053,9 @@ fn generate_setter(
         field::Slot(reg_field) => {
             let offset = reg_field.get_offset() as usize;
             let typ = reg_field.get_type()?;
+            if typ.is_pointer()? && !matches!(typ.which()?, type_::Interface(_)) {
+                maybe_orphan_field = Some((offset, typ.type_string(ctx, Leaf::Owned)?));
+            }
             match typ.which().expect("unrecognized type") {
                 type_::Void(()) => {
                     setter_param = "_value".to_string();
@@ -1088,6 +1099,12 @@ fn generate_setter(
                     initter_interior.push(Line(format!(
                         "self.builder.get_pointer_field({offset}).init_text(size)"
                     )));
+                    try_setter = Some(Line(format!(
+                        "self.builder.reborrow().get_pointer_field({offset}).try_set_text(value)"
+                    )));
+                    try_initter = Some(Line(format!(
+                        "self.builder.get_pointer_field({offset}).try_init_text(size)"
+                    )));
                     initter_params.push("size: u32");
                     (
                         Some(fmt!(ctx, "{capnp}::text::Reader<'_>")),
@@ -1101,6 +1118,12 @@ fn generate_setter(
                     initter_interior.push(Line(format!(
                         "self.builder.get_pointer_field({offset}).init_data(size)"
                     )));
+                    try_setter = Some(Line(format!(
+                        "self.builder.reborrow().get_pointer_field({offset}).try_set_data(value)"
+                    )));
+                    try_initter = Some(Line(format!(
+                        "self.builder.get_pointer_field({offset}).try_init_data(size)"
+                    )));
                     initter_params.push("size: u32");
                     (
                         Some(fmt!(ctx, "{capnp}::data::Reader<'_>")),
@@ -1115,6 +1138,8 @@ fn generate_setter(
                     initter_params.push("size: u32");
                     initter_interior.push(
                         Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field({offset}), size)")));
+                    try_initter = Some(
+                        Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::try_init_pointer(self.builder.get_pointer_field({offset}), size)")));
 
                     match ot1.get_element_type()?.which()? {
                         type_::List(_) => (
@@ -1158,6 +1183,8 @@ fn generate_setter(
                     return_result = true;
                     initter_interior.push(
                       Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field({offset}), 0)")));
+                    try_initter = Some(
+                      Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::try_init_pointer(self.builder.get_pointer_field({offset}), 0)")));
                     if typ.is_branded()? {
                         setter_interior.push(
                             Line(fmt!(ctx,
@@ -1190,6 +1217,7 @@ fn generate_setter(
                 type_::AnyPointer(_) => {
                     if typ.is_parameter()? {
                         initter_interior.push(Line(fmt!(ctx,"{capnp}::any_pointer::Builder::new(self.builder.get_pointer_field({offset})).init_as()")));
+                        try_initter = Some(Line(fmt!(ctx,"{capnp}::any_pointer::Builder::new(self.builder.get_pointer_field({offset})).try_init_as()")));
                         setter_interior.push(Line(fmt!(ctx,"{capnp}::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field({offset}), value, false)")));
                         return_result = true;
 
@@ -1232,6 +1260,17 @@ fn generate_setter(
         )));
         result.push(indent(setter_interior));
         result.push(line("}"));
+        if let Some(try_setter) = try_setter {
+            let mut interior = try_interior.clone();
+            interior.push(try_setter);
+            result.push(line("#[inline]"));
+            result.push(Line(fmt!(
+                ctx,
+                "pub fn try_set_{styled_name}(&mut self, {setter_param}: {reader_type}) -> {capnp}::Result<()> {{"
+            )));
+            result.push(indent(interior));
+            result.push(line("}"));
+        }
     }
     if let Some(builder_type) = maybe_builder_type {
         result.push(line("#[inline]"));
@@ -1242,6 +1281,58 @@ fn generate_setter(
         )));
         result.push(indent(initter_interior));
         result.push(line("}"));
+        if let Some(try_initter) = try_initter {
+            let mut interior = try_interior;
+            interior.push(try_initter);
+            result.push(line("#[inline]"));
+            result.push(Line(fmt!(
+                ctx,
+                "pub fn try_init_{styled_name}(self, {args}) -> {capnp}::Result<{builder_type}> {{"
+            )));
+            result.push(indent(interior));
+            result.push(line("}"));
+        }
+    }
+    if let Some((offset, owned_type)) = maybe_orphan_field {
+        let mut adopter_interior = Vec::new();
+        if discriminant_value != field::NO_DISCRIMINANT {
+            adopter_interior.push(Line(format!(
+                "self.builder.set_data_field::<u16>({}, {});",
+                discriminant_offset as usize, discriminant_value as usize
+            )));
+        }
+        adopter_interior.push(Line(format!(
+            "self.builder.reborrow().get_pointer_field({offset}).adopt(orphan.into_internal());"
+        )));
+        result.push(line("#[cfg(target_has_atomic = \"ptr\")]"));
+        result.push(line("#[inline]"));
+        result.push(Line(fmt!(
+            ctx,
+            "pub fn adopt_{styled_name}(&mut self, orphan: {capnp}::orphan::Orphan<{owned_type}>) {{"
+        )));
+        result.push(indent(adopter_interior));
+        result.push(line("}"));
+        let mut disowner_interior = Vec::new();
+        if discriminant_value != field::NO_DISCRIMINANT {
+            // Like the C++ implementation, refuse to disown an inactive union member, whose
+            // pointer holds some other member's object.
+            disowner_interior.push(Line(format!(
+                "assert!(self.builder.get_data_field::<u16>({}) == {}, \"disown_{styled_name}() called when {styled_name} is not the active union member\");",
+                discriminant_offset as usize, discriminant_value as usize
+            )));
+        }
+        disowner_interior.push(Line(fmt!(
+            ctx,
+            "{capnp}::orphan::Orphan::new(self.builder.reborrow().get_pointer_field({offset}).disown())"
+        )));
+        result.push(line("#[cfg(target_has_atomic = \"ptr\")]"));
+        result.push(line("#[inline]"));
+        result.push(Line(fmt!(
+            ctx,
+            "pub fn disown_{styled_name}(&mut self) -> {capnp}::orphan::Orphan<{owned_type}> {{"
+        )));
+        result.push(indent(disowner_interior));
+        result.push(line("}"));
     }
     Ok(Branch(result))
 }
@@ -1700,6 +1791,48 @@ fn generate_get_field_types(
     }
 }
 
+fn generate_get_interface_types(
+    ctx: &GeneratorContext,
+    node_reader: schema_capnp::node::Reader,
+    method_branches: Vec<FormattedText>,
+    superclass_branches: Vec<FormattedText>,
+) -> ::capnp::Result<FormattedText> {
+    fn match_body(mut branches: Vec<FormattedText>, what: &str) -> FormattedText {
+        if branches.is_empty() {
+            Line(format!("panic!(\"invalid {what} index {{}}\", index)"))
+        } else {
+            branches.push(Line(format!(
+                "_ => panic!(\"invalid {what} index {{}}\", index),"
+            )));
+            Branch(vec![
+                Line("match index {".into()),
+                indent(branches),
+                Line("}".into()),
+            ])
+        }
+    }
+    let (params, where_clause) = if node_reader.get_is_generic() {
+        let params = node_reader.parameters_texts(ctx);
+        (format!("<{}>", params.params), params.where_clause)
+    } else {
+        ("".to_string(), "".to_string())
+    };
+    Ok(Branch(vec![
+        Line(fmt!(
+            ctx,
+            "pub fn get_method_types{params}(index: u16) -> ({capnp}::introspect::Type, {capnp}::introspect::Type) {where_clause} {{"
+        )),
+        indent(match_body(method_branches, "method")),
+        Line("}".into()),
+        Line(fmt!(
+            ctx,
+            "pub fn get_superclass_types{params}(index: u16) -> {capnp}::introspect::Type {where_clause} {{"
+        )),
+        indent(match_body(superclass_branches, "superclass")),
+        Line("}".into()),
+    ]))
+}
+
 fn annotation_branch(
     ctx: &GeneratorContext,
     annotation: schema_capnp::annotation::Reader,
@@ -1767,6 +1900,18 @@ fn generate_get_annotation_types(
                 }
             }
         }
+        node::Interface(i) => {
+            for (midx, method) in i.get_methods()?.iter().enumerate() {
+                for (idx, annotation) in method.get_annotations()?.iter().enumerate() {
+                    branches.push(annotation_branch(
+                        ctx,
+                        annotation,
+                        Some(midx as u16),
+                        idx as u32,
+                    )?);
+                }
+            }
+        }
         _ => (),
     }
 
@@ -2124,6 +2269,9 @@ fn generate_node(
                         Line(fmt!(ctx,"fn init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> Self {{")),
                         indent(Line(fmt!(ctx,"builder.init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE).into()"))),
                         line("}"),
+                        Line(fmt!(ctx,"fn try_init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> {capnp}::Result<Self> {{")),
+                        indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.try_init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE)?.into())"))),
+                        line("}"),
                         Line(fmt!(ctx,"fn get_from_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
                         indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.get_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE, default)?.into())"))),
                         line("}")
@@ -2137,7 +2285,7 @@ fn generate_node(
                     Branch(vec![
                         Line("#[derive(Copy, Clone)]".into()),
                         line("pub struct Owned(());"),
-                        Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }}).into() }} }}")),
+                        Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema::new(&_private::RAW_SCHEMA, _private::get_field_types, _private::get_annotation_types)).into() }} }}")),
                         Line(fmt!(ctx, "impl {capnp}::traits::Owned for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
                         Line(fmt!(ctx,"impl {capnp}::traits::OwnedStruct for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
                         Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Pipeline; }}"))
@@ -2148,7 +2296,7 @@ fn generate_node(
                         Line(format!("pub struct Owned<{}> {{", params.params)),
                             indent(Line(params.phantom_data_type.clone())),
                         line("}"),
-                        Line(fmt!(ctx,"impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}> }}).into() }} }}",
+                        Line(fmt!(ctx,"impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema::new(&_private::RAW_SCHEMA, _private::get_field_types::<{0}>, _private::get_annotation_types::<{0}>)).into() }} }}",
                             params.params, params.where_clause)),
                         Line(fmt!(ctx,"impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Reader<'a, {0}>; type Builder<'a> = Builder<'a, {0}>; }}",
                             params.params, params.where_clause)),
@@ -2199,7 +2347,7 @@ fn generate_node(
                             params.params, params.where_clause)),
                 indent(vec![
                     Line(format!("fn from(reader: Reader<'a,{0}>) -> Self {{", params.params)),
-                    indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Reader::new(reader.reader, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}>}})))", params.params))),
+                    indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Reader::new(reader.reader, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema::new(&_private::RAW_SCHEMA, _private::get_field_types::<{0}>, _private::get_annotation_types::<{0}>))))", params.params))),
                     line("}")
                 ]),
                 line("}"),
@@ -2289,7 +2437,7 @@ fn generate_node(
                             params.params, params.where_clause)),
                 indent(vec![
                         Line(format!("fn from(builder: Builder<'a,{0}>) -> Self {{", params.params)),
-                        indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Builder::new(builder.builder, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}>}})))", params.params))),
+                        indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Builder::new(builder.builder, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema::new(&_private::RAW_SCHEMA, _private::get_field_types::<{0}>, _private::get_annotation_types::<{0}>))))", params.params))),
                         line("}")
                 ]),
                 line("}"),
@@ -2404,14 +2552,14 @@ fn generate_node(
                     "impl {capnp}::introspect::Introspect for {last_name} {{"
                 )),
                 indent(Line(fmt!(ctx,
-                    "fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Enum({capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}).into() }}", name_as_mod))),
+                    "fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Enum({capnp}::introspect::RawEnumSchema::new(&{0}::ENCODED_NODE, {0}::get_annotation_types)).into() }}", name_as_mod))),
                 Line("}".into()),
             ]));
 
             output.push(Branch(vec![
                 Line(fmt!(ctx,"impl <'a> ::core::convert::From<{last_name}> for {capnp}::dynamic_value::Reader<'a> {{")),
                 indent(Line(fmt!(ctx,
-                    "fn from(e: {last_name}) -> Self {{ {capnp}::dynamic_value::Enum::new(e.into(), {capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}.into()).into() }}", name_as_mod ))),
+                    "fn from(e: {last_name}) -> Self {{ {capnp}::dynamic_value::Enum::new(e.into(), {capnp}::introspect::RawEnumSchema::new(&{0}::ENCODED_NODE, {0}::get_annotation_types).into()).into() }}", name_as_mod ))),
                 Line("}".into())
             ]));
 
@@ -2481,6 +2629,7 @@ fn generate_node(
             let mut mod_interior = Vec::new();
             let mut dispatch_arms = Vec::new();
             let mut private_mod_interior = Vec::new();
+            let mut method_type_branches = Vec::new();
 
             let bracketed_params = if params.params.is_empty() {
                 "".to_string()
@@ -2543,6 +2692,11 @@ fn generate_node(
                     &result_scopes.join("::"),
                 )?;
 
+                method_type_branches.push(Line(fmt!(
+                    ctx,
+                    "{ordinal} => (<{param_type} as {capnp}::introspect::Introspect>::introspect(), <{result_type} as {capnp}::introspect::Introspect>::introspect()),"
+                )));
+
                 dispatch_arms.push(
                     Line(fmt!(ctx,
                         "{ordinal} => server.{}({capnp}::private::capability::internal_get_typed_params(params), {capnp}::private::capability::internal_get_typed_results(results)),",
@@ -2586,6 +2740,32 @@ fn generate_node(
                 method.get_annotations()?;
             }
 
+            let mut superclass_type_branches = Vec::new();
+            for (index, superclass) in interface.get_superclasses()?.iter().enumerate() {
+                let type_id = superclass.get_id();
+                let the_mod = ctx.get_qualified_module(type_id);
+                let superclass_type =
+                    do_branding(ctx, type_id, superclass.get_brand()?, Leaf::Owned, &the_mod)?;
+                superclass_type_branches.push(Line(fmt!(
+                    ctx,
+                    "{index} => <{superclass_type} as {capnp}::introspect::Introspect>::introspect(),"
+                )));
+            }
+
+            private_mod_interior.push(crate::pointer_constants::node_word_array_declaration(
+                ctx,
+                "ENCODED_NODE",
+                *node_reader,
+                crate::pointer_constants::WordArrayDeclarationOptions { public: true },
+            )?);
+            private_mod_interior.push(generate_get_interface_types(
+                ctx,
+                *node_reader,
+                method_type_branches,
+                superclass_type_branches,
+            )?);
+            private_mod_interior.push(generate_get_annotation_types(ctx, *node_reader)?);
+
             let mut base_dispatch_arms = Vec::new();
 
             let server_base = {
@@ -2658,7 +2838,8 @@ fn generate_node(
                 Branch(vec![
                     Line("#[derive(Copy, Clone)]".into()),
                     line("pub struct Owned(());"),
-                    Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability.into() }} }}")),
+                    Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawInterfaceSchema::new(&_private::ENCODED_NODE, _private::get_method_types, _private::get_superclass_types, _private::get_annotation_types)).into() }} }}")),
+                    Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Client {{ fn introspect() -> {capnp}::introspect::Type {{ <Owned as {capnp}::introspect::Introspect>::introspect() }} }}")),
                     line("impl ::capnp::traits::Owned for Owned { type Reader<'a> = Client; type Builder<'a> = Client; }"),
                     Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Client; }}"))])
             } else {
@@ -2668,7 +2849,10 @@ fn generate_node(
                     indent(Line(params.phantom_data_type.clone())),
                     line("}"),
                     Line(fmt!(ctx,
-                              "impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability.into() }} }}",
+                              "impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawInterfaceSchema::new(&_private::ENCODED_NODE, _private::get_method_types::<{0}>, _private::get_superclass_types::<{0}>, _private::get_annotation_types::<{0}>)).into() }} }}",
+                              params.params, params.where_clause)),
+                    Line(fmt!(ctx,
+                              "impl <{0}> {capnp}::introspect::Introspect for Client <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ <Owned<{0}> as {capnp}::introspect::Introspect>::introspect() }} }}",
                               params.params, params.where_clause)),
                     Line(fmt!(ctx,
                         "impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Client<{0}>; type Builder<'a> = Client<{0}>; }}",
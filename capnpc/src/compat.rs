@@ -0,0 +1,564 @@
+//! Checks whether a new version of a schema is wire-compatible with an old one.
+//!
+//! Nodes are matched between the two versions by ID. Fields and methods are matched by name,
+//! falling back to their ordinal so that renaming them is not reported. Nodes that only exist in
+//! one of the versions are not compared.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use capnp::schema_capnp::{code_generator_request, field, method, node, type_, value};
+
+/// The kind of an [`Incompatibility`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IncompatibilityKind {
+    /// A node changed between being a struct, enum, interface, const or annotation.
+    NodeKindChanged,
+
+    /// A field is gone from its struct.
+    FieldRemoved,
+
+    /// A field has a different ordinal.
+    FieldOrdinalChanged,
+
+    /// A field has a different type.
+    FieldTypeChanged,
+
+    /// A field has a different default value.
+    FieldDefaultChanged,
+
+    /// A field was moved into or out of a group, or changed between being a group and not.
+    FieldGroupChanged,
+
+    /// A union member has a different discriminant value, or a union's discriminant moved.
+    UnionDiscriminantChanged,
+
+    /// An enum lost one of its enumerants.
+    EnumerantRemoved,
+
+    /// A method is gone from its interface.
+    MethodRemoved,
+
+    /// A method has a different ordinal.
+    MethodOrdinalChanged,
+
+    /// A method has a different parameter or result type.
+    MethodSignatureChanged,
+}
+
+impl IncompatibilityKind {
+    /// Returns a stable, kebab-case name for the kind, such as `field-type-changed`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NodeKindChanged => "node-kind-changed",
+            Self::FieldRemoved => "field-removed",
+            Self::FieldOrdinalChanged => "field-ordinal-changed",
+            Self::FieldTypeChanged => "field-type-changed",
+            Self::FieldDefaultChanged => "field-default-changed",
+            Self::FieldGroupChanged => "field-group-changed",
+            Self::UnionDiscriminantChanged => "union-discriminant-changed",
+            Self::EnumerantRemoved => "enumerant-removed",
+            Self::MethodRemoved => "method-removed",
+            Self::MethodOrdinalChanged => "method-ordinal-changed",
+            Self::MethodSignatureChanged => "method-signature-changed",
+        }
+    }
+}
+
+impl fmt::Display for IncompatibilityKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// A change that breaks wire compatibility between two versions of a schema.
+///
+/// Displays as a single tab-separated line of kind, node ID, path and detail, so that the
+/// output of a check can be consumed by other tools.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Incompatibility {
+    pub kind: IncompatibilityKind,
+
+    /// ID of the node in which the change was found.
+    pub node_id: u64,
+
+    /// Display name of the node, followed by the name of the member that changed, if any.
+    /// For example, `foo.capnp:Person.age`.
+    pub path: String,
+
+    /// Human-readable description of the change.
+    pub detail: String,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "{}\t@0x{:016x}\t{}\t{}",
+            self.kind, self.node_id, self.path, self.detail
+        )
+    }
+}
+
+/// Reports the changes in `new` that break wire compatibility with `old`.
+pub fn check(
+    old: code_generator_request::Reader,
+    new: code_generator_request::Reader,
+) -> capnp::Result<Vec<Incompatibility>> {
+    let mut checker = Checker {
+        old: node_map(old)?,
+        new: node_map(new)?,
+        result: Vec::new(),
+    };
+    for old_node in old.get_nodes()? {
+        if let Some(&new_node) = checker.new.get(&old_node.get_id()) {
+            checker.check_node(old_node, new_node)?;
+        }
+    }
+    Ok(checker.result)
+}
+
+fn node_map(request: code_generator_request::Reader) -> capnp::Result<HashMap<u64, node::Reader>> {
+    let mut map = HashMap::new();
+    for node in request.get_nodes()? {
+        map.insert(node.get_id(), node);
+    }
+    Ok(map)
+}
+
+struct Checker<'a> {
+    old: HashMap<u64, node::Reader<'a>>,
+    new: HashMap<u64, node::Reader<'a>>,
+    result: Vec<Incompatibility>,
+}
+
+impl<'a> Checker<'a> {
+    fn report(
+        &mut self,
+        kind: IncompatibilityKind,
+        node: node::Reader,
+        member: Option<&str>,
+        detail: String,
+    ) -> capnp::Result<()> {
+        let mut path = node.get_display_name()?.to_string()?;
+        if let Some(member) = member {
+            path.push('.');
+            path.push_str(member);
+        }
+        self.result.push(Incompatibility {
+            kind,
+            node_id: node.get_id(),
+            path,
+            detail,
+        });
+        Ok(())
+    }
+
+    fn check_node(&mut self, old: node::Reader<'a>, new: node::Reader<'a>) -> capnp::Result<()> {
+        match (old.which()?, new.which()?) {
+            (node::Struct(old_struct), node::Struct(new_struct)) => {
+                if old_struct.get_discriminant_count() > 0
+                    && new_struct.get_discriminant_count() > 0
+                    && old_struct.get_discriminant_offset() != new_struct.get_discriminant_offset()
+                {
+                    self.report(
+                        IncompatibilityKind::UnionDiscriminantChanged,
+                        new,
+                        None,
+                        format!(
+                            "discriminant offset changed from {} to {}",
+                            old_struct.get_discriminant_offset(),
+                            new_struct.get_discriminant_offset()
+                        ),
+                    )?;
+                }
+                let new_fields = new_struct.get_fields()?;
+                for old_field in old_struct.get_fields()? {
+                    match self.find_field(old_field, new_fields)? {
+                        Some(new_field) => self.check_field(new, old_field, new_field)?,
+                        None => {
+                            let name = old_field.get_name()?.to_str()?;
+                            if self.find_field_in_groups(old_field, new_fields)? {
+                                self.report(
+                                    IncompatibilityKind::FieldGroupChanged,
+                                    new,
+                                    Some(name),
+                                    "field was moved into a group".into(),
+                                )?;
+                            } else {
+                                self.report(
+                                    IncompatibilityKind::FieldRemoved,
+                                    new,
+                                    Some(name),
+                                    "field was removed".into(),
+                                )?;
+                            }
+                        }
+                    }
+                }
+            }
+            (node::Enum(old_enum), node::Enum(new_enum)) => {
+                let new_len = new_enum.get_enumerants()?.len();
+                for (ordinal, enumerant) in old_enum.get_enumerants()?.iter().enumerate() {
+                    if ordinal as u32 >= new_len {
+                        self.report(
+                            IncompatibilityKind::EnumerantRemoved,
+                            new,
+                            Some(enumerant.get_name()?.to_str()?),
+                            format!("enumerant @{ordinal} was removed"),
+                        )?;
+                    }
+                }
+            }
+            (node::Interface(old_interface), node::Interface(new_interface)) => {
+                let new_methods = new_interface.get_methods()?;
+                for (old_ordinal, old_method) in old_interface.get_methods()?.iter().enumerate() {
+                    let name = old_method.get_name()?.to_str()?;
+                    let Some((new_ordinal, new_method)) =
+                        self.find_method(old_ordinal, old_method, new_methods)?
+                    else {
+                        self.report(
+                            IncompatibilityKind::MethodRemoved,
+                            new,
+                            Some(name),
+                            format!("method @{old_ordinal} was removed"),
+                        )?;
+                        continue;
+                    };
+                    let name = new_method.get_name()?.to_str()?;
+                    if old_ordinal != new_ordinal {
+                        self.report(
+                            IncompatibilityKind::MethodOrdinalChanged,
+                            new,
+                            Some(name),
+                            format!("ordinal changed from @{old_ordinal} to @{new_ordinal}"),
+                        )?;
+                    }
+                    for (which, old_id, new_id) in [
+                        (
+                            "parameter",
+                            old_method.get_param_struct_type(),
+                            new_method.get_param_struct_type(),
+                        ),
+                        (
+                            "result",
+                            old_method.get_result_struct_type(),
+                            new_method.get_result_struct_type(),
+                        ),
+                    ] {
+                        if old_id != new_id {
+                            let detail = format!(
+                                "{which} type changed from {} to {}",
+                                self.type_id_name(&self.old, old_id)?,
+                                self.type_id_name(&self.new, new_id)?
+                            );
+                            self.report(
+                                IncompatibilityKind::MethodSignatureChanged,
+                                new,
+                                Some(name),
+                                detail,
+                            )?;
+                        }
+                    }
+                }
+            }
+            (node::File(()), node::File(()))
+            | (node::Const(_), node::Const(_))
+            | (node::Annotation(_), node::Annotation(_)) => {}
+            (old_which, new_which) => {
+                self.report(
+                    IncompatibilityKind::NodeKindChanged,
+                    new,
+                    None,
+                    format!(
+                        "changed from {} to {}",
+                        node_kind_name(&old_which),
+                        node_kind_name(&new_which)
+                    ),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the field of `new_fields` that corresponds to `old_field`, matching by name first
+    /// and then by ordinal.
+    fn find_field(
+        &self,
+        old_field: field::Reader<'a>,
+        new_fields: capnp::struct_list::Reader<'a, field::Owned>,
+    ) -> capnp::Result<Option<field::Reader<'a>>> {
+        let name = old_field.get_name()?.to_str()?;
+        for new_field in new_fields {
+            if new_field.get_name()?.to_str()? == name {
+                return Ok(Some(new_field));
+            }
+        }
+        if let Some(ordinal) = explicit_ordinal(old_field)? {
+            for new_field in new_fields {
+                if explicit_ordinal(new_field)? == Some(ordinal) {
+                    return Ok(Some(new_field));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Finds the method of `new_methods` that corresponds to `old_method`, which has ordinal
+    /// `old_ordinal`, matching by name first and then by ordinal. Returns the method together
+    /// with its ordinal.
+    fn find_method(
+        &self,
+        old_ordinal: usize,
+        old_method: method::Reader<'a>,
+        new_methods: capnp::struct_list::Reader<'a, method::Owned>,
+    ) -> capnp::Result<Option<(usize, method::Reader<'a>)>> {
+        let name = old_method.get_name()?.to_str()?;
+        for (new_ordinal, new_method) in new_methods.iter().enumerate() {
+            if new_method.get_name()?.to_str()? == name {
+                return Ok(Some((new_ordinal, new_method)));
+            }
+        }
+        if old_ordinal < new_methods.len() as usize {
+            return Ok(Some((old_ordinal, new_methods.get(old_ordinal as u32))));
+        }
+        Ok(None)
+    }
+
+    /// Returns whether a field corresponding to `old_field` exists in one of the groups of
+    /// `new_fields`, at any depth.
+    fn find_field_in_groups(
+        &self,
+        old_field: field::Reader<'a>,
+        new_fields: capnp::struct_list::Reader<'a, field::Owned>,
+    ) -> capnp::Result<bool> {
+        for new_field in new_fields {
+            if let field::Group(group) = new_field.which()? {
+                let Some(group_node) = self.new.get(&group.get_type_id()) else {
+                    continue;
+                };
+                let node::Struct(group_struct) = group_node.which()? else {
+                    continue;
+                };
+                let group_fields = group_struct.get_fields()?;
+                if self.find_field(old_field, group_fields)?.is_some()
+                    || self.find_field_in_groups(old_field, group_fields)?
+                {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn check_field(
+        &mut self,
+        new_node: node::Reader<'a>,
+        old_field: field::Reader<'a>,
+        new_field: field::Reader<'a>,
+    ) -> capnp::Result<()> {
+        let name = new_field.get_name()?.to_str()?;
+
+        let old_ordinal = explicit_ordinal(old_field)?;
+        let new_ordinal = explicit_ordinal(new_field)?;
+        if let (Some(old_ordinal), Some(new_ordinal)) = (old_ordinal, new_ordinal) {
+            if old_ordinal != new_ordinal {
+                self.report(
+                    IncompatibilityKind::FieldOrdinalChanged,
+                    new_node,
+                    Some(name),
+                    format!("ordinal changed from @{old_ordinal} to @{new_ordinal}"),
+                )?;
+            }
+        }
+
+        if old_field.get_discriminant_value() != new_field.get_discriminant_value() {
+            self.report(
+                IncompatibilityKind::UnionDiscriminantChanged,
+                new_node,
+                Some(name),
+                format!(
+                    "discriminant changed from {} to {}",
+                    discriminant_name(old_field.get_discriminant_value()),
+                    discriminant_name(new_field.get_discriminant_value())
+                ),
+            )?;
+        }
+
+        match (old_field.which()?, new_field.which()?) {
+            (field::Slot(old_slot), field::Slot(new_slot)) => {
+                let old_type = old_slot.get_type()?;
+                let new_type = new_slot.get_type()?;
+                if !types_equal(old_type, new_type)? {
+                    let detail = format!(
+                        "type changed from {} to {}",
+                        self.type_name(&self.old, old_type)?,
+                        self.type_name(&self.new, new_type)?
+                    );
+                    self.report(
+                        IncompatibilityKind::FieldTypeChanged,
+                        new_node,
+                        Some(name),
+                        detail,
+                    )?;
+                } else if !values_equal(
+                    old_slot.get_default_value()?,
+                    new_slot.get_default_value()?,
+                )? {
+                    self.report(
+                        IncompatibilityKind::FieldDefaultChanged,
+                        new_node,
+                        Some(name),
+                        "default value changed".into(),
+                    )?;
+                }
+            }
+            (field::Group(_), field::Group(_)) => {}
+            (field::Slot(_), field::Group(_)) => {
+                self.report(
+                    IncompatibilityKind::FieldGroupChanged,
+                    new_node,
+                    Some(name),
+                    "field became a group".into(),
+                )?;
+            }
+            (field::Group(_), field::Slot(_)) => {
+                self.report(
+                    IncompatibilityKind::FieldGroupChanged,
+                    new_node,
+                    Some(name),
+                    "group became a field".into(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn type_name(
+        &self,
+        nodes: &HashMap<u64, node::Reader<'a>>,
+        ty: type_::Reader,
+    ) -> capnp::Result<String> {
+        Ok(match ty.which()? {
+            type_::Void(()) => "Void".into(),
+            type_::Bool(()) => "Bool".into(),
+            type_::Int8(()) => "Int8".into(),
+            type_::Int16(()) => "Int16".into(),
+            type_::Int32(()) => "Int32".into(),
+            type_::Int64(()) => "Int64".into(),
+            type_::Uint8(()) => "UInt8".into(),
+            type_::Uint16(()) => "UInt16".into(),
+            type_::Uint32(()) => "UInt32".into(),
+            type_::Uint64(()) => "UInt64".into(),
+            type_::Float32(()) => "Float32".into(),
+            type_::Float64(()) => "Float64".into(),
+            type_::Text(()) => "Text".into(),
+            type_::Data(()) => "Data".into(),
+            type_::List(list) => {
+                format!("List({})", self.type_name(nodes, list.get_element_type()?)?)
+            }
+            type_::Enum(e) => self.type_id_name(nodes, e.get_type_id())?,
+            type_::Struct(s) => self.type_id_name(nodes, s.get_type_id())?,
+            type_::Interface(i) => self.type_id_name(nodes, i.get_type_id())?,
+            type_::AnyPointer(_) => "AnyPointer".into(),
+        })
+    }
+
+    fn type_id_name(
+        &self,
+        nodes: &HashMap<u64, node::Reader<'a>>,
+        id: u64,
+    ) -> capnp::Result<String> {
+        match nodes.get(&id) {
+            Some(node) => Ok(node.get_display_name()?.to_string()?),
+            None => Ok(format!("@0x{id:016x}")),
+        }
+    }
+}
+
+fn explicit_ordinal(field: field::Reader) -> capnp::Result<Option<u16>> {
+    match field.get_ordinal().which()? {
+        field::ordinal::Explicit(ordinal) => Ok(Some(ordinal)),
+        field::ordinal::Implicit(()) => Ok(None),
+    }
+}
+
+fn discriminant_name(value: u16) -> String {
+    if value == field::NO_DISCRIMINANT {
+        "none".into()
+    } else {
+        value.to_string()
+    }
+}
+
+fn node_kind_name(which: &node::WhichReader) -> &'static str {
+    match which {
+        node::File(()) => "file",
+        node::Struct(_) => "struct",
+        node::Enum(_) => "enum",
+        node::Interface(_) => "interface",
+        node::Const(_) => "const",
+        node::Annotation(_) => "annotation",
+    }
+}
+
+/// Compares two types structurally, ignoring brands.
+fn types_equal(a: type_::Reader, b: type_::Reader) -> capnp::Result<bool> {
+    Ok(match (a.which()?, b.which()?) {
+        (type_::Void(()), type_::Void(()))
+        | (type_::Bool(()), type_::Bool(()))
+        | (type_::Int8(()), type_::Int8(()))
+        | (type_::Int16(()), type_::Int16(()))
+        | (type_::Int32(()), type_::Int32(()))
+        | (type_::Int64(()), type_::Int64(()))
+        | (type_::Uint8(()), type_::Uint8(()))
+        | (type_::Uint16(()), type_::Uint16(()))
+        | (type_::Uint32(()), type_::Uint32(()))
+        | (type_::Uint64(()), type_::Uint64(()))
+        | (type_::Float32(()), type_::Float32(()))
+        | (type_::Float64(()), type_::Float64(()))
+        | (type_::Text(()), type_::Text(()))
+        | (type_::Data(()), type_::Data(()))
+        | (type_::AnyPointer(_), type_::AnyPointer(_)) => true,
+        (type_::List(a), type_::List(b)) => {
+            types_equal(a.get_element_type()?, b.get_element_type()?)?
+        }
+        (type_::Enum(a), type_::Enum(b)) => a.get_type_id() == b.get_type_id(),
+        (type_::Struct(a), type_::Struct(b)) => a.get_type_id() == b.get_type_id(),
+        (type_::Interface(a), type_::Interface(b)) => a.get_type_id() == b.get_type_id(),
+        _ => false,
+    })
+}
+
+fn values_equal(a: value::Reader, b: value::Reader) -> capnp::Result<bool> {
+    Ok(match (a.which()?, b.which()?) {
+        (value::Void(()), value::Void(())) => true,
+        (value::Bool(a), value::Bool(b)) => a == b,
+        (value::Int8(a), value::Int8(b)) => a == b,
+        (value::Int16(a), value::Int16(b)) => a == b,
+        (value::Int32(a), value::Int32(b)) => a == b,
+        (value::Int64(a), value::Int64(b)) => a == b,
+        (value::Uint8(a), value::Uint8(b)) => a == b,
+        (value::Uint16(a), value::Uint16(b)) => a == b,
+        (value::Uint32(a), value::Uint32(b)) => a == b,
+        (value::Uint64(a), value::Uint64(b)) => a == b,
+        (value::Float32(a), value::Float32(b)) => a.to_bits() == b.to_bits(),
+        (value::Float64(a), value::Float64(b)) => a.to_bits() == b.to_bits(),
+        (value::Enum(a), value::Enum(b)) => a == b,
+        (value::Text(a), value::Text(b)) => a? == b?,
+        (value::Data(a), value::Data(b)) => a? == b?,
+        (value::List(a), value::List(b))
+        | (value::Struct(a), value::Struct(b))
+        | (value::AnyPointer(a), value::AnyPointer(b)) => {
+            canonical_bytes(a)? == canonical_bytes(b)?
+        }
+        (value::Interface(()), value::Interface(())) => true,
+        _ => false,
+    })
+}
+
+fn canonical_bytes(pointer: capnp::any_pointer::Reader) -> capnp::Result<Vec<u8>> {
+    let mut message = capnp::message::Builder::new_default();
+    message.set_root_canonical(pointer)?;
+    Ok(message.get_segments_for_output().concat())
+}
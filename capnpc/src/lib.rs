@@ -69,6 +69,7 @@
 
 pub mod codegen;
 pub mod codegen_types;
+pub mod compat;
 mod pointer_constants;
 
 use std::{
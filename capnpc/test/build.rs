@@ -7,12 +7,15 @@ fn main() {
     capnpc::CompilerCommand::new()
         .capnp_executable(&cmdpath)
         .crate_provides("external_crate", [0xe6f94f52f7be8fe2])
+        .crate_provides("capnp", [0x8ef99297a43a5e34])
         .file("test.capnp")
+        .file("test-json.capnp")
         .file("in-submodule.capnp")
         .file("in-other-submodule.capnp")
         .file("schema/test-in-dir.capnp")
         .file("schema-with-src-prefix/test-in-src-prefix-dir.capnp")
         .import_path("..")
+        .import_path("../../capnp-import/capnproto/c++/src")
         .src_prefix("schema-with-src-prefix")
         .raw_code_generator_request_path(
             std::env::var("OUT_DIR").expect("OUT_DIR env var is not set")
@@ -46,6 +49,19 @@ fn main() {
         .run()
         .expect("compiling schema");
 
+    // Two versions of the same schema, to test `capnpc::compat`.
+    for version in ["v1", "v2"] {
+        let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR env var is not set");
+        capnpc::CompilerCommand::new()
+            .capnp_executable(&cmdpath)
+            .file(format!("evolution/{version}/evolution.capnp"))
+            .src_prefix(format!("evolution/{version}"))
+            .output_path(format!("{out_dir}/evolution-{version}"))
+            .raw_code_generator_request_path(format!("{out_dir}/evolution-{version}.bin"))
+            .run()
+            .expect("compiling schema");
+    }
+
     // Have to do this test last
     std::env::remove_var("OUT_DIR");
     let error = capnpc::CompilerCommand::new()
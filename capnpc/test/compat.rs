@@ -0,0 +1,72 @@
+use capnp::message::{self, ReaderOptions};
+use capnp::serialize::{self, OwnedSegments};
+use capnpc::compat::{self, IncompatibilityKind};
+
+fn read_request(bytes: &[u8]) -> message::Reader<OwnedSegments> {
+    serialize::read_message(bytes, ReaderOptions::new()).unwrap()
+}
+
+fn v1() -> message::Reader<OwnedSegments> {
+    read_request(include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/evolution-v1.bin"
+    )))
+}
+
+fn v2() -> message::Reader<OwnedSegments> {
+    read_request(include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/evolution-v2.bin"
+    )))
+}
+
+#[test]
+fn unchanged_schema() {
+    let old = v1();
+    let new = v1();
+    let incompatibilities =
+        compat::check(old.get_root().unwrap(), new.get_root().unwrap()).unwrap();
+    assert!(incompatibilities.is_empty());
+}
+
+#[test]
+fn evolved_schema() {
+    let old = v1();
+    let new = v2();
+    let incompatibilities =
+        compat::check(old.get_root().unwrap(), new.get_root().unwrap()).unwrap();
+    let found: Vec<(IncompatibilityKind, &str)> = incompatibilities
+        .iter()
+        .map(|i| (i.kind, &i.path[..]))
+        .collect();
+
+    use IncompatibilityKind::*;
+    assert_eq!(
+        found,
+        [
+            (EnumerantRemoved, "evolution.capnp:Color.blue"),
+            // Widening `age` moved the union's discriminant.
+            (UnionDiscriminantChanged, "evolution.capnp:Person"),
+            (FieldTypeChanged, "evolution.capnp:Person.age"),
+            (FieldDefaultChanged, "evolution.capnp:Person.nickname"),
+            (FieldOrdinalChanged, "evolution.capnp:Person.phone"),
+            (UnionDiscriminantChanged, "evolution.capnp:Person.phone"),
+            (FieldOrdinalChanged, "evolution.capnp:Person.fax"),
+            (UnionDiscriminantChanged, "evolution.capnp:Person.fax"),
+            (FieldGroupChanged, "evolution.capnp:Person.address"),
+            (MethodSignatureChanged, "evolution.capnp:Directory.remove"),
+        ]
+    );
+
+    // Renaming `email` and `list` and adding new fields, enumerants and methods are all
+    // compatible.
+    assert!(!incompatibilities
+        .iter()
+        .any(|i| i.path.contains("email") || i.path.contains("lookup") || i.path.contains("list")));
+
+    assert_eq!(
+        incompatibilities[2].to_string(),
+        "field-type-changed\t@0xbbd4bdd6cc9c9653\tevolution.capnp:Person.age\t\
+         type changed from UInt16 to UInt32"
+    );
+}
@@ -246,3 +246,964 @@ fn test_stringify() {
     let stringified = format!("{:?}", root.into_reader());
     assert_eq!(stringified, "(voidField = (), boolField = false, int8Field = 3, int16Field = 0, int32Field = 0, int64Field = 0, uInt8Field = 0, uInt16Field = 0, uInt32Field = 0, uInt64Field = 0, float32Field = 0, float64Field = 0, textField = \"hello world\", dataField = 0x\"01020304057fff\", structField = (voidField = (), boolField = false, int8Field = 0, int16Field = 0, int32Field = 0, int64Field = 0, uInt8Field = 0, uInt16Field = 0, uInt32Field = 123456, uInt64Field = 0, float32Field = 0, float64Field = 0, enumField = foo), enumField = bar, boolList = [false, true])");
 }
+
+#[test]
+fn test_stringify_printer() {
+    use crate::test_capnp::{test_all_types, test_defaults, test_sensitive};
+    use capnp::stringify::{DataFormat, Printer};
+
+    let mut message = message::Builder::new_default();
+    let mut root: test_all_types::Builder<'_> = message.init_root();
+    root.set_int8_field(3);
+    root.set_data_field(&[1, 2, 3, 4, 5]);
+    root.reborrow()
+        .init_struct_field()
+        .set_u_int32_field(123456);
+    let mut int32_list = root.reborrow().init_int32_list(5);
+    for i in 0..5 {
+        int32_list.set(i, i as i32 * 10);
+    }
+    let root = root.into_reader();
+
+    let mut printer = Printer::new();
+    assert_eq!(printer.display(root).to_string(), format!("{root:?}"));
+    printer.pretty(true);
+    assert_eq!(printer.display(root).to_string(), format!("{root:#?}"));
+
+    let mut printer = Printer::new();
+    printer.hide_default_fields(true);
+    assert_eq!(
+        printer.display(root).to_string(),
+        "(int8Field = 3, dataField = 0x\"0102030405\", structField = (uInt32Field = 123456), int32List = [0, 10, 20, 30, 40])"
+    );
+    printer
+        .max_list_len(2)
+        .max_data_len(3)
+        .data_format(DataFormat::Decimal);
+    assert_eq!(
+        printer.display(root).to_string(),
+        "(int8Field = 3, dataField = [1, 2, 3, ... 2 more], structField = (uInt32Field = 123456), int32List = [0, 10, ... 3 more])"
+    );
+    printer.max_depth(1);
+    assert_eq!(
+        printer.display(root).to_string(),
+        "(int8Field = 3, dataField = [1, 2, 3, ... 2 more], structField = (...), int32List = [...])"
+    );
+    printer.max_depth(0);
+    assert_eq!(printer.display(root).to_string(), "(...)");
+
+    let mut printer = Printer::new();
+    printer.pretty(true).max_list_len(1).max_data_len(2);
+    assert_eq!(
+        printer.display(root.get_int32_list().unwrap()).to_string(),
+        "[\n  0,\n  ... 4 more\n]"
+    );
+    assert_eq!(
+        printer.display(root.get_data_field().unwrap()).to_string(),
+        "0x\"0102\" ... 3 more"
+    );
+
+    let mut message = message::Builder::new_default();
+    let mut defaults: test_defaults::Builder<'_> = message.init_root();
+    let mut printer = Printer::new();
+    printer.hide_default_fields(true);
+    assert_eq!(
+        printer
+            .display(defaults.reborrow().into_reader())
+            .to_string(),
+        "()"
+    );
+    defaults.set_bool_field(false);
+    defaults.set_int8_field(-123);
+    assert_eq!(
+        printer.display(defaults.into_reader()).to_string(),
+        "(boolField = false)"
+    );
+
+    let mut message = message::Builder::new_default();
+    let mut sensitive: test_sensitive::Builder<'_> = message.init_root();
+    sensitive.set_user("alice".into());
+    sensitive.set_password("hunter2".into());
+    let mut printer = Printer::new();
+    printer.redact_annotation(0xca382cdda0e7d20d);
+    assert_eq!(
+        printer.display(sensitive.into_reader()).to_string(),
+        "(user = \"alice\", password = <redacted>, attempts = 0)"
+    );
+}
+
+#[test]
+fn test_parse_text() {
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let mut root: dynamic_value::Builder<'_> = root.into();
+    test_util::dynamic_init_test_message(root.reborrow().downcast());
+    let root = root.into_reader();
+
+    for text in [format!("{root:?}"), format!("{root:#?}")] {
+        let mut message2 = message::Builder::new_default();
+        let root2: test_all_types::Builder<'_> = message2.init_root();
+        let root2: dynamic_value::Builder<'_> = root2.into();
+        capnp::text_format::parse(&text, root2.downcast()).unwrap();
+        let root2 = message2
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        let root2: dynamic_value::Reader<'_> = root2.into();
+        assert_eq!(format!("{root2:?}"), format!("{root:?}"));
+        test_util::dynamic_check_test_message(root2.downcast());
+    }
+}
+
+#[test]
+fn test_parse_text_handwritten() {
+    use crate::test_capnp::{test_union, TestEnum};
+
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let root: dynamic_value::Builder<'_> = root.into();
+    let input = r#"
+        # Outer parentheses are optional.
+        int8Field = -0x80,
+        uInt64Field = 18446744073709551615,
+        float32Field = -inf,
+        float64Field = 1.5e-3,
+        textField = "tab\tquote\" \u{1f980}",
+        dataField = 0x"de ad be ef",
+        enumField = qux,
+        structField = (textField = "inner", enumList = [bar, 3]),
+        structList = [(int32Field = 1), ()],
+        textList = [],
+        int16List = [1, -2, 3,],
+    "#;
+    capnp::text_format::parse(input, root.downcast()).unwrap();
+
+    let root = message
+        .get_root_as_reader::<test_all_types::Reader<'_>>()
+        .unwrap();
+    assert_eq!(root.get_int8_field(), -128);
+    assert_eq!(root.get_u_int64_field(), u64::MAX);
+    assert_eq!(root.get_float32_field(), f32::NEG_INFINITY);
+    assert_eq!(root.get_float64_field(), 1.5e-3);
+    assert_eq!(root.get_text_field().unwrap(), "tab\tquote\" \u{1f980}");
+    assert_eq!(root.get_data_field().unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(root.get_enum_field().unwrap(), TestEnum::Qux);
+    let inner = root.get_struct_field().unwrap();
+    assert_eq!(inner.get_text_field().unwrap(), "inner");
+    let enums = inner.get_enum_list().unwrap();
+    assert_eq!(enums.get(0).unwrap(), TestEnum::Bar);
+    assert_eq!(enums.get(1).unwrap(), TestEnum::Qux);
+    let structs = root.get_struct_list().unwrap();
+    assert_eq!(structs.len(), 2);
+    assert_eq!(structs.get(0).get_int32_field(), 1);
+    assert!(root.has_text_list());
+    assert_eq!(root.get_text_list().unwrap().len(), 0);
+    assert_eq!(
+        root.get_int16_list().unwrap().iter().collect::<Vec<_>>(),
+        [1, -2, 3]
+    );
+
+    // Naming a union member sets the discriminant.
+    let mut message = message::Builder::new_default();
+    let root: test_union::Builder<'_> = message.init_root();
+    let root: dynamic_value::Builder<'_> = root.into();
+    capnp::text_format::parse(
+        "(union0 = (u0f1s32 = 1234567), union1 = (u1f1sp = \"foo\"), union3 = (u3f0s1 = true))",
+        root.downcast(),
+    )
+    .unwrap();
+    let root = message
+        .get_root_as_reader::<test_union::Reader<'_>>()
+        .unwrap();
+    assert!(matches!(
+        root.get_union0().which().unwrap(),
+        test_union::union0::U0f1s32(1234567)
+    ));
+    assert!(matches!(
+        root.get_union1().which().unwrap(),
+        test_union::union1::U1f1sp(Ok(t)) if t == "foo"
+    ));
+    assert!(matches!(
+        root.get_union3().which().unwrap(),
+        test_union::union3::U3f0s1(true)
+    ));
+}
+
+#[test]
+fn test_parse_text_merges_nested_structs() {
+    use crate::test_capnp::test_groups;
+
+    let mut message = message::Builder::new_default();
+    let mut root: test_all_types::Builder<'_> = message.init_root();
+    let mut inner = root.reborrow().init_struct_field();
+    inner.set_int32_field(7);
+    inner.set_text_field("kept".into());
+    let root: dynamic_value::Builder<'_> = root.into();
+    capnp::text_format::parse("(structField = (int32Field = 8))", root.downcast()).unwrap();
+
+    let root = message
+        .get_root_as_reader::<test_all_types::Reader<'_>>()
+        .unwrap();
+    let inner = root.get_struct_field().unwrap();
+    assert_eq!(inner.get_int32_field(), 8);
+    assert_eq!(inner.get_text_field().unwrap(), "kept");
+
+    // Groups merge too, unless the input switches to another member of their union.
+    let mut message = message::Builder::new_default();
+    let root: test_groups::Builder<'_> = message.init_root();
+    let mut foo = root.get_groups().init_foo();
+    foo.set_corge(1);
+    foo.set_grault(2);
+    let root: test_groups::Builder<'_> = message.get_root().unwrap();
+    let root: dynamic_value::Builder<'_> = root.into();
+    capnp::text_format::parse("(groups = (foo = (grault = 3)))", root.downcast()).unwrap();
+    let root = message
+        .get_root_as_reader::<test_groups::Reader<'_>>()
+        .unwrap();
+    let test_groups::groups::Foo(foo) = root.get_groups().which().unwrap() else {
+        panic!()
+    };
+    assert_eq!((foo.get_corge(), foo.get_grault()), (1, 3));
+
+    let root: test_groups::Builder<'_> = message.get_root().unwrap();
+    let root: dynamic_value::Builder<'_> = root.into();
+    capnp::text_format::parse("(groups = (bar = (garply = 4)))", root.downcast()).unwrap();
+    let root = message
+        .get_root_as_reader::<test_groups::Reader<'_>>()
+        .unwrap();
+    let test_groups::groups::Bar(bar) = root.get_groups().which().unwrap() else {
+        panic!()
+    };
+    assert_eq!((bar.get_corge(), bar.get_garply()), (0, 4));
+}
+
+#[test]
+fn test_parse_text_errors() {
+    fn parse(input: &str) -> capnp::Error {
+        let mut message = message::Builder::new_default();
+        let root: test_all_types::Builder<'_> = message.init_root();
+        let root: dynamic_value::Builder<'_> = root.into();
+        capnp::text_format::parse(input, root.downcast()).unwrap_err()
+    }
+
+    let e = parse("(int8Field = 1,\n  noSuchField = 2)");
+    assert_eq!(e.kind, capnp::ErrorKind::FieldNotFound);
+    assert_eq!(e.extra, "noSuchField at line 2, column 3");
+
+    let e = parse("(int8Field = 128)");
+    assert_eq!(e.kind, capnp::ErrorKind::TypeMismatch);
+    assert_eq!(e.extra, "expected an Int8 at line 1, column 14");
+
+    let e = parse("(enumField = nope)");
+    assert_eq!(e.kind, capnp::ErrorKind::TypeMismatch);
+
+    let e = parse("(textField = [1])");
+    assert_eq!(e.kind, capnp::ErrorKind::TypeMismatch);
+
+    for input in [
+        "(int8Field = 1",
+        "(int8Field 1)",
+        "(int8Field = 1) trailing",
+        "(textField = \"unterminated)",
+        "(textField = \"\\q\")",
+        "(dataField = 0x\"abc\")",
+        "(structField = ((((",
+    ] {
+        assert_eq!(
+            parse(input).kind,
+            capnp::ErrorKind::InvalidTextFormat,
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_interface_schema_wide_hierarchy() {
+    use crate::test_capnp::{test_diamond0, test_diamond_extra, test_diamond_top};
+    use capnp::introspect::{Introspect, TypeVariant};
+    use capnp::schema::InterfaceSchema;
+    use capnp::traits::HasTypeId;
+
+    let TypeVariant::Capability(raw) =
+        <test_diamond_top::Client as Introspect>::introspect().which()
+    else {
+        panic!()
+    };
+    let schema: InterfaceSchema = raw.into();
+
+    // Reaching `extraMethod` takes hundreds of steps if every path through the diamonds is
+    // followed separately.
+    let extra = schema.get_method_by_name("extraMethod").unwrap();
+    assert_eq!(
+        extra.get_containing_interface().get_proto().get_id(),
+        <test_diamond_extra::Client as HasTypeId>::TYPE_ID
+    );
+    let diamond = schema.get_method_by_name("diamondMethod").unwrap();
+    assert_eq!(
+        diamond.get_containing_interface().get_proto().get_id(),
+        <test_diamond0::Client as HasTypeId>::TYPE_ID
+    );
+    assert!(schema.find_method_by_name("nonexistent").unwrap().is_none());
+}
+
+#[test]
+fn test_interface_schema() {
+    use crate::test_capnp::{empty_interface, test_all_types, test_extends, test_interface};
+    use capnp::introspect::{Introspect, TypeVariant};
+    use capnp::schema::InterfaceSchema;
+    use capnp::traits::HasTypeId;
+
+    let TypeVariant::Capability(raw) = <test_extends::Client as Introspect>::introspect().which()
+    else {
+        panic!()
+    };
+    let schema: InterfaceSchema = raw.into();
+    let methods = schema.get_methods().unwrap();
+    let names: Vec<_> = methods
+        .iter()
+        .map(|m| m.get_proto().get_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["qux", "corge", "grault"]);
+    assert_eq!(methods.get(1).get_ordinal(), 1);
+    assert_eq!(
+        methods
+            .get(2)
+            .get_result_type()
+            .get_proto()
+            .get_display_name()
+            .unwrap(),
+        "test.capnp:TestBigStruct"
+    );
+
+    let superclasses = schema.get_superclasses().unwrap();
+    assert_eq!(superclasses.len(), 1);
+    assert_eq!(
+        superclasses.get(0).get_proto().get_id(),
+        <test_interface::Client as HasTypeId>::TYPE_ID
+    );
+
+    // Methods of superclasses are found too.
+    let foo = schema.get_method_by_name("foo").unwrap();
+    assert_eq!(foo.get_ordinal(), 0);
+    assert_eq!(
+        foo.get_containing_interface().get_proto().get_id(),
+        <test_interface::Client as HasTypeId>::TYPE_ID
+    );
+    let params = foo.get_param_type();
+    assert_eq!(params.get_fields().unwrap().len(), 2);
+    assert!(matches!(
+        params.get_field_by_name("j").unwrap().get_type().which(),
+        TypeVariant::Bool
+    ));
+    assert!(schema.find_method_by_name("nonexistent").unwrap().is_none());
+    assert!(schema.get_method_by_name("nonexistent").is_err());
+
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let root: dynamic_struct::Builder<'_> = dynamic_value::Builder::from(root).downcast();
+    for field_name in ["interfaceField", "interfaceList"] {
+        let mut ty = root
+            .get_schema()
+            .get_field_by_name(field_name)
+            .unwrap()
+            .get_type();
+        if let TypeVariant::List(element_type) = ty.which() {
+            ty = element_type;
+        }
+        let TypeVariant::Capability(raw) = ty.which() else {
+            panic!()
+        };
+        assert_eq!(
+            InterfaceSchema::from(raw).get_proto().get_id(),
+            <empty_interface::Client as HasTypeId>::TYPE_ID
+        );
+    }
+}
+
+#[test]
+fn test_generic_interface_schema() {
+    use crate::test_capnp::{
+        generic_base, generic_extend2, test_implicit_method_params_in_generic,
+    };
+    use capnp::introspect::{Introspect, TypeVariant};
+    use capnp::schema::InterfaceSchema;
+    use capnp::traits::HasTypeId;
+
+    let TypeVariant::Capability(raw) = <generic_extend2::Owned as Introspect>::introspect().which()
+    else {
+        panic!()
+    };
+    let base = InterfaceSchema::from(raw)
+        .get_superclasses()
+        .unwrap()
+        .get(0);
+    assert_eq!(
+        base.get_proto().get_id(),
+        <generic_base::Client<capnp::data::Owned> as HasTypeId>::TYPE_ID
+    );
+
+    let TypeVariant::Capability(raw) = <test_implicit_method_params_in_generic::Client<
+        capnp::text::Owned,
+    > as Introspect>::introspect()
+    .which() else {
+        panic!()
+    };
+    let call4 = InterfaceSchema::from(raw)
+        .get_method_by_name("call4")
+        .unwrap();
+    let foo = call4
+        .get_param_type()
+        .get_field_by_name("foo")
+        .unwrap()
+        .get_type();
+    assert!(matches!(foo.which(), TypeVariant::Text));
+    let bar = call4
+        .get_result_type()
+        .get_field_by_name("bar")
+        .unwrap()
+        .get_type();
+    assert!(matches!(bar.which(), TypeVariant::AnyPointer));
+}
+
+fn load_test_schemas() -> capnp::schema::SchemaLoader {
+    let raw_code_gen_request =
+        std::fs::read(concat!(env!("OUT_DIR"), "/raw_code_gen_request.bin")).unwrap();
+    let message = capnp::serialize::read_message(
+        raw_code_gen_request.as_slice(),
+        capnp::message::ReaderOptions::new(),
+    )
+    .unwrap();
+    let request: capnp::schema_capnp::code_generator_request::Reader<'_> =
+        message.get_root().unwrap();
+    let mut loader = capnp::schema::SchemaLoader::new();
+    loader.load_request(request).unwrap();
+    loader
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_schema_loader() {
+    use capnp::traits::HasTypeId;
+
+    let loader = load_test_schemas();
+    let schema = loader
+        .get_struct(<test_all_types::Reader<'_> as HasTypeId>::TYPE_ID)
+        .unwrap();
+
+    let mut message = message::Builder::new_default();
+    let root = message
+        .init_root::<capnp::any_pointer::Builder<'_>>()
+        .init_dynamic(schema)
+        .unwrap();
+    test_util::dynamic_init_test_message(root);
+
+    let typed: test_all_types::Reader<'_> = message.get_root_as_reader().unwrap();
+    test_util::dynamic_check_test_message(dynamic_value::Reader::from(typed).downcast());
+
+    let root = message
+        .get_root_as_reader::<capnp::any_pointer::Reader<'_>>()
+        .unwrap();
+    test_util::dynamic_check_test_message(root.get_as_dynamic(schema).unwrap());
+    let root = message
+        .get_root::<capnp::any_pointer::Builder<'_>>()
+        .unwrap();
+    test_util::dynamic_check_test_message_builder(root.get_as_dynamic(schema).unwrap());
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_schema_loader_reads_compiled_message() {
+    use crate::test_capnp::TestEnum;
+    use capnp::traits::HasTypeId;
+
+    let loader = load_test_schemas();
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let root: dynamic_value::Builder<'_> = root.into();
+    test_util::dynamic_init_test_message(root.downcast());
+
+    let schema = loader
+        .get_struct(<test_all_types::Reader<'_> as HasTypeId>::TYPE_ID)
+        .unwrap();
+    let root = message
+        .get_root_as_reader::<capnp::any_pointer::Reader<'_>>()
+        .unwrap()
+        .get_as_dynamic(schema)
+        .unwrap();
+    test_util::dynamic_check_test_message(root);
+
+    let enum_schema = loader.get_enum(TestEnum::TYPE_ID).unwrap();
+    assert_eq!(enum_schema.get_enumerants().unwrap().len(), 8);
+    assert!(loader
+        .get_enum(<test_all_types::Reader<'_> as HasTypeId>::TYPE_ID)
+        .is_err());
+    assert!(loader.get_struct(TestEnum::TYPE_ID).is_err());
+    assert!(loader.get_struct(0x1234).is_err());
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_schema_loader_rejects_bad_nodes() {
+    use capnp::schema_capnp::node;
+    use capnp::traits::HasTypeId;
+
+    let loader = load_test_schemas();
+    let id = <test_all_types::Reader<'_> as HasTypeId>::TYPE_ID;
+    let original = loader.get_node(id).unwrap();
+
+    // Loading the same node again is fine.
+    let mut reloader = load_test_schemas();
+    reloader.load_nodes([original]).unwrap();
+
+    // A node whose fields lie outside of its data section is rejected.
+    let mut message = message::Builder::new_default();
+    message.set_root(original).unwrap();
+    let mut modified: node::Builder<'_> = message.get_root().unwrap();
+    modified.set_id(0x8765_4321);
+    let node::Which::Struct(mut st) = modified.which().unwrap() else {
+        panic!()
+    };
+    st.set_data_word_count(1);
+    let mut fresh = load_test_schemas();
+    assert!(fresh
+        .load_nodes([message.get_root_as_reader().unwrap()])
+        .is_err());
+
+    // A node that refers to nodes that were never loaded is rejected.
+    let mut empty = capnp::schema::SchemaLoader::new();
+    assert!(empty.load_nodes([original]).is_err());
+    assert!(empty.get_struct(id).is_err());
+
+    // A different node with an already-loaded ID is rejected.
+    let mut modified: node::Builder<'_> = message.get_root().unwrap();
+    modified.set_id(id);
+    assert!(fresh
+        .load_nodes([message.get_root_as_reader().unwrap()])
+        .is_err());
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_schema_loader_rejects_bad_groups() {
+    use capnp::schema_capnp::node;
+
+    const PARENT_ID: u64 = 0x8000_0000_0000_0001;
+    const GROUP_ID: u64 = 0x8000_0000_0000_0002;
+
+    /// Builds a struct with one data word and a group field, and a group of `group_words` data
+    /// words with a `UInt64` field in its last word.
+    fn load(group_words: u16, is_group: bool, group_scope_id: u64) -> capnp::Result<()> {
+        let mut parent_message = message::Builder::new_default();
+        let mut parent = parent_message.init_root::<node::Builder<'_>>();
+        parent.set_id(PARENT_ID);
+        let mut parent_struct = parent.init_struct();
+        parent_struct.set_data_word_count(1);
+        let mut field = parent_struct.init_fields(1).get(0);
+        field.set_name("group".into());
+        field.init_group().set_type_id(GROUP_ID);
+
+        let mut group_message = message::Builder::new_default();
+        let mut group = group_message.init_root::<node::Builder<'_>>();
+        group.set_id(GROUP_ID);
+        group.set_scope_id(group_scope_id);
+        let mut group_struct = group.init_struct();
+        group_struct.set_data_word_count(group_words);
+        group_struct.set_is_group(is_group);
+        let mut field = group_struct.init_fields(1).get(0);
+        field.set_name("value".into());
+        let mut slot = field.init_slot();
+        slot.set_offset(u32::from(group_words) - 1);
+        slot.reborrow().init_type().set_uint64(());
+        slot.init_default_value().set_uint64(0);
+
+        let mut loader = capnp::schema::SchemaLoader::new();
+        loader.load_nodes([
+            parent_message.get_root_as_reader()?,
+            group_message.get_root_as_reader()?,
+        ])
+    }
+
+    load(1, true, PARENT_ID).unwrap();
+
+    // A group that is larger than its parent could be used to write past the end of it.
+    assert!(load(64, true, PARENT_ID).is_err());
+
+    // The referenced node has to be a group of the parent.
+    assert!(load(1, false, PARENT_ID).is_err());
+    assert!(load(1, true, 0x1234).is_err());
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_schema_loader_batches() {
+    use capnp::schema_capnp::node;
+    use capnp::traits::HasTypeId;
+
+    // A node in a later batch can refer to nodes from every earlier batch.
+    let mut loader = load_test_schemas();
+    let original = loader
+        .get_node(<test_all_types::Reader<'_> as HasTypeId>::TYPE_ID)
+        .unwrap();
+    for id in 1..=3 {
+        let mut message = message::Builder::new_default();
+        message.set_root(original).unwrap();
+        message.get_root::<node::Builder<'_>>().unwrap().set_id(id);
+        loader
+            .load_nodes([message.get_root_as_reader().unwrap()])
+            .unwrap();
+    }
+
+    for id in 1..=3 {
+        let schema = loader.get_struct(id).unwrap();
+        let mut message = message::Builder::new_default();
+        let root = message
+            .init_root::<capnp::any_pointer::Builder<'_>>()
+            .init_dynamic(schema)
+            .unwrap();
+        test_util::dynamic_init_test_message(root);
+        let typed: test_all_types::Reader<'_> = message.get_root_as_reader().unwrap();
+        test_util::dynamic_check_test_message(dynamic_value::Reader::from(typed).downcast());
+    }
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_schema_loader_interfaces() {
+    use crate::test_capnp::{test_extends, test_interface};
+    use capnp::introspect::TypeVariant;
+    use capnp::traits::HasTypeId;
+
+    let loader = load_test_schemas();
+    let schema = loader
+        .get_interface(<test_extends::Client as HasTypeId>::TYPE_ID)
+        .unwrap();
+    assert_eq!(schema.get_methods().unwrap().len(), 3);
+    let foo = schema.get_method_by_name("foo").unwrap();
+    assert_eq!(
+        foo.get_containing_interface().get_proto().get_id(),
+        <test_interface::Client as HasTypeId>::TYPE_ID
+    );
+    assert!(matches!(
+        foo.get_param_type()
+            .get_field_by_name("i")
+            .unwrap()
+            .get_type()
+            .which(),
+        TypeVariant::UInt32
+    ));
+
+    let all_types = loader
+        .get_struct(<test_all_types::Reader<'_> as HasTypeId>::TYPE_ID)
+        .unwrap();
+    let ty = all_types
+        .get_field_by_name("interfaceField")
+        .unwrap()
+        .get_type();
+    assert!(matches!(ty.which(), TypeVariant::Capability(_)));
+    assert!(loader
+        .get_interface(<test_all_types::Reader<'_> as HasTypeId>::TYPE_ID)
+        .is_err());
+}
+
+#[test]
+fn test_diff() {
+    let mut message1 = message::Builder::new_default();
+    test_util::init_test_message(message1.init_root());
+    let mut message2 = message::Builder::new_default();
+    test_util::init_test_message(message2.init_root());
+
+    let root1: test_all_types::Reader<'_> = message1.get_root_as_reader().unwrap();
+    let root2: test_all_types::Reader<'_> = message2.get_root_as_reader().unwrap();
+    assert!(dynamic_value::diff(root1, root2).unwrap().is_empty());
+    capnp::assert_capnp_eq!(root1, root2);
+
+    {
+        let mut root2: test_all_types::Builder<'_> = message2.get_root().unwrap();
+        root2.set_int32_field(7);
+        root2
+            .reborrow()
+            .get_struct_field()
+            .unwrap()
+            .set_text_field("changed".into());
+        root2
+            .reborrow()
+            .get_struct_list()
+            .unwrap()
+            .get(1)
+            .set_u_int8_field(99);
+        root2.init_int32_list(1).set(0, 42);
+    }
+
+    let root1: test_all_types::Reader<'_> = message1.get_root_as_reader().unwrap();
+    let root2: test_all_types::Reader<'_> = message2.get_root_as_reader().unwrap();
+    let differences: Vec<String> = dynamic_value::diff(root1, root2)
+        .unwrap()
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    assert_eq!(
+        differences,
+        [
+            "int32Field: -12345678 -> 7",
+            "structField.textField: \"baz\" -> \"changed\"",
+            "int32List[0]: <absent> -> 42",
+            "structList[1].uInt8Field: 0 -> 99",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_unions_and_any_pointers() {
+    use crate::test_capnp::{test_any_pointer, test_groups};
+
+    let mut message1 = message::Builder::new_default();
+    let mut groups1 = message1
+        .init_root::<test_groups::Builder<'_>>()
+        .init_groups();
+    groups1.reborrow().init_foo().set_corge(1);
+    let mut message2 = message::Builder::new_default();
+    let mut groups2 = message2
+        .init_root::<test_groups::Builder<'_>>()
+        .init_groups();
+    groups2.reborrow().init_bar().set_corge(1);
+
+    let differences = dynamic_value::diff(groups1.into_reader(), groups2.into_reader()).unwrap();
+    assert_eq!(differences.len(), 2);
+    assert_eq!(differences[0].path, "foo");
+    assert!(differences[0].left.is_some() && differences[0].right.is_none());
+    assert_eq!(differences[1].path, "bar");
+    assert!(differences[1].left.is_none() && differences[1].right.is_some());
+
+    let mut message1 = message::Builder::new_default();
+    let mut root1 = message1.init_root::<test_any_pointer::Builder<'_>>();
+    root1
+        .reborrow()
+        .init_any_pointer_field()
+        .set_as("foo")
+        .unwrap();
+    let mut message2 = message::Builder::new_default();
+    let mut root2 = message2.init_root::<test_any_pointer::Builder<'_>>();
+    root2
+        .reborrow()
+        .init_any_pointer_field()
+        .set_as("foo")
+        .unwrap();
+    capnp::assert_capnp_eq!(root1.reborrow_as_reader(), root2.reborrow_as_reader());
+
+    root2
+        .reborrow()
+        .init_any_pointer_field()
+        .set_as("bar")
+        .unwrap();
+    let differences = dynamic_value::diff(root1.into_reader(), root2.into_reader()).unwrap();
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].path, "anyPointerField");
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion `left == right` failed: round trip\n  int32Field: -12345678 -> 7"
+)]
+fn test_assert_capnp_eq_fails() {
+    let mut message1 = message::Builder::new_default();
+    test_util::init_test_message(message1.init_root());
+    let mut message2 = message::Builder::new_default();
+    test_util::init_test_message(message2.init_root());
+    message2
+        .get_root::<test_all_types::Builder<'_>>()
+        .unwrap()
+        .set_int32_field(7);
+
+    let root1: test_all_types::Reader<'_> = message1.get_root_as_reader().unwrap();
+    let root2: test_all_types::Reader<'_> = message2.get_root_as_reader().unwrap();
+    capnp::assert_capnp_eq!(root1, root2, "round {}", "trip");
+}
+
+#[test]
+fn test_field_path_parse() {
+    use capnp::dynamic_struct::{FieldPath, PathSegment};
+    use capnp::ErrorKind;
+
+    let path: FieldPath = "orders[2].items[0][13].price".parse().unwrap();
+    assert_eq!(
+        path.segments(),
+        [
+            PathSegment::Field("orders".into()),
+            PathSegment::Index(2),
+            PathSegment::Field("items".into()),
+            PathSegment::Index(0),
+            PathSegment::Index(13),
+            PathSegment::Field("price".into()),
+        ]
+    );
+    assert_eq!(path.to_string(), "orders[2].items[0][13].price");
+    assert_eq!(FieldPath::parse("[1].x").unwrap().len(), 2);
+    assert!(FieldPath::parse("").unwrap().is_empty());
+
+    for (input, extra) in [
+        ("foo.", "expected field name at column 5 of `foo.`"),
+        ("foo..bar", "expected field name at column 5 of `foo..bar`"),
+        ("foo[x]", "expected list index at column 5 of `foo[x]`"),
+        ("foo[1", "expected list index at column 5 of `foo[1`"),
+        (
+            "foo[1]bar",
+            "expected '.' or '[' at column 7 of `foo[1]bar`",
+        ),
+        (
+            "foo[99999999999]",
+            "list index is too large at column 5 of `foo[99999999999]`",
+        ),
+        ("foo-bar", "expected '.' or '[' at column 4 of `foo-bar`"),
+    ] {
+        let error = FieldPath::parse(input).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidFieldPath);
+        assert_eq!(error.extra, extra);
+    }
+}
+
+#[test]
+fn test_field_path_get() {
+    use capnp::dynamic_struct::FieldPath;
+    use capnp::ErrorKind;
+
+    let mut message = message::Builder::new_default();
+    test_util::init_test_message(message.init_root());
+    let root: test_all_types::Reader<'_> = message.get_root_as_reader().unwrap();
+    let root = dynamic_value::Reader::from(root).downcast::<dynamic_struct::Reader<'_>>();
+
+    let get = |path: &str| root.get_path(&path.parse().unwrap());
+    let text = get("structField.structList[1].textField")
+        .unwrap()
+        .downcast::<capnp::text::Reader<'_>>();
+    assert_eq!(text, "x structlist 2");
+    assert_eq!(
+        get("structField.int32List[3]").unwrap().downcast::<i32>(),
+        0x7fffffff
+    );
+    let list = get("structField.structList")
+        .unwrap()
+        .downcast::<dynamic_list::Reader<'_>>();
+    let path: FieldPath = "[2].textField".parse().unwrap();
+    let text = list
+        .get_path(&path)
+        .unwrap()
+        .downcast::<capnp::text::Reader<'_>>();
+    assert_eq!(text, "x structlist 3");
+    assert!(matches!(get("").unwrap(), dynamic_value::Reader::Struct(_)));
+
+    for (path, index, extra) in [
+        ("structField.nope", 1, "structField.nope: no such field"),
+        (
+            "structField.structList[3].textField",
+            2,
+            "structField.structList[3]: index out of bounds for list of length 3",
+        ),
+        ("int32Field.foo", 1, "int32Field.foo: not a struct"),
+        ("structField[0]", 1, "structField[0]: not a list"),
+    ] {
+        let error = get(path).err().unwrap();
+        assert_eq!(error.kind, ErrorKind::FieldPathSegmentNotFound(index));
+        assert_eq!(error.extra, extra);
+    }
+}
+
+#[test]
+fn test_field_path_set_and_init() {
+    use capnp::dynamic_struct::FieldPath;
+    use capnp::ErrorKind;
+
+    let path = |path: &str| path.parse::<FieldPath>().unwrap();
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let mut root = dynamic_value::Builder::from(root).downcast::<dynamic_struct::Builder<'_>>();
+
+    root.reborrow().initn_path(&path("structList"), 2).unwrap();
+    root.set_path(&path("structList[1].textField"), "second".into())
+        .unwrap();
+    root.reborrow()
+        .init_path(&path("structList[0].structField"))
+        .unwrap();
+    root.set_path(&path("structList[0].structField.uInt16Field"), 7u16.into())
+        .unwrap();
+    root.reborrow()
+        .initn_path(&path("structList[0].int32List"), 2)
+        .unwrap();
+    root.set_path(&path("structList[0].int32List[1]"), (-5i32).into())
+        .unwrap();
+    root.reborrow().initn_path(&path("textList"), 1).unwrap();
+    root.reborrow().initn_path(&path("textList[0]"), 3).unwrap();
+
+    let error = root
+        .set_path(&path("structList[2].textField"), "third".into())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::FieldPathSegmentNotFound(1));
+    let error = root.set_path(&path(""), "root".into()).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::InvalidFieldPath);
+    let error = root
+        .reborrow()
+        .init_path(&path("structList[0]"))
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.kind,
+        ErrorKind::InitIsOnlyValidForStructAndAnyPointerFields
+    );
+
+    let reader = root.into_reader();
+    let structs = reader.get_named("structList").unwrap();
+    let structs = structs.downcast::<dynamic_list::Reader<'_>>();
+    let first = structs
+        .get(0)
+        .unwrap()
+        .downcast::<dynamic_struct::Reader<'_>>();
+    let get = |path: &str| reader.get_path(&path.parse().unwrap()).unwrap();
+    assert_eq!(
+        get("structList[1].textField").downcast::<capnp::text::Reader<'_>>(),
+        "second"
+    );
+    assert_eq!(
+        get("structList[0].structField.uInt16Field").downcast::<u16>(),
+        7
+    );
+    assert_eq!(
+        first
+            .get_path(&path("int32List[1]"))
+            .unwrap()
+            .downcast::<i32>(),
+        -5
+    );
+    assert_eq!(
+        get("textList[0]").downcast::<capnp::text::Reader<'_>>(),
+        "\0\0\0"
+    );
+}
+
+#[test]
+fn test_field_path_unions() {
+    use crate::test_capnp::test_groups;
+    use capnp::dynamic_struct::FieldPath;
+    use capnp::ErrorKind;
+
+    let path = |path: &str| path.parse::<FieldPath>().unwrap();
+    let mut message = message::Builder::new_default();
+    let root: test_groups::Builder<'_> = message.init_root();
+    let mut root = dynamic_value::Builder::from(root).downcast::<dynamic_struct::Builder<'_>>();
+
+    // Only the last segment of a path may switch the active union member.
+    let error = root
+        .set_path(&path("groups.bar.grault"), "hello".into())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::FieldPathSegmentNotFound(1));
+    assert_eq!(error.extra, "groups.bar: not the active union member");
+    root.reborrow().init_path(&path("groups.bar")).unwrap();
+    root.set_path(&path("groups.bar.grault"), "hello".into())
+        .unwrap();
+
+    let reader = root.into_reader();
+    let text = reader
+        .get_path(&path("groups.bar.grault"))
+        .unwrap()
+        .downcast::<capnp::text::Reader<'_>>();
+    assert_eq!(text, "hello");
+    let error = reader.get_path(&path("groups.foo.corge")).err().unwrap();
+    assert_eq!(error.kind, ErrorKind::FieldPathSegmentNotFound(1));
+}
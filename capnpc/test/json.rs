@@ -0,0 +1,265 @@
+use crate::test_capnp::{test_all_types, TestEnum};
+use crate::test_json_capnp::{
+    test_json_annotations, test_json_annotations2, TestJsonAnnotatedEnum,
+};
+use crate::test_util;
+use capnp::message;
+use capnp::{dynamic_value, json};
+
+const GOLDEN_ANNOTATED: &str = concat!(
+    r#"{"names-can_contain!anything Really":"foo","#,
+    r#""flatFoo":123,"flatBar":"abc","renamed-flatBaz":{"hello":true},"flatQux":"cba","#,
+    r#""pfx.foo":"this is a long string in order to force multi-line pretty printing","#,
+    r#""pfx.renamed-bar":321,"pfx.baz":{"hello":true},"pfx.xfp.qux":"fed","#,
+    r#""union-type":"renamed-bar","barMember":789,"multiMember":"ghi","#,
+    r#""dependency":{"renamed-foo":"corge"},"simpleGroup":{"renamed-grault":"garply"},"#,
+    r#""enums":["qux","renamed-bar","foo","renamed-baz"],"#,
+    r#""innerJson":[123,"hello",{"object":true}],"#,
+    r#""testBase64":"ZnJlZA==","testHex":"706c756768","#,
+    r#""bUnion":"renamed-bar","bValue":678,"#,
+    r#""externalUnion":{"type":"bar","value":"cba"},"#,
+    r#""unionWithVoid":{"type":"voidValue"}}"#
+);
+
+const GOLDEN_ANNOTATED_REVERSE: &str = r#"{
+  "unionWithVoid": {"type": "voidValue"},
+  "externalUnion": {"type": "bar", "value": "cba"},
+  "bValue": 678,
+  "bUnion": "renamed-bar",
+  "testHex": "706c756768",
+  "testBase64": "ZnJlZA==",
+  "innerJson": [123, "hello", {"object": true}],
+  "enums": ["qux", "renamed-bar", "foo", "renamed-baz"],
+  "simpleGroup": { "renamed-grault": "garply" },
+  "dependency": { "renamed-foo": "corge" },
+  "multiMember": "ghi",
+  "barMember": 789,
+  "union-type": "renamed-bar",
+  "pfx.xfp.qux": "fed",
+  "pfx.baz": {"hello": true},
+  "pfx.renamed-bar": 321,
+  "pfx.foo": "this is a long string in order to force multi-line pretty printing",
+  "flatQux": "cba",
+  "renamed-flatBaz": {"hello": true},
+  "flatBar": "abc",
+  "flatFoo": 123,
+  "names-can_contain!anything Really": "foo"
+}"#;
+
+#[test]
+fn test_json_encoding() {
+    assert_eq!(json::encode(()).unwrap(), "null");
+    assert_eq!(json::encode(true).unwrap(), "true");
+    assert_eq!(json::encode(123i32).unwrap(), "123");
+    assert_eq!(json::encode(-5.5f64).unwrap(), "-5.5");
+    assert_eq!(json::encode(f32::NAN).unwrap(), "\"NaN\"");
+    assert_eq!(json::encode(f64::NEG_INFINITY).unwrap(), "\"-Infinity\"");
+    assert_eq!(
+        json::encode(-9007199254740993i64).unwrap(),
+        "\"-9007199254740993\""
+    );
+    assert_eq!(
+        json::encode("ab\"cd\\ef\x03").unwrap(),
+        "\"ab\\\"cd\\\\ef\\u0003\""
+    );
+    assert_eq!(json::encode(TestEnum::Corge).unwrap(), "\"corge\"");
+
+    let bytes: &[u8] = &[12, 34, 56];
+    assert_eq!(json::encode(bytes).unwrap(), "[12,34,56]");
+    assert_eq!(
+        json::Codec::new().pretty_print(true).encode(bytes).unwrap(),
+        "[\n  12,\n  34,\n  56\n]"
+    );
+}
+
+#[test]
+fn test_json_round_trip() {
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let mut root: dynamic_value::Builder<'_> = root.into();
+    test_util::dynamic_init_test_message(root.reborrow().downcast());
+    let root = root.into_reader();
+
+    for pretty_print in [false, true] {
+        let encoded = json::Codec::new()
+            .pretty_print(pretty_print)
+            .encode(root)
+            .unwrap();
+        assert!(encoded.contains("\"int64Field\"") && encoded.contains("\"-123456789012345\""));
+
+        let mut message2 = message::Builder::new_default();
+        let root2: test_all_types::Builder<'_> = message2.init_root();
+        let root2: dynamic_value::Builder<'_> = root2.into();
+        json::decode(&encoded, root2.downcast()).unwrap();
+        let root2 = message2
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        let root2: dynamic_value::Reader<'_> = root2.into();
+        test_util::dynamic_check_test_message(root2.downcast());
+    }
+}
+
+#[test]
+fn test_json_annotations() {
+    let mut message = message::Builder::new_default();
+    let mut root: test_json_annotations::Builder<'_> = message.init_root();
+    root.set_some_field("foo".into());
+
+    let mut a_group = root.reborrow().get_a_group();
+    a_group.set_flat_foo(123);
+    a_group.set_flat_bar("abc".into());
+    a_group.reborrow().get_flat_baz().set_hello(true);
+    a_group
+        .reborrow()
+        .get_double_flat()
+        .set_flat_qux("cba".into());
+
+    let mut prefixed_group = root.reborrow().get_prefixed_group();
+    prefixed_group
+        .set_foo("this is a long string in order to force multi-line pretty printing".into());
+    prefixed_group.set_bar(321);
+    prefixed_group.reborrow().get_baz().set_hello(true);
+    prefixed_group
+        .reborrow()
+        .get_more_prefix()
+        .set_qux("fed".into());
+
+    let mut union_bar = root.reborrow().get_a_union().init_bar();
+    union_bar.set_bar_member(789);
+    union_bar.set_multi_member("ghi".into());
+
+    root.reborrow().init_dependency().set_foo("corge".into());
+    root.reborrow()
+        .get_simple_group()
+        .set_grault("garply".into());
+
+    let mut enums = root.reborrow().init_enums(4);
+    enums.set(0, TestJsonAnnotatedEnum::Qux);
+    enums.set(1, TestJsonAnnotatedEnum::Bar);
+    enums.set(2, TestJsonAnnotatedEnum::Foo);
+    enums.set(3, TestJsonAnnotatedEnum::Baz);
+
+    let mut array = root.reborrow().init_inner_json().init_array(3);
+    array.reborrow().get(0).set_number(123.0);
+    array.reborrow().get(1).set_string("hello".into());
+    let mut field = array.reborrow().get(2).init_object(1).get(0);
+    field.set_name("object".into());
+    field.init_value().set_boolean(true);
+
+    root.set_test_base64(b"fred");
+    root.set_test_hex(b"plugh");
+    root.reborrow().get_b_union().set_bar(678);
+    root.reborrow()
+        .init_external_union()
+        .init_bar()
+        .set_value("cba".into());
+    root.reborrow().get_union_with_void().set_void_value(());
+
+    let root = root.into_reader();
+    assert_eq!(json::encode(root).unwrap(), GOLDEN_ANNOTATED);
+    let golden_text = format!("{:?}", dynamic_value::Reader::from(root));
+
+    for input in [GOLDEN_ANNOTATED, GOLDEN_ANNOTATED_REVERSE] {
+        let mut message2 = message::Builder::new_default();
+        let root2: test_json_annotations::Builder<'_> = message2.init_root();
+        let root2: dynamic_value::Builder<'_> = root2.into();
+        json::decode(input, root2.downcast()).unwrap();
+        let root2 = message2
+            .get_root_as_reader::<test_json_annotations::Reader<'_>>()
+            .unwrap();
+        assert_eq!(
+            format!("{:?}", dynamic_value::Reader::from(root2)),
+            golden_text
+        );
+    }
+}
+
+#[test]
+fn test_json_decode_merges_nested_structs() {
+    let mut message = message::Builder::new_default();
+    let mut root: test_all_types::Builder<'_> = message.init_root();
+    let mut inner = root.reborrow().init_struct_field();
+    inner.set_int32_field(7);
+    inner.set_text_field("kept".into());
+    let root: dynamic_value::Builder<'_> = root.into();
+    json::decode(r#"{"structField": {"int32Field": 8}}"#, root.downcast()).unwrap();
+
+    let root = message
+        .get_root_as_reader::<test_all_types::Reader<'_>>()
+        .unwrap();
+    let inner = root.get_struct_field().unwrap();
+    assert_eq!(inner.get_int32_field(), 8);
+    assert_eq!(inner.get_text_field().unwrap(), "kept");
+
+    // Naming the active member of a discriminated union merges into it too.
+    let mut message = message::Builder::new_default();
+    let root: test_json_annotations::Builder<'_> = message.init_root();
+    let mut bar = root.get_a_union().init_bar();
+    bar.set_bar_member(789);
+    bar.set_multi_member("ghi".into());
+    let root: test_json_annotations::Builder<'_> = message.get_root().unwrap();
+    let root: dynamic_value::Builder<'_> = root.into();
+    json::decode(
+        r#"{"union-type": "renamed-bar", "barMember": 1}"#,
+        root.downcast(),
+    )
+    .unwrap();
+    let root = message
+        .get_root_as_reader::<test_json_annotations::Reader<'_>>()
+        .unwrap();
+    let test_json_annotations::a_union::Bar(bar) = root.get_a_union().which().unwrap() else {
+        panic!()
+    };
+    assert_eq!(bar.get_bar_member(), 1);
+    assert_eq!(bar.get_multi_member().unwrap(), "ghi");
+}
+
+#[test]
+fn test_json_decode_errors() {
+    fn decode(codec: &json::Codec, input: &str) -> capnp::Result<()> {
+        let mut message = message::Builder::new_default();
+        let root: test_json_annotations2::Builder<'_> = message.init_root();
+        let root: dynamic_value::Builder<'_> = root.into();
+        codec.decode(input, root.downcast())
+    }
+
+    let mut codec = json::Codec::new();
+    let valid = r#"{"renamed-foo": "a"}"#;
+    let unknown = r#"{"renamed-foo": "a", "unknown-field": "b"}"#;
+    decode(&codec, valid).unwrap();
+    decode(&codec, unknown).unwrap();
+    codec.reject_unknown_fields(true);
+    decode(&codec, valid).unwrap();
+    let e = decode(&codec, unknown).unwrap_err();
+    assert_eq!(e.kind, capnp::ErrorKind::FieldNotFound);
+    assert_eq!(e.extra, "unknown-field");
+
+    let e = decode(&codec, r#"{"renamed-foo": 1}"#).unwrap_err();
+    assert_eq!(e.kind, capnp::ErrorKind::TypeMismatch);
+    assert_eq!(
+        e.extra,
+        "expected text, found a number, in field `renamed-foo`"
+    );
+
+    let e = decode(&codec, "{\"renamed-foo\": \"a\",\n  }").unwrap_err();
+    assert_eq!(e.kind, capnp::ErrorKind::InvalidJson);
+    assert_eq!(e.extra, "expected a string key at line 2, column 3");
+
+    for input in ["", "[1, 2", "{\"a\" 1}", "\"\\x\"", "tru", "{} {}"] {
+        assert_eq!(
+            decode(&codec, input).unwrap_err().kind,
+            capnp::ErrorKind::InvalidJson,
+            "{input}"
+        );
+    }
+    assert_eq!(
+        decode(&codec, "[]").unwrap_err().kind,
+        capnp::ErrorKind::TypeMismatch
+    );
+
+    let deep = format!("{}{}", "[".repeat(100), "]".repeat(100));
+    assert_eq!(
+        decode(&codec, &deep).unwrap_err().kind,
+        capnp::ErrorKind::NestingLimitExceeded
+    );
+}
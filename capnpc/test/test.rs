@@ -27,6 +27,10 @@ pub mod test_capnp {
     include!(concat!(env!("OUT_DIR"), "/test_capnp.rs"));
 }
 
+pub mod test_json_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_json_capnp.rs"));
+}
+
 pub mod foo {
     pub mod bar {
         pub mod in_submodule_capnp {
@@ -81,9 +85,18 @@ pub mod test_output_path_capnp {
 #[cfg(test)]
 mod test_util;
 
+#[cfg(test)]
+mod compat;
+
 #[cfg(test)]
 mod dynamic;
 
+#[cfg(test)]
+mod json;
+
+#[cfg(test)]
+mod transcode;
+
 #[cfg(test)]
 mod tests {
     use crate::test_util::{init_test_message, CheckTestMessage};
@@ -2134,6 +2147,485 @@ mod tests {
         CheckTestMessage::check_test_message(message_reader.get().unwrap());
     }
 
+    #[test]
+    fn orphans() {
+        use crate::test_capnp::{test_all_types, test_any_pointer};
+        use capnp::orphan::Orphan;
+
+        fn total_words<A: message::Allocator>(message: &message::Builder<A>) -> usize {
+            let segments = message.get_segments_for_output();
+            segments.iter().map(|s| s.len() / 8).sum()
+        }
+
+        for builder_options in [
+            message::HeapAllocator::new(),
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        ] {
+            let mut message = message::Builder::new(builder_options);
+
+            let mut orphan: Orphan<test_all_types::Owned> = message.get_orphanage().init_orphan();
+            init_test_message(orphan.get(message.get_orphanage()).unwrap());
+            CheckTestMessage::check_test_message(
+                orphan.get_reader(message.get_orphanage()).unwrap(),
+            );
+
+            let mut root: test_any_pointer::Builder<'_> = message.init_root();
+            root.reborrow().get_any_pointer_field().adopt(orphan);
+            CheckTestMessage::check_test_message(
+                root.reborrow()
+                    .get_any_pointer_field()
+                    .get_as::<test_all_types::Builder<'_>>()
+                    .unwrap(),
+            );
+
+            // Move the struct one level down without copying it.
+            let words_before = total_words(&message);
+            let mut root: test_any_pointer::Builder<'_> = message.get_root().unwrap();
+            let all_types: Orphan<test_all_types::Owned> =
+                root.reborrow().get_any_pointer_field().disown_as();
+            assert!(root.reborrow().get_any_pointer_field().is_null());
+            assert!(!all_types.is_null(message.get_orphanage()));
+
+            let mut inner: Orphan<test_any_pointer::Owned> = message.get_orphanage().init_orphan();
+            inner
+                .get(message.get_orphanage())
+                .unwrap()
+                .get_any_pointer_field()
+                .adopt(all_types);
+            let mut root: test_any_pointer::Builder<'_> = message.get_root().unwrap();
+            root.reborrow().get_any_pointer_field().adopt(inner);
+            assert!(total_words(&message) - words_before <= 8);
+
+            let root: test_any_pointer::Reader<'_> = message.get_root_as_reader().unwrap();
+            let inner: test_any_pointer::Reader<'_> =
+                root.get_any_pointer_field().get_as().unwrap();
+            CheckTestMessage::check_test_message(
+                inner
+                    .get_any_pointer_field()
+                    .get_as::<test_all_types::Reader<'_>>()
+                    .unwrap(),
+            );
+
+            let mut message2 = message::Builder::new_default();
+            let copy: Orphan<test_all_types::Owned> = message2
+                .get_orphanage()
+                .new_orphan_copy(inner.get_any_pointer_field().get_as().unwrap())
+                .unwrap();
+            CheckTestMessage::check_test_message(
+                copy.get_reader(message2.get_orphanage()).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn orphan_discard() {
+        use capnp::orphan::Orphan;
+
+        let mut message = message::Builder::new_default();
+        let orphan: Orphan<text::Owned> = message
+            .get_orphanage()
+            .new_orphan_copy("secret".into())
+            .unwrap();
+        let segments = message.get_segments_for_output();
+        assert!(segments[0].windows(6).any(|w| w == b"secret"));
+
+        orphan.discard(message.get_orphanage());
+        let segments = message.get_segments_for_output();
+        assert!(!segments[0].windows(6).any(|w| w == b"secret"));
+    }
+
+    #[test]
+    #[should_panic(expected = "orphans can only be adopted into the message that they belong to")]
+    fn orphan_adopted_into_other_message() {
+        let mut message = message::Builder::new_default();
+        let orphan = message
+            .get_orphanage()
+            .initn_orphan::<primitive_list::Owned<u32>>(3);
+
+        let mut message2 = message::Builder::new_default();
+        message2
+            .init_root::<capnp::any_pointer::Builder<'_>>()
+            .adopt(orphan);
+    }
+
+    #[test]
+    fn orphan_accessors() {
+        use crate::test_capnp::{test_all_types, test_any_pointer, test_generics, test_union};
+
+        let mut message = message::Builder::new_default();
+        let mut root: test_all_types::Builder<'_> = message.init_root();
+        init_test_message(root.reborrow().init_struct_field());
+        root.set_text_field("hello".into());
+        root.reborrow().init_int32_list(3).set(2, 123);
+
+        let inner = root.disown_struct_field();
+        let text = root.disown_text_field();
+        let list = root.disown_int32_list();
+        assert!(!root.has_struct_field());
+        assert!(!root.has_text_field());
+        assert!(!root.has_int32_list());
+
+        let mut new_inner = root.reborrow().init_struct_field();
+        new_inner.adopt_struct_field(inner);
+        new_inner.adopt_text_field(text);
+        new_inner.adopt_int32_list(list);
+
+        let new_inner = root.into_reader().get_struct_field().unwrap();
+        CheckTestMessage::check_test_message(new_inner.get_struct_field().unwrap());
+        assert_eq!(new_inner.get_text_field().unwrap(), "hello");
+        assert_eq!(new_inner.get_int32_list().unwrap().get(2), 123);
+
+        // Adopting a union member makes it the active one.
+        let mut message = message::Builder::new_default();
+        let mut root: test_union::Builder<'_> = message.init_root();
+        root.reborrow().get_union0().set_u0f0sp("abcdef".into());
+        let text = root.reborrow().get_union0().disown_u0f0sp();
+        root.reborrow().get_union1().set_u1f0s64(64);
+        root.reborrow().get_union1().adopt_u1f1sp(text);
+        match root.reborrow().get_union1().which() {
+            Ok(test_union::union1::U1f1sp(Ok(t))) => assert_eq!(t, "abcdef"),
+            _ => panic!(),
+        }
+
+        // Generic fields use the type parameter, and AnyPointer fields can take any orphan.
+        let mut message = message::Builder::new_default();
+        let mut root: test_generics::Builder<'_, test_all_types::Owned, text::Owned> =
+            message.init_root();
+        init_test_message(root.reborrow().get_foo().unwrap());
+        let foo = root.disown_foo();
+        root.reborrow().get_rev().unwrap().adopt_bar(foo);
+        CheckTestMessage::check_test_message(root.reborrow().get_rev().unwrap().get_bar().unwrap());
+
+        let foo = root.reborrow().get_rev().unwrap().disown_bar();
+        let mut orphanage = message.get_orphanage();
+        let mut any: capnp::orphan::Orphan<test_any_pointer::Owned> = orphanage.init_orphan();
+        any.get(orphanage)
+            .unwrap()
+            .adopt_any_pointer_field(foo.into_any_pointer());
+        let mut any = any.get(message.get_orphanage()).unwrap();
+        let foo = any
+            .disown_any_pointer_field()
+            .into_typed::<test_all_types::Owned>();
+        let mut root: test_generics::Builder<'_, test_all_types::Owned, text::Owned> =
+            message.get_root().unwrap();
+        root.adopt_foo(foo);
+        CheckTestMessage::check_test_message(root.into_reader().get_foo().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "disown_u0f1sp() called when u0f1sp is not the active union member")]
+    fn disown_inactive_union_member_panics() {
+        use crate::test_capnp::test_union;
+
+        let mut message = message::Builder::new_default();
+        let mut root: test_union::Builder<'_> = message.init_root();
+        root.reborrow().get_union0().set_u0f0sp("abcdef".into());
+        let _ = root.get_union0().disown_u0f1sp();
+    }
+
+    #[test]
+    fn compact() {
+        use crate::test_capnp::test_all_types;
+
+        fn total_bytes<A: message::Allocator>(message: &message::Builder<A>) -> usize {
+            let segments = message.get_segments_for_output();
+            segments.iter().map(|s| s.len()).sum()
+        }
+
+        for builder_options in [
+            message::HeapAllocator::new(),
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        ] {
+            let mut message = message::Builder::new(builder_options);
+            assert_eq!(message.compact().unwrap(), 0);
+            for _ in 0..10 {
+                init_test_message(message.init_root());
+            }
+            let bytes_before = total_bytes(&message);
+
+            let reclaimed = message.compact().unwrap();
+            assert_eq!(message.get_segments_for_output().len(), 1);
+            assert_eq!(total_bytes(&message), bytes_before - reclaimed);
+            let root: test_all_types::Reader<'_> = message.get_root_as_reader().unwrap();
+            CheckTestMessage::check_test_message(root);
+            let root_words = root.total_size().unwrap().word_count as usize;
+            assert_eq!(total_bytes(&message), (root_words + 1) * 8);
+
+            CheckTestMessage::check_test_message(
+                message.get_root::<test_all_types::Builder<'_>>().unwrap(),
+            );
+            assert_eq!(message.compact().unwrap(), 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "orphan used with a message that it does not belong to")]
+    fn compact_invalidates_orphans() {
+        let mut message = message::Builder::new_default();
+        let mut orphan = message
+            .get_orphanage()
+            .initn_orphan::<primitive_list::Owned<u32>>(3);
+        message.compact().unwrap();
+        let _ = orphan.get(message.get_orphanage());
+    }
+
+    #[test]
+    fn compact_invalidates_every_orphan_method() {
+        use capnp::orphan::Orphan;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        fn assert_invalidated(f: impl FnOnce()) {
+            let error = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+            let error = error.downcast_ref::<String>().unwrap();
+            assert!(
+                error.contains("orphan used with a message that it does not belong to")
+                    || error.contains("orphans can only be adopted into the message"),
+                "{error}"
+            );
+        }
+
+        let mut message = message::Builder::new_default();
+        let mut orphans: Vec<Orphan<primitive_list::Owned<u32>>> = (0..6)
+            .map(|_| message.get_orphanage().initn_orphan(3))
+            .collect();
+        message.compact().unwrap();
+
+        let mut orphan = orphans.pop().unwrap();
+        assert_invalidated(|| {
+            orphan.is_null(message.get_orphanage());
+        });
+        assert_invalidated(|| {
+            let _ = orphan.get(message.get_orphanage());
+        });
+        assert_invalidated(|| {
+            let _ = orphan.get_reader(message.get_orphanage());
+        });
+        assert_invalidated(|| orphan.discard(message.get_orphanage()));
+        let orphan = orphans.pop().unwrap();
+        assert_invalidated(|| {
+            let mut root: capnp::any_pointer::Builder<'_> = message.init_root();
+            root.adopt(orphan);
+        });
+
+        // Changing the type of an orphan does not touch its memory, but the result is no more
+        // usable than the original.
+        let orphan = orphans.pop().unwrap().into_any_pointer();
+        assert_invalidated(|| {
+            orphan.is_null(message.get_orphanage());
+        });
+        let mut orphan = orphans
+            .pop()
+            .unwrap()
+            .into_any_pointer()
+            .into_typed::<text::Owned>();
+        assert_invalidated(|| {
+            let _ = orphan.get(message.get_orphanage());
+        });
+        let _ = orphans.pop().unwrap().into_internal();
+    }
+
+    #[test]
+    fn max_message_words() {
+        let long_text = "a".repeat(200);
+        for builder_options in [
+            message::HeapAllocator::new().max_message_words(16),
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize)
+                .max_message_words(16),
+        ] {
+            let mut message = message::Builder::new(builder_options);
+            message.set_root("hello").unwrap();
+            let e = message.set_root(&long_text[..]).unwrap_err();
+            assert_eq!(e.kind, capnp::ErrorKind::AllocationLimitExceeded);
+
+            // The message is still usable, and small values still fit.
+            message.set_root("world").unwrap();
+            let root: text::Reader<'_> = message.get_root_as_reader().unwrap();
+            assert_eq!(root, "world");
+            let words: usize = message
+                .get_segments_for_output()
+                .iter()
+                .map(|s| s.len() / 8)
+                .sum();
+            assert!(words <= 16);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The allocator refused to allocate more memory for a message")]
+    fn max_message_words_init_panics() {
+        let mut message =
+            message::Builder::new(message::HeapAllocator::new().max_message_words(16));
+        let _: primitive_list::Builder<'_, u64> = message.initn_root(100);
+    }
+
+    #[test]
+    fn try_init_in_scratch_space() {
+        use crate::test_capnp::test_all_types;
+
+        let mut buffer = Word::allocate_zeroed_vec(64);
+        let allocator =
+            message::ScratchSpaceHeapAllocator::new(Word::words_to_bytes_mut(&mut buffer))
+                .heap_fallback(false);
+        let mut message = message::Builder::new(allocator);
+        let mut root: test_all_types::Builder<'_> = message.try_init_root().unwrap();
+
+        let e = root.reborrow().try_init_struct_list(10).err().unwrap();
+        assert_eq!(e.kind, capnp::ErrorKind::BufferNotLargeEnough);
+        let e = root
+            .try_set_text_field("a".repeat(400)[..].into())
+            .unwrap_err();
+        assert_eq!(e.kind, capnp::ErrorKind::BufferNotLargeEnough);
+
+        root.try_set_text_field("foo".into()).unwrap();
+        let mut list = root.reborrow().try_init_int32_list(2).unwrap();
+        list.set(1, 7);
+        root.reborrow()
+            .try_init_struct_field()
+            .unwrap()
+            .set_u_int8_field(3);
+
+        assert_eq!(message.get_segments_for_output().len(), 1);
+        let root: test_all_types::Reader<'_> = message.get_root_as_reader().unwrap();
+        assert_eq!(root.get_text_field().unwrap(), "foo");
+        assert_eq!(root.get_int32_list().unwrap().get(1), 7);
+        assert_eq!(root.get_struct_field().unwrap().get_u_int8_field(), 3);
+        assert!(root.get_struct_list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn shared_reader_across_threads() {
+        use crate::test_capnp::test_all_types;
+
+        let mut message = message::Builder::new_default();
+        let text = "x".repeat(800);
+        message
+            .init_root::<test_all_types::Builder<'_>>()
+            .set_text_field(text[..].into());
+        let mut bytes = Vec::new();
+        capnp::serialize::write_message(&mut bytes, &message).unwrap();
+        let segments = capnp::serialize::read_message(&bytes[..], message::ReaderOptions::new())
+            .unwrap()
+            .into_segments();
+
+        // Enough to read the text once, but not twice.
+        let mut options = message::ReaderOptions::new();
+        options.traversal_limit_in_words(Some(150));
+        let reader = message::SharedReader::<test_all_types::Owned>::new(segments, options);
+        let root = reader.get().unwrap();
+        assert_eq!(root.get_text_field().unwrap(), &text[..]);
+        let e = reader
+            .get()
+            .and_then(|root| root.get_text_field())
+            .err()
+            .unwrap();
+        assert_eq!(e.kind, capnp::ErrorKind::ReadLimitExceeded);
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let reader = reader.clone();
+                let text = &text;
+                scope.spawn(move || {
+                    let root = reader.get().unwrap();
+                    assert_eq!(root.get_text_field().unwrap(), &text[..]);
+                });
+            }
+        });
+
+        let reader = reader.clone_with_options(message::ReaderOptions::new());
+        for _ in 0..4 {
+            assert_eq!(reader.get().unwrap().get_text_field().unwrap(), &text[..]);
+        }
+    }
+
+    #[test]
+    fn canonical_stream_matches_canonicalize() {
+        use crate::test_capnp::{test_all_types, test_any_pointer};
+
+        let mut message = message::Builder::new(
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        );
+        init_test_message(message.init_root());
+        let message = message.into_reader();
+        let root: test_all_types::Reader<'_> = message.get_root().unwrap();
+        let canonical_words = message.canonicalize().unwrap();
+        let canonical_bytes = Word::words_to_bytes(&canonical_words[..]);
+        assert_eq!(capnp::canonical::to_bytes(&root).unwrap(), canonical_bytes);
+
+        // A sub-struct is canonicalized as the root of its own message.
+        let mut wrapper = message::Builder::new_default();
+        wrapper
+            .init_root::<test_any_pointer::Builder<'_>>()
+            .init_any_pointer_field()
+            .set_as(root)
+            .unwrap();
+        let wrapper: test_any_pointer::Reader<'_> = wrapper.get_root_as_reader().unwrap();
+        let field = wrapper.get_any_pointer_field();
+        assert_eq!(capnp::canonical::to_bytes(&field).unwrap(), canonical_bytes);
+        let inner: test_all_types::Reader<'_> = field.get_as().unwrap();
+        assert_eq!(capnp::canonical::to_bytes(&inner).unwrap(), canonical_bytes);
+        let dynamic = capnp::dynamic_value::Reader::from(inner)
+            .downcast::<capnp::dynamic_struct::Reader<'_>>();
+        assert_eq!(
+            capnp::canonical::to_bytes(&dynamic).unwrap(),
+            canonical_bytes
+        );
+    }
+
+    #[test]
+    fn canonical_eq_and_hash() {
+        use crate::test_capnp::test_all_types;
+        use capnp::canonical::{self, Canonical};
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::Hasher;
+
+        let mut compact = message::Builder::new_default();
+        init_test_message(compact.init_root());
+        let mut scattered = message::Builder::new(
+            message::HeapAllocator::new()
+                .first_segment_words(1)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        );
+        init_test_message(scattered.init_root());
+        let mut different = message::Builder::new_default();
+        init_test_message(different.init_root());
+        different
+            .get_root::<test_all_types::Builder<'_>>()
+            .unwrap()
+            .set_u_int32_field(0);
+
+        let readers: [test_all_types::Reader<'_>; 3] = [
+            compact.get_root_as_reader().unwrap(),
+            scattered.get_root_as_reader().unwrap(),
+            different.get_root_as_reader().unwrap(),
+        ];
+        let set: HashSet<_> = readers
+            .iter()
+            .map(|&reader| Canonical::new(reader).unwrap())
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Canonical::new(readers[1]).unwrap()));
+
+        let digests: Vec<u64> = readers
+            .iter()
+            .map(|reader| {
+                let mut hasher = DefaultHasher::new();
+                canonical::hash(reader, &mut hasher).unwrap();
+                hasher.finish()
+            })
+            .collect();
+        assert_eq!(digests[0], digests[1]);
+        assert_ne!(digests[0], digests[2]);
+    }
+
     #[test]
     fn test_slice_segments() {
         use crate::test_capnp::test_all_types;
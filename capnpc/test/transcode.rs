@@ -0,0 +1,187 @@
+use capnp::dynamic_struct::{self, FieldPath};
+use capnp::dynamic_value::{self, Enum};
+use capnp::message::{self, ReaderOptions};
+use capnp::schema::SchemaLoader;
+use capnp::transcode::Transcoder;
+use capnp::{any_pointer, serialize, text, ErrorKind};
+
+struct Version {
+    loader: SchemaLoader,
+    message: message::Reader<serialize::OwnedSegments>,
+}
+
+impl Version {
+    fn load(bytes: &[u8]) -> Self {
+        let message = serialize::read_message(bytes, ReaderOptions::new()).unwrap();
+        let mut loader = SchemaLoader::new();
+        loader.load_request(message.get_root().unwrap()).unwrap();
+        Self { loader, message }
+    }
+
+    fn id(&self, name: &str) -> u64 {
+        let request: capnp::schema_capnp::code_generator_request::Reader<'_> =
+            self.message.get_root().unwrap();
+        let display_name = format!("evolution.capnp:{name}");
+        request
+            .get_nodes()
+            .unwrap()
+            .iter()
+            .find(|node| node.get_display_name().unwrap() == &display_name[..])
+            .unwrap()
+            .get_id()
+    }
+}
+
+fn v1() -> Version {
+    Version::load(include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/evolution-v1.bin"
+    )))
+}
+
+fn v2() -> Version {
+    Version::load(include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/evolution-v2.bin"
+    )))
+}
+
+fn init_v1_person(version: &Version, message: &mut message::Builder<message::HeapAllocator>) {
+    let schema = version.loader.get_struct(version.id("Person")).unwrap();
+    let color = version.loader.get_enum(version.id("Color")).unwrap();
+    let mut person = message
+        .init_root::<any_pointer::Builder<'_>>()
+        .init_dynamic(schema)
+        .unwrap();
+    person.set_named("name", "Alice".into()).unwrap();
+    person.set_named("age", 40u16.into()).unwrap();
+    person
+        .set_named("email", "alice@example.com".into())
+        .unwrap();
+    person.set_named("fax", "555-1234".into()).unwrap();
+    person.set_named("address", "1 Main St".into()).unwrap();
+    let mut tags = person
+        .reborrow()
+        .initn_named("tags", 2)
+        .unwrap()
+        .downcast::<capnp::dynamic_list::Builder<'_>>();
+    tags.set(0, "a".into()).unwrap();
+    tags.set(1, "b".into()).unwrap();
+    person
+        .set_named("favoriteColor", Enum::new(2, color).into())
+        .unwrap();
+}
+
+fn get_text<'a>(person: dynamic_struct::Reader<'a>, path: &str) -> text::Reader<'a> {
+    person
+        .get_path(&path.parse::<FieldPath>().unwrap())
+        .unwrap()
+        .downcast()
+}
+
+#[test]
+fn transcode_v1_to_v2() {
+    let old = v1();
+    let new = v2();
+    let mut source = message::Builder::new_default();
+    init_v1_person(&old, &mut source);
+    let source = source
+        .get_root_as_reader::<any_pointer::Reader<'_>>()
+        .unwrap()
+        .get_as_dynamic(old.loader.get_struct(old.id("Person")).unwrap())
+        .unwrap();
+
+    let person_schema = new.loader.get_struct(new.id("Person")).unwrap();
+    let mut destination = message::Builder::new_default();
+    let root = destination
+        .init_root::<any_pointer::Builder<'_>>()
+        .init_dynamic(person_schema)
+        .unwrap();
+    let dropped = Transcoder::new()
+        .old_name_annotation(new.id("oldName"))
+        .transcode(source, root)
+        .unwrap();
+    let dropped: Vec<String> = dropped.iter().map(|d| d.to_string()).collect();
+    assert_eq!(dropped, ["favoriteColor: blue"]);
+
+    let person = destination
+        .get_root_as_reader::<any_pointer::Reader<'_>>()
+        .unwrap()
+        .get_as_dynamic(person_schema)
+        .unwrap();
+    assert_eq!(get_text(person, "name"), "Alice");
+    assert!(matches!(
+        person.get_named("age").unwrap(),
+        dynamic_value::Reader::UInt32(40)
+    ));
+    assert_eq!(get_text(person, "emailAddress"), "alice@example.com");
+    // The old default is copied, rather than taking on the new one.
+    assert!(person.has_named("nickname").unwrap());
+    assert_eq!(get_text(person, "nickname"), "none");
+    assert_eq!(
+        person
+            .which()
+            .unwrap()
+            .unwrap()
+            .get_proto()
+            .get_name()
+            .unwrap(),
+        "fax"
+    );
+    assert_eq!(get_text(person, "fax"), "555-1234");
+    assert_eq!(get_text(person, "home.address"), "1 Main St");
+    assert_eq!(get_text(person, "tags[1]"), "b");
+    assert!(matches!(
+        person.get_named("score").unwrap(),
+        dynamic_value::Reader::Float32(score) if score == 0.0
+    ));
+}
+
+#[test]
+fn transcode_without_old_names() {
+    let old = v1();
+    let new = v2();
+    let mut source = message::Builder::new_default();
+    init_v1_person(&old, &mut source);
+    let source = source
+        .get_root_as_reader::<any_pointer::Reader<'_>>()
+        .unwrap()
+        .get_as_dynamic(old.loader.get_struct(old.id("Person")).unwrap())
+        .unwrap();
+
+    let mut destination = message::Builder::new_default();
+    let root = destination
+        .init_root::<any_pointer::Builder<'_>>()
+        .init_dynamic(new.loader.get_struct(new.id("Person")).unwrap())
+        .unwrap();
+    let dropped = capnp::transcode::transcode(source, root).unwrap();
+    let dropped: Vec<String> = dropped.iter().map(|d| d.to_string()).collect();
+    assert_eq!(
+        dropped,
+        ["email: \"alice@example.com\"", "favoriteColor: blue"]
+    );
+}
+
+#[test]
+fn transcode_rejects_narrowing() {
+    let old = v1();
+    let new = v2();
+    let mut source = message::Builder::new_default();
+    let mut person = source
+        .init_root::<any_pointer::Builder<'_>>()
+        .init_dynamic(new.loader.get_struct(new.id("Person")).unwrap())
+        .unwrap();
+    person.set_named("age", 70000u32.into()).unwrap();
+
+    let mut destination = message::Builder::new_default();
+    let root = destination
+        .init_root::<any_pointer::Builder<'_>>()
+        .init_dynamic(old.loader.get_struct(old.id("Person")).unwrap())
+        .unwrap();
+    let error = Transcoder::new()
+        .transcode(person.into_reader(), root)
+        .err()
+        .unwrap();
+    assert_eq!(error.kind, ErrorKind::TypeMismatch);
+    assert_eq!(error.extra, "age");
+}
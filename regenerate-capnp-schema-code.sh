@@ -6,3 +6,5 @@ set -x
 cargo build -p capnpc
 capnp compile -otarget/debug/capnpc-rust-bootstrap:capnp/src capnp/schema.capnp --src-prefix capnp/
 rustfmt capnp/src/schema_capnp.rs
+capnp compile -otarget/debug/capnpc-rust-bootstrap:capnp/src capnp/json.capnp --src-prefix capnp/
+rustfmt capnp/src/json_capnp.rs